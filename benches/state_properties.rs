@@ -107,5 +107,44 @@ fn properties_pcsaft_polar(c: &mut Criterion) {
     });
 }
 
-criterion_group!(bench, properties_pcsaft, properties_pcsaft_polar);
+/// Pressure on a grid of temperatures and densities evaluated at once
+/// compared to creating a state for every grid point.
+fn pressure_grid_pcsaft(c: &mut Criterion) {
+    let parameters = PcSaftParameters::from_json(
+        vec!["methane", "ethane", "propane"],
+        "./parameters/pcsaft/gross2001.json",
+        None,
+        IdentifierOption::Name,
+    )
+    .unwrap();
+    let eos = Arc::new(PcSaft::new(Arc::new(parameters)));
+    let t = Array1::linspace(200.0, 400.0, 50) * KELVIN;
+    let rho = Array1::linspace(0.1, 15.0, 50) * KILO * MOL / METER.powi::<P3>();
+    let x = arr1(&[1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0]);
+    let m = &x * MOL;
+
+    let mut group = c.benchmark_group("pressure_grid_pcsaft_methane_ethane_propane");
+    group.bench_function("grid", |b| {
+        b.iter(|| eos.pressure_grid(&t, &rho, Some(&x)).unwrap())
+    });
+    group.bench_function("states", |b| {
+        b.iter(|| {
+            let mut p = Vec::with_capacity(t.len() * rho.len());
+            for t in t.into_iter() {
+                for rho in rho.into_iter() {
+                    let state = State::new_nvt(&eos, t, MOL / rho, &m).unwrap();
+                    p.push(state.pressure(Contributions::Total));
+                }
+            }
+            p
+        })
+    });
+}
+
+criterion_group!(
+    bench,
+    properties_pcsaft,
+    properties_pcsaft_polar,
+    pressure_grid_pcsaft
+);
 criterion_main!(bench);
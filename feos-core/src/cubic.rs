@@ -226,9 +226,11 @@ impl Molarweight for PengRobinson {
 mod tests {
     use super::*;
     use crate::state::{Contributions, State};
+    use crate::ReferenceSystem;
     use crate::{EosResult, SolverOptions, Verbosity};
     use approx::*;
-    use quantity::{KELVIN, PASCAL};
+    use ndarray::arr1;
+    use quantity::{Density, Moles, KELVIN, PASCAL};
     use std::sync::Arc;
 
     fn pure_record_vec() -> Vec<PureRecord<PengRobinsonRecord>> {
@@ -288,4 +290,50 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn validate_moles() -> EosResult<()> {
+        let mixture = pure_record_vec();
+        let parameters = PengRobinsonParameters::new_pure(mixture[0].clone())?;
+        let pr = PengRobinson::new(Arc::new(parameters));
+        let moles = pr.validate_moles(Some(&(Array1::zeros(0) * MOL)))?;
+        assert_eq!(moles.len(), 1);
+
+        let parameters = PengRobinsonParameters::new_binary(mixture, None)?;
+        let pr = PengRobinson::new(Arc::new(parameters));
+        let moles = arr1(&[1.0, 2.0, 3.0]) * MOL;
+        assert_eq!(
+            pr.validate_moles(Some(&moles)).unwrap_err().to_string(),
+            "Equation of state has 2 components, but the composition has 3 entries."
+        );
+        assert_eq!(
+            pr.validate_moles(None).unwrap_err().to_string(),
+            "Undetermined state: mole numbers are required for 2 components."
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_reduced() -> EosResult<()> {
+        let parameters = PengRobinsonParameters::new_binary(pure_record_vec(), None)?;
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let t = 300.0;
+        let rho = 0.005;
+        let x = arr1(&[0.3, 0.7]);
+        let h = 1e-6 * rho;
+        let da_drho = (pr.evaluate_reduced(t, rho + h, Some(&x))?
+            - pr.evaluate_reduced(t, rho - h, Some(&x))?)
+            / (2.0 * h);
+
+        let moles = Moles::from_reduced(x);
+        let state = State::new_nvt(
+            &pr,
+            t * KELVIN,
+            Moles::from_reduced(1.0) / Density::from_reduced(rho),
+            &moles,
+        )?;
+        let p_res = state.pressure(Contributions::Residual).to_reduced() / t;
+        assert_relative_eq!(rho * rho * da_drho, p_res, max_relative = 1e-8);
+        Ok(())
+    }
 }
@@ -27,9 +27,8 @@ pub fn density_iteration<E: Residual>(
     }
 
     let maxiter = 50;
-    let mut iterations = 0;
+    let mut converged = false;
     'iteration: for k in 0..maxiter {
-        iterations += 1;
         let (mut p, mut dp_drho) = State::new_nvt(eos, temperature, n / rho, moles)?.p_dpdrho();
 
         // attempt to correct for poor initial density rho_init
@@ -126,10 +125,11 @@ pub fn density_iteration<E: Residual>(
         // Newton step
         rho += delta_rho;
         if error.to_reduced().abs() < f64::max(abstol, (rho * reltol).to_reduced()) {
+            converged = true;
             break 'iteration;
         }
     }
-    if iterations == maxiter + 1 {
+    if !converged {
         Err(EosError::NotConverged("density_iteration".to_owned()))
     } else {
         Ok(State::new_nvt(eos, temperature, n / rho, moles)?)
@@ -0,0 +1,91 @@
+//! Utilities for developers of new Helmholtz energy contributions.
+//!
+//! Only available with the `dev` feature.
+use crate::{EosError, EosResult, Residual, StateHD};
+use ndarray::{Array1, ScalarOperand};
+use num_dual::{Dual64, DualNum};
+
+/// Relative step size of the central finite differences.
+const STEP: f64 = 1e-5;
+/// Maximum relative deviation between dual numbers and finite differences.
+const MAX_RELATIVE: f64 = 1e-6;
+
+fn contribution<R: Residual, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &R,
+    name: &str,
+    state: &StateHD<D>,
+) -> EosResult<D> {
+    eos.residual_helmholtz_energy_contributions(state)
+        .into_iter()
+        .find_map(|(n, a)| (n == name).then_some(a))
+        .ok_or_else(|| EosError::UnknownContribution(name.to_owned()))
+}
+
+fn compare(property: &str, dual: f64, finite_difference: f64) -> EosResult<()> {
+    let scale = dual.abs().max(finite_difference.abs()).max(f64::EPSILON);
+    if (dual - finite_difference).abs() / scale > MAX_RELATIVE
+        || dual.is_nan()
+        || finite_difference.is_nan()
+    {
+        return Err(EosError::DerivativeMismatch {
+            property: property.to_owned(),
+            dual,
+            finite_difference,
+        });
+    }
+    Ok(())
+}
+
+/// Verify the pressure and chemical potentials of a single Helmholtz energy contribution.
+///
+/// The derivatives of the contribution `contribution` (as identified by its name in
+/// [Residual::residual_helmholtz_energy_contributions]) with respect to volume and moles
+/// are calculated using dual numbers and compared to central finite differences of the
+/// Helmholtz energy. Returns an [EosError::DerivativeMismatch] error, if the relative
+/// deviation exceeds `1e-6` for the pressure or any chemical potential, and an
+/// [EosError::UnknownContribution] error, if the contribution does not exist.
+///
+/// All quantities are in reduced units, i.e., the pressure in $k_\mathrm{B}T/\AA^3$ and
+/// the chemical potentials in $k_\mathrm{B}T$.
+pub fn verify_derivatives<R: Residual>(
+    eos: &R,
+    contribution_name: &str,
+    state: &StateHD<f64>,
+) -> EosResult<()> {
+    let t = Dual64::from(state.temperature);
+    let v = Dual64::from(state.volume);
+    let n = state.moles.mapv(Dual64::from);
+    let a = |volume: f64, moles: &Array1<f64>| {
+        contribution(
+            eos,
+            contribution_name,
+            &StateHD::new(state.temperature, volume, moles.clone()),
+        )
+    };
+
+    // pressure
+    let dual = -contribution(
+        eos,
+        contribution_name,
+        &StateHD::new(t, v.derivative(), n.clone()),
+    )?
+    .eps;
+    let h = STEP * state.volume;
+    let fd = -(a(state.volume + h, &state.moles)? - a(state.volume - h, &state.moles)?) / (2.0 * h);
+    compare("pressure", dual, fd)?;
+
+    // chemical potentials
+    let h = STEP * state.moles.sum();
+    for i in 0..state.moles.len() {
+        let mut n_dual = n.clone();
+        n_dual[i] = n_dual[i].derivative();
+        let dual = contribution(eos, contribution_name, &StateHD::new(t, v, n_dual))?.eps;
+        let mut n_plus = state.moles.clone();
+        let mut n_minus = state.moles.clone();
+        n_plus[i] += h;
+        n_minus[i] -= h;
+        let fd = (a(state.volume, &n_plus)? - a(state.volume, &n_minus)?) / (2.0 * h);
+        compare(&format!("chemical potential of component {i}"), dual, fd)?;
+    }
+    Ok(())
+}
@@ -1,7 +1,11 @@
-use crate::EosResult;
-use ndarray::{Array1, ScalarOperand};
+use crate::{
+    Contributions, EosError, EosResult, PhaseEquilibrium, ReferenceSystem, State, StateHD,
+};
+use ndarray::{Array1, Axis, ScalarOperand};
+use num_dual::DualNum;
 use quantity::{
-    Diffusivity, MolarWeight, Moles, Temperature, ThermalConductivity, Viscosity, Volume,
+    Diffusivity, MolarEnergy, MolarEntropy, MolarWeight, Moles, Pressure, Temperature,
+    ThermalConductivity, Viscosity, Volume,
 };
 use std::sync::Arc;
 
@@ -9,7 +13,7 @@ mod ideal_gas;
 mod residual;
 
 pub use ideal_gas::IdealGas;
-pub use residual::{EntropyScaling, Molarweight, NoResidual, Residual};
+pub use residual::{validate_residual_entropy, EntropyScaling, Molarweight, NoResidual, Residual};
 
 /// The number of components that the model is initialized for.
 pub trait Components {
@@ -21,12 +25,49 @@ pub trait Components {
     fn subset(&self, component_list: &[usize]) -> Self;
 }
 
+/// Definition of the state in which the enthalpy and entropy
+/// of each pure component are zero.
+#[derive(Clone, Copy)]
+pub enum ReferenceState {
+    /// Stable phase of the pure components at given temperature and pressure.
+    TemperaturePressure(Temperature, Pressure),
+    /// Saturated liquid of the pure components at given pressure,
+    /// e.g., the normal boiling point for a pressure of 1 atm.
+    SaturatedLiquid(Pressure),
+}
+
+/// Molar enthalpies and entropies of the pure components in their reference state.
+#[derive(Clone)]
+pub struct ReferenceValues {
+    pub reference_state: ReferenceState,
+    pub enthalpy: MolarEnergy<Array1<f64>>,
+    pub entropy: MolarEntropy<Array1<f64>>,
+}
+
 /// An equation of state consisting of an ideal gas model
 /// and a residual Helmholtz energy model.
-#[derive(Clone)]
 pub struct EquationOfState<I, R> {
     pub ideal_gas: Arc<I>,
     pub residual: Arc<R>,
+    /// Factor by which the maximum density of the residual model is scaled.
+    pub max_density_scaling: f64,
+    /// Names of residual Helmholtz energy contributions that are omitted.
+    pub disabled_contributions: Vec<String>,
+    /// Properties of the pure components in the reference state,
+    /// which are subtracted from all caloric properties.
+    pub reference_values: Option<ReferenceValues>,
+}
+
+impl<I, R> Clone for EquationOfState<I, R> {
+    fn clone(&self) -> Self {
+        Self {
+            ideal_gas: self.ideal_gas.clone(),
+            residual: self.residual.clone(),
+            max_density_scaling: self.max_density_scaling,
+            disabled_contributions: self.disabled_contributions.clone(),
+            reference_values: self.reference_values.clone(),
+        }
+    }
 }
 
 impl<I, R> EquationOfState<I, R> {
@@ -36,6 +77,129 @@ impl<I, R> EquationOfState<I, R> {
         Self {
             ideal_gas,
             residual,
+            max_density_scaling: 1.0,
+            disabled_contributions: Vec::new(),
+            reference_values: None,
+        }
+    }
+
+    /// Return a copy of the [EquationOfState] with the maximum density
+    /// of the residual model scaled by `max_density_scaling`.
+    ///
+    /// The maximum density is used to initialize liquid densities and
+    /// bounds the steps of the density iteration. For parameter sets with
+    /// liquid densities above the maximum density of the model, a factor
+    /// larger than 1 can be used to loosen the bound.
+    ///
+    /// Returns an error if the factor is not positive and finite.
+    pub fn with_max_density_scaling(&self, max_density_scaling: f64) -> EosResult<Self> {
+        if !max_density_scaling.is_finite() || max_density_scaling <= 0.0 {
+            return Err(EosError::InvalidState(
+                String::from("EquationOfState"),
+                String::from("max_density_scaling"),
+                max_density_scaling,
+            ));
+        }
+        Ok(Self {
+            max_density_scaling,
+            ..self.clone()
+        })
+    }
+
+    /// Return a copy of the [EquationOfState] in which the residual
+    /// Helmholtz energy contribution `name` is enabled again.
+    pub fn with_contribution(&self, name: &str) -> Self {
+        Self {
+            disabled_contributions: self
+                .disabled_contributions
+                .iter()
+                .filter(|&c| c != name)
+                .cloned()
+                .collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl<I: IdealGas, R: Residual> EquationOfState<I, R> {
+    /// Return a copy of the [EquationOfState] without the residual
+    /// Helmholtz energy contribution `name`.
+    ///
+    /// The name has to match one of the names returned by
+    /// [Residual::residual_helmholtz_energy_contributions], otherwise
+    /// an [EosError::UnknownContribution] error is returned.
+    pub fn without_contribution(&self, name: &str) -> EosResult<Self> {
+        let n = self.components();
+        let state = StateHD::new(300.0, 1e3 * n as f64, Array1::ones(n));
+        if !self
+            .residual
+            .residual_helmholtz_energy_contributions(&state)
+            .iter()
+            .any(|(c, _)| c == name)
+        {
+            return Err(EosError::UnknownContribution(name.to_owned()));
+        }
+        let mut disabled_contributions = self.disabled_contributions.clone();
+        if !disabled_contributions.iter().any(|c| c == name) {
+            disabled_contributions.push(name.to_owned());
+        }
+        Ok(Self {
+            disabled_contributions,
+            ..self.clone()
+        })
+    }
+
+    /// Return a copy of the [EquationOfState] in which the molar
+    /// enthalpy and entropy of every pure component vanish in the
+    /// given reference state.
+    ///
+    /// The offsets are applied to the ideal gas contribution, so that
+    /// the enthalpy, entropy, internal energy, Helmholtz and Gibbs energy
+    /// as well as the chemical potentials are shifted consistently, while
+    /// all other properties and phase equilibria are unaffected.
+    pub fn with_reference_state(&self, reference_state: ReferenceState) -> EosResult<Self> {
+        let eos = Self {
+            reference_values: None,
+            ..self.clone()
+        };
+        let (enthalpy, entropy): (Vec<_>, Vec<_>) = (0..self.components())
+            .map(|i| {
+                let eos = Arc::new(eos.subset(&[i]));
+                let state = match reference_state {
+                    ReferenceState::TemperaturePressure(temperature, pressure) => {
+                        State::new_pure(&eos, temperature, pressure)?
+                    }
+                    ReferenceState::SaturatedLiquid(pressure) => {
+                        PhaseEquilibrium::pure(&eos, pressure, None, Default::default())?
+                            .liquid()
+                            .clone()
+                    }
+                };
+                Ok((
+                    state.molar_enthalpy(Contributions::Total),
+                    state.molar_entropy(Contributions::Total),
+                ))
+            })
+            .collect::<EosResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        Ok(Self {
+            reference_values: Some(ReferenceValues {
+                reference_state,
+                enthalpy: MolarEnergy::from_vec(enthalpy),
+                entropy: MolarEntropy::from_vec(entropy),
+            }),
+            ..eos
+        })
+    }
+
+    /// Return a copy of the [EquationOfState] in which the reference
+    /// values are recalculated for the current models, e.g., after
+    /// the ideal gas or residual model was replaced.
+    pub fn update_reference_values(&self) -> EosResult<Self> {
+        match &self.reference_values {
+            Some(r) => self.with_reference_state(r.reference_state),
+            None => Ok(self.clone()),
         }
     }
 }
@@ -45,10 +209,7 @@ impl<I: IdealGas> EquationOfState<I, NoResidual> {
     /// an ideal gas models.
     pub fn ideal_gas(ideal_gas: Arc<I>) -> Self {
         let residual = Arc::new(NoResidual(ideal_gas.components()));
-        Self {
-            ideal_gas,
-            residual,
-        }
+        Self::new(ideal_gas, residual)
     }
 }
 
@@ -63,16 +224,34 @@ impl<I: Components, R: Components> Components for EquationOfState<I, R> {
     }
 
     fn subset(&self, component_list: &[usize]) -> Self {
-        Self::new(
-            Arc::new(self.ideal_gas.subset(component_list)),
-            Arc::new(self.residual.subset(component_list)),
-        )
+        Self {
+            ideal_gas: Arc::new(self.ideal_gas.subset(component_list)),
+            residual: Arc::new(self.residual.subset(component_list)),
+            reference_values: self.reference_values.as_ref().map(|r| ReferenceValues {
+                reference_state: r.reference_state,
+                enthalpy: MolarEnergy::from_reduced(
+                    r.enthalpy.to_reduced().select(Axis(0), component_list),
+                ),
+                entropy: MolarEntropy::from_reduced(
+                    r.entropy.to_reduced().select(Axis(0), component_list),
+                ),
+            }),
+            ..self.clone()
+        }
     }
 }
 
 impl<I: IdealGas, R: Components + Sync + Send> IdealGas for EquationOfState<I, R> {
-    fn ln_lambda3<D: num_dual::DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
-        self.ideal_gas.ln_lambda3(temperature)
+    fn ln_lambda3<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        let ln_lambda3 = self.ideal_gas.ln_lambda3(temperature);
+        match &self.reference_values {
+            None => ln_lambda3,
+            Some(r) => {
+                let (h, s) = (r.enthalpy.to_reduced(), r.entropy.to_reduced());
+                let t_inv = temperature.recip();
+                ln_lambda3 + Array1::from_shape_fn(h.len(), |i| t_inv * -h[i] + s[i])
+            }
+        }
     }
 
     fn ideal_gas_model(&self) -> String {
@@ -82,14 +261,20 @@ impl<I: IdealGas, R: Components + Sync + Send> IdealGas for EquationOfState<I, R
 
 impl<I: IdealGas, R: Residual> Residual for EquationOfState<I, R> {
     fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
-        self.residual.compute_max_density(moles)
+        self.max_density_scaling * self.residual.compute_max_density(moles)
+    }
+
+    fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+        self.residual.packing_fraction(temperature, partial_density)
     }
 
     fn residual_helmholtz_energy_contributions<D: num_dual::DualNum<f64> + Copy + ScalarOperand>(
         &self,
-        state: &crate::StateHD<D>,
+        state: &StateHD<D>,
     ) -> Vec<(String, D)> {
-        self.residual.residual_helmholtz_energy_contributions(state)
+        let mut contributions = self.residual.residual_helmholtz_energy_contributions(state);
+        contributions.retain(|(name, _)| !self.disabled_contributions.contains(name));
+        contributions
     }
 }
 
@@ -97,6 +282,10 @@ impl<I, R: Molarweight> Molarweight for EquationOfState<I, R> {
     fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
         self.residual.molar_weight()
     }
+
+    fn has_molar_weight(&self) -> bool {
+        self.residual.has_molar_weight()
+    }
 }
 
 impl<I: IdealGas, R: Residual + EntropyScaling> EntropyScaling for EquationOfState<I, R> {
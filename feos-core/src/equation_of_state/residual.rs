@@ -1,7 +1,7 @@
 use super::Components;
 use crate::{EosError, EosResult, ReferenceSystem, StateHD};
 use ndarray::prelude::*;
-use ndarray::ScalarOperand;
+use ndarray::{ScalarOperand, Zip};
 use num_dual::*;
 use num_traits::{One, Zero};
 use quantity::*;
@@ -13,6 +13,17 @@ use typenum::Quot;
 /// Enables calculation of (mass) specific properties.
 pub trait Molarweight {
     fn molar_weight(&self) -> MolarWeight<Array1<f64>>;
+
+    /// Return `false` if the molar weights are not available, e.g.,
+    /// because the parameters were created without them.
+    fn has_molar_weight(&self) -> bool {
+        true
+    }
+
+    /// Molar weight of a mixture with the given composition: $MW=\sum_ix_iMW_i$
+    fn molar_weight_of_mixture(&self, molefracs: &Array1<f64>) -> MolarWeight {
+        (self.molar_weight() * Dimensionless::new(molefracs)).sum()
+    }
 }
 
 /// A residual Helmholtz energy model.
@@ -42,22 +53,30 @@ pub trait Residual: Components + Send + Sync {
             .fold(D::zero(), |acc, (_, a)| acc + a)
     }
 
+    /// Return the packing fraction $\eta$ of the hard-sphere reference fluid for a
+    /// given temperature (in K) and partial densities (in Angstrom^-3).
+    ///
+    /// Returns `None` for models that are not based on a hard-sphere reference.
+    fn packing_fraction(&self, _temperature: f64, _partial_density: &Array1<f64>) -> Option<f64> {
+        None
+    }
+
     /// Check if the provided optional mole number is consistent with the
     /// equation of state.
     ///
     /// In general, the number of elements in `moles` needs to match the number
     /// of components of the equation of state. For a pure component, however,
-    /// no moles need to be provided. In that case, it is set to the constant
-    /// reference value.
+    /// no moles (or an empty array) need to be provided. In that case, it is set
+    /// to the constant reference value.
     fn validate_moles(&self, moles: Option<&Moles<Array1<f64>>>) -> EosResult<Moles<Array1<f64>>> {
-        let l = moles.map_or(1, |m| m.len());
-        if self.components() == l {
-            match moles {
-                Some(m) => Ok(m.to_owned()),
-                None => Ok(Moles::from_reduced(Array::ones(1))),
-            }
-        } else {
-            Err(EosError::IncompatibleComponents(self.components(), l))
+        let n = self.components();
+        match moles {
+            Some(m) if m.len() == n => Ok(m.to_owned()),
+            Some(m) if !m.is_empty() => Err(EosError::IncompatibleComposition(n, m.len())),
+            _ if n == 1 => Ok(Moles::from_reduced(Array::ones(1))),
+            _ => Err(EosError::UndeterminedState(format!(
+                "mole numbers are required for {n} components"
+            ))),
         }
     }
 
@@ -72,6 +91,52 @@ pub trait Residual: Components + Send + Sync {
         Ok(Density::from_reduced(self.compute_max_density(&mr)))
     }
 
+    /// Evaluate the residual Helmholtz energy per particle $\beta A^\mathrm{res}/N$
+    /// directly from a reduced temperature (in K) and a reduced density (in Angstrom^-3).
+    ///
+    /// For a pure component, no mole fractions need to be provided.
+    fn evaluate_reduced(
+        &self,
+        temperature: f64,
+        density: f64,
+        molefracs: Option<&Array1<f64>>,
+    ) -> EosResult<f64> {
+        let moles = molefracs.map(|x| Moles::from_reduced(x.clone()));
+        let x = self.validate_moles(moles.as_ref())?.to_reduced();
+        let x = &x / x.sum();
+        let state = StateHD::new(temperature, density.recip(), x);
+        Ok(self.residual_helmholtz_energy(&state))
+    }
+
+    /// Calculate the pressure on a grid of temperatures and densities.
+    ///
+    /// The element `[i, j]` of the result is the pressure at `temperature[i]`
+    /// and `density[j]`. For a pure component, no mole fractions need to be
+    /// provided. With the `rayon` feature, the grid is evaluated in parallel.
+    fn pressure_grid(
+        &self,
+        temperature: &Temperature<Array1<f64>>,
+        density: &Density<Array1<f64>>,
+        molefracs: Option<&Array1<f64>>,
+    ) -> EosResult<Pressure<Array2<f64>>> {
+        let moles = molefracs.map(|x| Moles::from_reduced(x.clone()));
+        let x = self.validate_moles(moles.as_ref())?.to_reduced();
+        let x = (&x / x.sum()).mapv(Dual64::from);
+        let t = temperature.to_reduced();
+        let rho = density.to_reduced();
+        let mut p = Array2::zeros((t.len(), rho.len()));
+        let pressure = |(i, j): (usize, usize), p: &mut f64| {
+            let v = Dual64::from(rho[j].recip()).derivative();
+            let state = StateHD::new(Dual64::from(t[i]), v, x.clone());
+            *p = t[i] * (rho[j] - self.residual_helmholtz_energy(&state).eps);
+        };
+        #[cfg(feature = "rayon")]
+        Zip::indexed(&mut p).par_for_each(pressure);
+        #[cfg(not(feature = "rayon"))]
+        Zip::indexed(&mut p).for_each(pressure);
+        Ok(Pressure::from_reduced(p))
+    }
+
     /// Calculate the second virial coefficient $B(T)$
     fn second_virial_coefficient(
         &self,
@@ -171,6 +236,22 @@ pub trait EntropyScaling {
     fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64>;
 }
 
+/// Check that the reduced residual entropy is within the range of validity
+/// of the entropy scaling correlations.
+///
+/// Entropy scaling correlations are only defined for non-positive
+/// residual entropies.
+pub fn validate_residual_entropy(correlation: &str, s_res: f64) -> EosResult<()> {
+    if s_res.is_nan() || s_res > 0.0 {
+        return Err(EosError::InvalidState(
+            format!("{correlation} correlation"),
+            String::from("s_res"),
+            s_res,
+        ));
+    }
+    Ok(())
+}
+
 /// Dummy implementation for [EquationOfState](super::EquationOfState)s that only contain an ideal gas contribution.
 pub struct NoResidual(pub usize);
 
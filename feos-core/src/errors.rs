@@ -1,5 +1,8 @@
 use crate::parameter::ParameterError;
+use crate::state::DensityInitialization;
 use num_dual::linalg::LinAlgError;
+use quantity::{Pressure, Temperature};
+use std::fmt;
 use thiserror::Error;
 
 /// Error type for improperly defined states and convergence problems.
@@ -9,16 +12,67 @@ pub enum EosError {
     Error(String),
     #[error("`{0}` did not converge within the maximum number of iterations.")]
     NotConverged(String),
+    #[error("Critical point for given {specification} did not converge within {iterations} iterations: {condition} is not satisfied (residual = {residual:e}).")]
+    CriticalPointNotConverged {
+        /// The quantity that is specified in the calculation.
+        specification: CriticalPointSpecification,
+        /// The criticality condition with the largest residual after the last iteration.
+        condition: CriticalityCondition,
+        /// The norm of the residual vector after the last iteration.
+        residual: f64,
+        /// The number of iterations performed.
+        iterations: usize,
+    },
     #[error("`{0}` encountered illegal values during the iteration.")]
     IterationFailed(String),
     #[error("Iteration resulted in trivial solution.")]
     TrivialSolution,
-    #[error("Equation of state is initialized for {0} components while the input specifies {1} components.")]
+    #[error("Equation of state has {0} components, but the calculation requires {1}.")]
     IncompatibleComponents(usize, usize),
+    #[error("Equation of state has {0} components, but the composition has {1} entries.")]
+    IncompatibleComposition(usize, usize),
+    #[error("Expected {expected} {name}, but {found} were provided.")]
+    IncompatibleLength {
+        /// The name of the input.
+        name: String,
+        /// The expected number of values.
+        expected: usize,
+        /// The number of values provided.
+        found: usize,
+    },
     #[error("Invalid state in {0}: {1} = {2}.")]
     InvalidState(String, String, f64),
+    #[error("{0} requires an equation of state with molar weights.")]
+    MissingMolarWeight(String),
     #[error("Undetermined state: {0}.")]
     UndeterminedState(String),
+    #[error("Density iteration failed for T = {0}, p = {1} (initialization: {2}): {3}")]
+    DensityIteration(
+        Temperature,
+        Pressure,
+        DensityInitialization,
+        #[source] Box<EosError>,
+    ),
+    #[error("State {index} of the grid failed: {source}")]
+    GridPointFailed {
+        /// The index of the failed state in the grid.
+        index: usize,
+        /// The error that occurred during the calculation of the state.
+        #[source]
+        source: Box<EosError>,
+    },
+    #[error("Unknown Helmholtz energy contribution `{0}`.")]
+    UnknownContribution(String),
+    #[cfg(feature = "dev")]
+    #[error("{property}: dual numbers ({dual}) and finite differences ({finite_difference}) do not agree.")]
+    DerivativeMismatch {
+        /// The property that was compared.
+        property: String,
+        /// The value calculated using dual numbers.
+        dual: f64,
+        /// The value calculated using finite differences.
+        finite_difference: f64,
+    },
     #[error("System is supercritical.")]
     SuperCritical,
     #[error("No phase split according to stability analysis.")]
@@ -34,5 +88,60 @@ pub enum EosError {
     RayonError(#[from] rayon::ThreadPoolBuildError),
 }
 
+/// The quantity that is specified in a critical point calculation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CriticalPointSpecification {
+    /// Critical point of a binary system at given temperature.
+    Temperature,
+    /// Critical point of a binary system at given pressure.
+    Pressure,
+    /// Critical point at given composition.
+    Composition,
+}
+
+impl fmt::Display for CriticalPointSpecification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Temperature => write!(f, "temperature"),
+            Self::Pressure => write!(f, "pressure"),
+            Self::Composition => write!(f, "composition"),
+        }
+    }
+}
+
+/// The conditions that are solved for in a critical point calculation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CriticalityCondition {
+    /// The smallest eigenvalue of the stability matrix vanishes.
+    StabilityLimit,
+    /// The third derivative along the corresponding eigenvector vanishes.
+    ThirdDerivative,
+    /// The pressure equals the specified pressure.
+    Pressure,
+}
+
+impl CriticalityCondition {
+    /// The condition with the largest absolute residual, where the residuals
+    /// are ordered as stability limit, third derivative and pressure.
+    pub(crate) fn from_residuals(residuals: &[f64]) -> Self {
+        let conditions = [Self::StabilityLimit, Self::ThirdDerivative, Self::Pressure];
+        residuals
+            .iter()
+            .zip(conditions)
+            .max_by(|(r1, _), (r2, _)| r1.abs().total_cmp(&r2.abs()))
+            .map_or(Self::StabilityLimit, |(_, c)| c)
+    }
+}
+
+impl fmt::Display for CriticalityCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StabilityLimit => write!(f, "stability limit"),
+            Self::ThirdDerivative => write!(f, "third derivative"),
+            Self::Pressure => write!(f, "pressure specification"),
+        }
+    }
+}
+
 /// Convenience type for `Result<T, EosError>`.
 pub type EosResult<T> = Result<T, EosError>;
@@ -27,20 +27,24 @@ macro_rules! log_result {
 
 pub mod cubic;
 mod density_iteration;
+#[cfg(feature = "dev")]
+pub mod dev;
 mod equation_of_state;
 mod errors;
 pub mod parameter;
 mod phase_equilibria;
 mod state;
 pub use equation_of_state::{
-    Components, EntropyScaling, EquationOfState, IdealGas, Molarweight, NoResidual, Residual,
+    validate_residual_entropy, Components, EntropyScaling, EquationOfState, IdealGas, Molarweight,
+    NoResidual, ReferenceState, Residual,
 };
-pub use errors::{EosError, EosResult};
+pub use errors::{CriticalPointSpecification, CriticalityCondition, EosError, EosResult};
 pub use phase_equilibria::{
     PhaseDiagram, PhaseDiagramHetero, PhaseEquilibrium, TemperatureOrPressure,
 };
 pub use state::{
-    Contributions, DensityInitialization, Derivative, State, StateBuilder, StateHD, StateVec,
+    Contributions, DPSpec, DensityInitialization, Derivative, PhaseIdentification, State,
+    StateBuilder, StateHD, StateVec,
 };
 
 #[cfg(feature = "python")]
@@ -68,7 +72,7 @@ impl Default for Verbosity {
 ///
 /// If the values are [None], solver specific default
 /// values are used.
-#[derive(Copy, Clone, Default)]
+#[derive(Copy, Clone, Default, PartialEq)]
 pub struct SolverOptions {
     /// Maximum number of iterations.
     pub max_iter: Option<usize>,
@@ -117,6 +121,18 @@ impl SolverOptions {
     }
 }
 
+/// Convergence statistics of an iterative solver.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+pub struct SolverStats {
+    /// Number of iterations.
+    pub iterations: usize,
+    /// Norm of the residual in the last iteration.
+    pub residual: f64,
+    /// Whether the residual is below the tolerance.
+    pub converged: bool,
+}
+
 /// Reference values used for reduced properties in feos
 const REFERENCE_VALUES: [f64; 7] = [
     1e-12,               // 1 ps
@@ -128,6 +144,19 @@ const REFERENCE_VALUES: [f64; 7] = [
     1.0,                 // 1 Cd
 ];
 
+/// Return the reference value of a unit given by the exponents of the
+/// SI base units in the order s, m, kg, A, K, mol, cd.
+///
+/// Dividing the SI value of a quantity by its reference value gives
+/// the quantity in reduced units.
+pub fn reference_value(exponents: [i32; 7]) -> f64 {
+    REFERENCE_VALUES
+        .iter()
+        .zip(exponents)
+        .map(|(r, e)| r.powi(e))
+        .product()
+}
+
 pub trait ReferenceSystem<Inner> {
     fn from_reduced(value: Inner) -> Self
     where
@@ -210,7 +239,7 @@ mod tests {
     use approx::*;
     use ndarray::Array1;
     use num_dual::DualNum;
-    use quantity::{BAR, KELVIN, MOL, RGAS};
+    use quantity::{BAR, KELVIN, MOL, PASCAL, RGAS};
     use std::sync::Arc;
 
     // Only to be able to instantiate an `EquationOfState`
@@ -274,6 +303,23 @@ mod tests {
         serde_json::from_str(records).expect("Unable to parse json.")
     }
 
+    #[test]
+    fn reference_value() {
+        use crate::ReferenceSystem;
+        use quantity::Pressure;
+
+        // Pa = kg / (m s^2)
+        let p_ref = super::reference_value([-2, -1, 1, 0, 0, 0, 0]);
+        assert_relative_eq!(
+            Pressure::from_reduced(1.0),
+            p_ref * PASCAL,
+            max_relative = 1e-14
+        );
+        let p = 12.3 * BAR;
+        assert_relative_eq!(p.to_reduced(), (p / PASCAL).into_value() / p_ref);
+        assert_relative_eq!(p.to_reduced() * p_ref * PASCAL, p, max_relative = 1e-14);
+    }
+
     #[test]
     fn validate_residual_properties() -> EosResult<()> {
         let mixture = pure_record_vec();
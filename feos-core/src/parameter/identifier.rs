@@ -125,6 +125,26 @@ impl Hash for Identifier {
     }
 }
 
+/// Labels for the components of a mixture.
+///
+/// Uses the name of each component and falls back to `x{i}` if no
+/// identifiers are available or the name is missing or not unique.
+pub fn component_labels(identifiers: Option<&[Identifier]>, components: usize) -> Vec<String> {
+    let names: Vec<_> = (0..components)
+        .map(|i| identifiers.and_then(|ids| ids.get(i)?.name.as_deref()))
+        .collect();
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| match name {
+            Some(name) if names.iter().filter(|&n| n == &Some(*name)).count() == 1 => {
+                name.to_string()
+            }
+            _ => format!("x{i}"),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -134,4 +154,15 @@ mod test {
         let id = Identifier::new(None, Some("acetone"), None, Some("CC(=O)C"), None, None);
         assert_eq!(id.to_string(), "Identifier(name=acetone, smiles=CC(=O)C)");
     }
+
+    #[test]
+    fn test_component_labels() {
+        let acetone = Identifier::new(None, Some("acetone"), None, None, None, None);
+        let unnamed = Identifier::new(Some("7732-18-5"), None, None, None, None, None);
+        let ids = [acetone.clone(), unnamed];
+        assert_eq!(component_labels(Some(&ids), 2), vec!["acetone", "x1"]);
+        assert_eq!(component_labels(None, 2), vec!["x0", "x1"]);
+        let ids = [acetone.clone(), acetone];
+        assert_eq!(component_labels(Some(&ids), 2), vec!["x0", "x1"]);
+    }
 }
@@ -5,6 +5,7 @@ use indexmap::{IndexMap, IndexSet};
 use ndarray::Array2;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 use std::fs::File;
@@ -19,7 +20,7 @@ mod model_record;
 mod segment;
 
 pub use chemical_record::{ChemicalRecord, SegmentCount};
-pub use identifier::{Identifier, IdentifierOption};
+pub use identifier::{component_labels, Identifier, IdentifierOption};
 pub use model_record::{BinaryRecord, FromSegments, FromSegmentsBinary, PureRecord};
 pub use segment::SegmentRecord;
 
@@ -53,12 +54,10 @@ where
         binary_record: Option<Self::Binary>,
     ) -> Result<Self, ParameterError> {
         let binary_record = binary_record.map(|br| {
-            Array2::from_shape_fn([2, 2], |(i, j)| {
-                if i == j {
-                    Self::Binary::default()
-                } else {
-                    br.clone()
-                }
+            Array2::from_shape_fn([2, 2], |(i, j)| match i.cmp(&j) {
+                Ordering::Less => br.clone(),
+                Ordering::Equal => Self::Binary::default(),
+                Ordering::Greater => Self::reverse_binary_record(br.clone()),
             })
         });
         Self::from_records(pure_records, binary_record)
@@ -74,6 +73,14 @@ where
         Self::from_records(pure_records, None)
     }
 
+    /// Returns the binary record for the two components in reversed order.
+    ///
+    /// Only has to be implemented if the binary interaction parameters
+    /// are not symmetric with respect to the order of the components.
+    fn reverse_binary_record(binary_record: Self::Binary) -> Self::Binary {
+        binary_record
+    }
+
     /// Return the original pure and binary records that were used to construct the parameters.
     #[allow(clippy::type_complexity)]
     fn records(&self) -> (&[PureRecord<Self::Pure>], Option<&Array2<Self::Binary>>);
@@ -121,8 +128,13 @@ where
                 ));
             binary_map
                 .get(&(id1.clone(), id2.clone()))
-                .or_else(|| binary_map.get(&(id2, id1)))
                 .cloned()
+                .or_else(|| {
+                    binary_map
+                        .get(&(id2, id1))
+                        .cloned()
+                        .map(Self::reverse_binary_record)
+                })
                 .unwrap_or_default()
         }))
     }
@@ -249,7 +261,7 @@ where
                 }
                 let kij = Self::Binary::from_segments_binary(&vec)?;
                 binary_records[(i, j)] = kij.clone();
-                binary_records[(j, i)] = kij;
+                binary_records[(j, i)] = Self::reverse_binary_record(kij);
             }
         }
 
@@ -492,6 +504,10 @@ pub enum ParameterError {
     IdentifierNotFound(String),
     #[error("Information missing.")]
     InsufficientInformation,
+    #[error("Missing {0}.")]
+    MissingParameters(String),
     #[error("Incompatible parameters: {0}")]
     IncompatibleParameters(String),
+    #[error("Invalid CSV file: {0}")]
+    InvalidCsv(String),
 }
@@ -58,6 +58,59 @@ impl<M> PureRecord<M> {
     where
         P: AsRef<Path>,
         M: Clone + DeserializeOwned,
+    {
+        let f = File::open(file)?;
+        let reader = BufReader::new(f);
+        // use stream in the future
+        let file_records: Vec<Self> = serde_json::from_reader(reader)?;
+        Self::select(substances, file_records, identifier_option)
+    }
+
+    /// Create pure substance parameters from multiple json files.
+    ///
+    /// The records of all files are merged before the substances are
+    /// searched. Returns an error if a queried substance is defined more
+    /// than once, either in different files or within the same file.
+    pub fn from_multiple_json<P>(
+        substances: &[&str],
+        files: &[P],
+        identifier_option: IdentifierOption,
+    ) -> Result<Vec<Self>, ParameterError>
+    where
+        P: AsRef<Path>,
+        M: Clone + DeserializeOwned,
+    {
+        let mut origin: HashMap<String, &Path> = HashMap::new();
+        let mut file_records = Vec::new();
+        for file in files {
+            let file = file.as_ref();
+            let records: Vec<Self> = serde_json::from_reader(BufReader::new(File::open(file)?))?;
+            for record in records {
+                if let Some(id) = record.identifier.as_string(identifier_option) {
+                    if substances.contains(&id.as_str()) {
+                        if let Some(other) = origin.insert(id.clone(), file) {
+                            return Err(ParameterError::IncompatibleParameters(format!(
+                                "Substance '{id}' is defined in both '{}' and '{}'.",
+                                other.display(),
+                                file.display()
+                            )));
+                        }
+                    }
+                }
+                file_records.push(record);
+            }
+        }
+        Self::select(substances, file_records, identifier_option)
+    }
+
+    /// Select the queried substances from a list of records in the order of `substances`.
+    fn select(
+        substances: &[&str],
+        file_records: Vec<Self>,
+        identifier_option: IdentifierOption,
+    ) -> Result<Vec<Self>, ParameterError>
+    where
+        M: Clone,
     {
         // create list of substances
         let mut queried: HashSet<String> = substances.iter().map(|s| s.to_string()).collect();
@@ -68,10 +121,6 @@ impl<M> PureRecord<M> {
             ));
         }
 
-        let f = File::open(file)?;
-        let reader = BufReader::new(f);
-        // use stream in the future
-        let file_records: Vec<Self> = serde_json::from_reader(reader)?;
         let mut records: HashMap<String, Self> = HashMap::with_capacity(substances.len());
 
         // build map, draining list of queried substances in the process
@@ -87,7 +136,11 @@ impl<M> PureRecord<M> {
 
         // report missing parameters
         if !queried.is_empty() {
-            return Err(ParameterError::ComponentsNotFound(format!("{:?}", queried)));
+            let missing: Vec<_> = substances
+                .iter()
+                .filter(|&&s| queried.contains(s))
+                .collect();
+            return Err(ParameterError::ComponentsNotFound(format!("{:?}", missing)));
         };
 
         // collect into vec in correct order
@@ -1,6 +1,7 @@
 use super::ParameterError;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
 use std::hash::{Hash, Hasher};
 use std::io::BufReader;
@@ -31,6 +32,32 @@ impl<M> SegmentRecord<M> {
     {
         Ok(serde_json::from_reader(BufReader::new(File::open(file)?))?)
     }
+
+    /// Read and merge lists of `SegmentRecord`s from multiple JSON files.
+    ///
+    /// Returns an error if a segment is defined in more than one file.
+    pub fn from_multiple_json<P: AsRef<Path>>(files: &[P]) -> Result<Vec<Self>, ParameterError>
+    where
+        M: DeserializeOwned,
+    {
+        let mut origin: HashMap<String, &Path> = HashMap::new();
+        let mut records = Vec::new();
+        for file in files {
+            let file = file.as_ref();
+            for record in Self::from_json(file)? {
+                if let Some(other) = origin.insert(record.identifier.clone(), file) {
+                    return Err(ParameterError::IncompatibleParameters(format!(
+                        "Segment '{}' is defined in both '{}' and '{}'.",
+                        record.identifier,
+                        other.display(),
+                        file.display()
+                    )));
+                }
+                records.push(record);
+            }
+        }
+        Ok(records)
+    }
 }
 
 impl<M> Hash for SegmentRecord<M> {
@@ -1,12 +1,18 @@
-use crate::equation_of_state::Residual;
+use crate::equation_of_state::{IdealGas, Molarweight, Residual};
 use crate::errors::{EosError, EosResult};
+use crate::parameter::{component_labels, Identifier};
 use crate::state::{DensityInitialization, State};
 use crate::{Contributions, ReferenceSystem};
 use ndarray::Array1;
-use quantity::{Dimensionless, Energy, Moles, Pressure, Temperature, RGAS};
+use quantity::{
+    Dimensionless, Energy, MolarEnergy, MolarEntropy, Moles, Pressure, Temperature, JOULE, KELVIN,
+    KILO, KILOGRAM, METER, MOL, PASCAL, RGAS,
+};
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Write;
 use std::sync::Arc;
+use typenum::P3;
 
 mod bubble_dew;
 mod phase_diagram_binary;
@@ -90,6 +96,11 @@ impl<E> PhaseEquilibrium<E, 2> {
     pub fn liquid(&self) -> &State<E> {
         &self.0[1]
     }
+
+    /// Distribution coefficients: $K_i=\frac{y_i}{x_i}$
+    pub fn k_values(&self) -> Array1<f64> {
+        &self.vapor().molefracs / &self.liquid().molefracs
+    }
 }
 
 impl<E> PhaseEquilibrium<E, 3> {
@@ -152,6 +163,98 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
     }
 }
 
+impl<E: Residual + IdealGas> PhaseEquilibrium<E, 2> {
+    /// Enthalpy of vaporization: $\Delta h^\text{vap}=h^V-h^L$
+    ///
+    /// For mixtures, the difference between the molar enthalpies
+    /// of the vapor and the liquid phase at their respective compositions.
+    pub fn enthalpy_of_vaporization(&self) -> MolarEnergy {
+        self.vapor().molar_enthalpy(Contributions::Total)
+            - self.liquid().molar_enthalpy(Contributions::Total)
+    }
+
+    /// Entropy of vaporization: $\Delta s^\text{vap}=s^V-s^L$
+    ///
+    /// For mixtures, the difference between the molar entropies
+    /// of the vapor and the liquid phase at their respective compositions.
+    pub fn entropy_of_vaporization(&self) -> MolarEntropy {
+        self.vapor().molar_entropy(Contributions::Total)
+            - self.liquid().molar_entropy(Contributions::Total)
+    }
+}
+
+impl<E: Residual + Molarweight + IdealGas> PhaseEquilibrium<E, 2> {
+    /// Return the properties of both phases as a map from property names
+    /// to values.
+    ///
+    /// The keys are the property names used in [StateVec::to_dict](crate::StateVec::to_dict)
+    /// prefixed with `vapor` or `liquid`, e.g., `"vapor molar enthalpy"`, and
+    /// the values are given in the same units. For mixtures, the mole fractions
+    /// are keyed by the component labels obtained from the `identifiers`
+    /// (see [component_labels]). The mass-based properties are only included
+    /// if molar weights are available.
+    pub fn to_dict(
+        &self,
+        contributions: Contributions,
+        identifiers: Option<&[Identifier]>,
+    ) -> HashMap<String, f64> {
+        let eos = &self.vapor().eos;
+        let n = eos.components();
+        let labels = component_labels(identifiers, n);
+        let mut dict = HashMap::with_capacity(2 * (8 + n));
+        for (phase, state) in [("vapor", self.vapor()), ("liquid", self.liquid())] {
+            if n != 1 {
+                for (label, &x) in labels.iter().zip(state.molefracs.iter()) {
+                    dict.insert(format!("{phase} {label}"), x);
+                }
+            }
+            let mut insert = |key: &str, value: f64| dict.insert(format!("{phase} {key}"), value);
+            insert("temperature", state.temperature.convert_to(KELVIN));
+            insert(
+                "pressure",
+                state.pressure(Contributions::Total).convert_to(PASCAL),
+            );
+            insert(
+                "density",
+                state.density.convert_to(MOL / METER.powi::<P3>()),
+            );
+            insert(
+                "molar enthalpy",
+                state
+                    .molar_enthalpy(contributions)
+                    .convert_to(KILO * JOULE / MOL),
+            );
+            insert(
+                "molar entropy",
+                state
+                    .molar_entropy(contributions)
+                    .convert_to(KILO * JOULE / KELVIN / MOL),
+            );
+            if eos.has_molar_weight() {
+                insert(
+                    "mass density",
+                    state
+                        .mass_density()
+                        .convert_to(KILOGRAM / METER.powi::<P3>()),
+                );
+                insert(
+                    "specific enthalpy",
+                    state
+                        .specific_enthalpy(contributions)
+                        .convert_to(KILO * JOULE / KILOGRAM),
+                );
+                insert(
+                    "specific entropy",
+                    state
+                        .specific_entropy(contributions)
+                        .convert_to(KILO * JOULE / KELVIN / KILOGRAM),
+                );
+            }
+        }
+        dict
+    }
+}
+
 impl<E: Residual, const N: usize> PhaseEquilibrium<E, N> {
     pub(super) fn update_pressure(
         mut self,
@@ -355,6 +355,78 @@ impl<E: Residual> PhaseEquilibrium<E, 3> {
         }
     }
 
+    /// Calculate the vapor-liquid-liquid equilibrium of a binary system
+    /// for a given temperature.
+    ///
+    /// A liquid with the composition of `feed` is checked for stability
+    /// at the sum of the pure component vapor pressures. If the liquid is
+    /// unstable, the liquid-liquid equilibrium obtained from the stability
+    /// analysis is used as initial guess for the solution of the equal
+    /// fugacity conditions in all three phases. The pressure follows from
+    /// the phase rule. If `feed` does not lie within the miscibility gap,
+    /// [EosError::NoPhaseSplit] is returned.
+    pub fn vlle(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        feed: &Moles<Array1<f64>>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+
+        // the three phase pressure of (nearly) immiscible liquids is the sum
+        // of the vapor pressures of the pure components
+        let pressure = PhaseEquilibrium::vapor_pressure(eos, temperature)
+            .into_iter()
+            .try_fold(Pressure::from_reduced(0.0), |acc, p| p.map(|p| acc + p))
+            .ok_or(EosError::SuperCritical)?;
+
+        // find the second liquid phase using a stability analysis
+        let liquid = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            feed,
+            DensityInitialization::Liquid,
+        )?;
+        let trial_phase = liquid
+            .stability_analysis(options)?
+            .into_iter()
+            .max_by(|s1, s2| s1.density.to_reduced().total_cmp(&s2.density.to_reduced()))
+            .ok_or(EosError::NoPhaseSplit)?;
+        let init = PhaseEquilibrium::from_states(trial_phase, liquid);
+        let lle = PhaseEquilibrium::tp_flash(
+            eos,
+            temperature,
+            pressure,
+            feed,
+            Some(&init),
+            options,
+            None,
+        )?;
+        let x1 = lle.vapor().molefracs[0];
+        let x2 = lle.liquid().molefracs[0];
+
+        // solve for the three phase equilibrium
+        let vlle = Self::heteroazeotrope_t(
+            eos,
+            temperature,
+            (x1, x2),
+            Some(pressure),
+            options,
+            (SolverOptions::default(), SolverOptions::default()),
+        )?;
+        let [v, l1, l2] = &vlle.0;
+        if PhaseEquilibrium::is_trivial_solution(v, l1)
+            || PhaseEquilibrium::is_trivial_solution(v, l2)
+            || PhaseEquilibrium::is_trivial_solution(l1, l2)
+        {
+            return Err(EosError::TrivialSolution);
+        }
+        Ok(vlle)
+    }
+
     /// Calculate a heteroazeotrope (three phase equilbrium) for a binary
     /// system and given temperature.
     fn heteroazeotrope_t(
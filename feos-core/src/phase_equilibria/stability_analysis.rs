@@ -27,15 +27,79 @@ impl<E: Residual> State<E> {
     /// Perform a stability analysis. The result is a list of [State]s with
     /// negative tangent plane distance (i.e. lower Gibbs energy) that can be
     /// used as initial estimates for a phase equilibrium calculation.
+    ///
+    /// A nearly pure liquid phase of every component and an ideal vapor phase
+    /// are used as trial phases.
     pub fn stability_analysis(&self, options: SolverOptions) -> EosResult<Vec<State<E>>> {
+        let trial_states = (0..self.eos.components() + 1)
+            .map(|i_trial| {
+                let phase = if i_trial == self.eos.components() {
+                    "Vapor phase".to_string()
+                } else {
+                    format!("Liquid phase {}", i_trial + 1)
+                };
+                (phase, self.define_trial_state(i_trial))
+            })
+            .collect();
+        self.minimize_trial_states(trial_states, options)
+    }
+
+    /// Perform a stability analysis starting from the given trial phases.
+    ///
+    /// The trial phases are specified as pairs of mole fractions and
+    /// [DensityInitialization]s, e.g., to find very dense phases that are
+    /// missed by the default initialization of [State::stability_analysis].
+    /// The mole fractions are normalized and must be finite, non-negative
+    /// and not all zero.
+    pub fn stability_analysis_with_trial_states(
+        &self,
+        trial_states: &[(Array1<f64>, DensityInitialization)],
+        options: SolverOptions,
+    ) -> EosResult<Vec<State<E>>> {
+        for (x, _) in trial_states {
+            if x.len() != self.eos.components() {
+                return Err(EosError::IncompatibleComposition(
+                    self.eos.components(),
+                    x.len(),
+                ));
+            }
+            if let Some(&xi) = x.iter().find(|xi| !xi.is_finite() || xi.is_sign_negative()) {
+                return Err(EosError::InvalidState(
+                    String::from("stability analysis"),
+                    String::from("molefracs"),
+                    xi,
+                ));
+            }
+            let sum = x.sum();
+            if sum <= 0.0 {
+                return Err(EosError::InvalidState(
+                    String::from("stability analysis"),
+                    String::from("sum of molefracs"),
+                    sum,
+                ));
+            }
+        }
+        let trial_states = trial_states
+            .iter()
+            .enumerate()
+            .map(|(i, (x, density_initialization))| {
+                (
+                    format!("Trial phase {}", i + 1),
+                    self.trial_state(x / x.sum(), *density_initialization),
+                )
+            })
+            .collect();
+        self.minimize_trial_states(trial_states, options)
+    }
+
+    fn minimize_trial_states(
+        &self,
+        trial_states: Vec<(String, EosResult<State<E>>)>,
+        options: SolverOptions,
+    ) -> EosResult<Vec<State<E>>> {
         let mut result = Vec::new();
-        for i_trial in 0..self.eos.components() + 1 {
-            let phase = if i_trial == self.eos.components() {
-                "Vapor phase".to_string()
-            } else {
-                format!("Liquid phase {}", i_trial + 1)
-            };
-            if let Ok(mut trial_state) = self.define_trial_state(i_trial) {
+        for (phase, trial_state) in trial_states {
+            if let Ok(mut trial_state) = trial_state {
                 let (tpd, i) = self.minimize_tpd(&mut trial_state, options)?;
                 let msg = if let Some(tpd) = tpd {
                     if tpd < ZERO_TPD {
@@ -82,12 +146,20 @@ impl<E: Residual> State<E> {
             )
         };
 
+        self.trial_state(x_trial, phase)
+    }
+
+    fn trial_state(
+        &self,
+        x_trial: Array1<f64>,
+        density_initialization: DensityInitialization,
+    ) -> EosResult<State<E>> {
         State::new_npt(
             &self.eos,
             self.temperature,
             self.pressure(Contributions::Total),
             &Moles::from_reduced(x_trial),
-            phase,
+            density_initialization,
         )
     }
 
@@ -1,16 +1,19 @@
 use super::PhaseEquilibrium;
-use crate::equation_of_state::Residual;
+use crate::equation_of_state::{IdealGas, Residual};
 use crate::errors::{EosError, EosResult};
 use crate::state::{Contributions, DensityInitialization, State};
-use crate::{SolverOptions, Verbosity};
+use crate::{ReferenceSystem, SolverOptions, Verbosity};
 use ndarray::*;
 use num_dual::linalg::norm;
-use quantity::{Dimensionless, Moles, Pressure, Temperature};
+use quantity::{Dimensionless, MolarEnergy, Moles, Pressure, Temperature};
 use std::sync::Arc;
 
 const MAX_ITER_TP: usize = 400;
 const TOL_TP: f64 = 1e-8;
 
+const MAX_ITER_PH: usize = 50;
+const TOL_PH: f64 = 1e-8;
+
 /// # Flash calculations
 impl<E: Residual> PhaseEquilibrium<E, 2> {
     /// Perform a Tp-flash calculation. If no initial values are
@@ -38,6 +41,147 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
     }
 }
 
+/// # Flash calculations
+impl<E: Residual + IdealGas> PhaseEquilibrium<E, 2> {
+    /// Perform a ph-flash calculation, i.e., determine the temperature
+    /// and the phase split of a feed for given pressure and molar enthalpy.
+    ///
+    /// For mixtures, the temperature is updated with Newton steps,
+    /// safeguarded by bisection, and a Tp-flash is performed in every
+    /// iteration. For pure components, the vapor phase fraction is
+    /// determined from the enthalpies of the saturated phases at the
+    /// given pressure.
+    ///
+    /// If the feed is a single phase at the specified conditions,
+    /// [EosError::NoPhaseSplit] is returned. The single phase state
+    /// can be calculated using [State::new_nph].
+    pub fn ph_flash(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        molar_enthalpy: MolarEnergy,
+        feed: &Moles<Array1<f64>>,
+        initial_temperature: Temperature,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        if eos.components() == 1 {
+            let mut vle = Self::pure(eos, pressure, None, options)?;
+            let h_vapor = vle.vapor().molar_enthalpy(Contributions::Total);
+            let h_liquid = vle.liquid().molar_enthalpy(Contributions::Total);
+            let beta = ((molar_enthalpy - h_liquid) / (h_vapor - h_liquid)).into_value();
+            if !(0.0 < beta && beta < 1.0) {
+                return Err(EosError::NoPhaseSplit);
+            }
+            vle.update_moles(pressure, [&(feed * beta), &(feed * (1.0 - beta))])?;
+            return Ok(vle);
+        }
+
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_PH, TOL_PH);
+        let h = molar_enthalpy.to_reduced();
+        let n = feed.sum();
+        let mut t = initial_temperature.to_reduced();
+        let mut vle: Option<Self> = None;
+        let mut bracket = (None, None);
+        let mut previous = None;
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |   temperature   | vapor phase fraction"
+        );
+        log_iter!(verbosity, "{:-<63}", "");
+
+        for i in 1..=max_iter {
+            let state = State::new_npt(
+                eos,
+                Temperature::from_reduced(t),
+                pressure,
+                feed,
+                DensityInitialization::None,
+            )?;
+            let (h_t, c_p, beta) = match state.tp_flash(vle.as_ref(), Default::default(), None) {
+                Ok(v) => {
+                    let c = Contributions::Total;
+                    let h_t = (v.vapor().enthalpy(c) + v.liquid().enthalpy(c)) / n;
+                    let c_p = (v.vapor().total_moles * v.vapor().molar_isobaric_heat_capacity(c)
+                        + v.liquid().total_moles * v.liquid().molar_isobaric_heat_capacity(c))
+                        / n;
+                    let beta = v.vapor_phase_fraction();
+                    vle = Some(v);
+                    (h_t.to_reduced(), c_p.to_reduced(), beta)
+                }
+                Err(_) => {
+                    let c = Contributions::Total;
+                    let h_t = state.molar_enthalpy(c).to_reduced();
+                    let c_p = state.molar_isobaric_heat_capacity(c).to_reduced();
+                    vle = None;
+                    (h_t, c_p, f64::NAN)
+                }
+            };
+
+            let residual = (h_t - h) / t;
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:13.8} | {:.8}",
+                i,
+                residual.abs(),
+                Temperature::from_reduced(t),
+                beta,
+            );
+            if residual.abs() < tol {
+                log_result!(verbosity, "ph-flash converged in {} step(s)\n", i);
+                return vle.ok_or(EosError::NoPhaseSplit);
+            }
+
+            // Newton step using the secant slope if available
+            if residual < 0.0 {
+                bracket.0 = Some(t);
+            } else {
+                bracket.1 = Some(t);
+            }
+            let slope = match previous {
+                Some((t0, h0)) if t0 != t && (h_t - h0) / (t - t0) > 0.0 => (h_t - h0) / (t - t0),
+                _ => c_p,
+            };
+            previous = Some((t, h_t));
+            let mut delta = (h_t - h) / slope;
+            if delta.abs() > 0.25 * t {
+                delta *= 0.25 * t / delta.abs();
+            }
+            t -= delta;
+
+            // bisection if the step leaves the bracket
+            if let (Some(t_low), Some(t_high)) = bracket {
+                if !(t_low < t && t < t_high) {
+                    t = 0.5 * (t_low + t_high);
+                }
+            }
+        }
+        Err(EosError::NotConverged(String::from("ph-flash")))
+    }
+}
+
+/// # Flash calculations
+impl<E: Residual + IdealGas> State<E> {
+    /// Perform a ph-flash calculation for the isenthalpic expansion
+    /// (throttling) of the [State] to the given pressure.
+    ///
+    /// The temperature of the [State] is used as initial guess.
+    /// See [PhaseEquilibrium::ph_flash] for details.
+    pub fn ph_flash(
+        &self,
+        pressure: Pressure,
+        options: SolverOptions,
+    ) -> EosResult<PhaseEquilibrium<E, 2>> {
+        PhaseEquilibrium::ph_flash(
+            &self.eos,
+            pressure,
+            self.molar_enthalpy(Contributions::Total),
+            &self.moles,
+            self.temperature,
+            options,
+        )
+    }
+}
+
 /// # Flash calculations
 impl<E: Residual> State<E> {
     /// Perform a Tp-flash calculation using the [State] as feed.
@@ -4,12 +4,14 @@ use crate::errors::{EosError, EosResult};
 use crate::state::{Contributions, DensityInitialization, State, TPSpec};
 use crate::{ReferenceSystem, SolverOptions, TemperatureOrPressure, Verbosity};
 use ndarray::{arr1, Array1};
-use quantity::{Moles, Pressure, Temperature, RGAS};
+use quantity::{Density, Moles, Pressure, Temperature, RGAS};
 use std::sync::Arc;
 
 const SCALE_T_NEW: f64 = 0.7;
 const MAX_ITER_PURE: usize = 50;
 const TOL_PURE: f64 = 1e-12;
+const TOL_MAXWELL: f64 = 1e-10;
+const N_INTERVALS_MAXWELL: usize = 500;
 
 /// # Pure component phase equilibria
 impl<E: Residual> PhaseEquilibrium<E, 2> {
@@ -255,6 +257,80 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
         Err(EosError::NotConverged("pure_p".to_owned()))
     }
 
+    /// Calculate the saturation pressure and the vapor and liquid density
+    /// of a pure component using the Maxwell (equal-area) construction.
+    ///
+    /// In contrast to [PhaseEquilibrium::pure], which solves for equal
+    /// chemical potentials, the saturation pressure is determined such that
+    /// the areas enclosed by the isotherm above and below the saturation
+    /// pressure are equal, i.e.,
+    /// $\int_{v^L}^{v^V}p\,\mathrm{d}v=p^\mathrm{sat}\left(v^V-v^L\right)$.
+    /// The integral is evaluated numerically, which makes the method useful
+    /// to cross-check phase equilibria, but slower than [PhaseEquilibrium::pure].
+    ///
+    /// The densities are returned in the order `[vapor, liquid]`.
+    pub fn maxwell_construction(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        options: SolverOptions,
+    ) -> EosResult<(Pressure, [Density; 2])> {
+        if eos.components() != 1 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 1));
+        }
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_PURE, TOL_MAXWELL);
+
+        // the saturation pressure lies between the pressures of the spinodals
+        let m = Moles::from_reduced(arr1(&[1.0]));
+        let [sp_v, sp_l] = State::spinodal(eos, temperature, Some(&m), Default::default())?;
+        let p_max = sp_v.pressure(Contributions::Total);
+        let p_min = Pressure::from_reduced(0.0).max(sp_l.pressure(Contributions::Total));
+        let mut p = 0.5 * (p_min + p_max);
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |     pressure     |    liquid density    |    vapor density     "
+        );
+        log_iter!(verbosity, "{:-<89}", "");
+
+        for i in 1..=max_iter {
+            let vle = Self::new_npt(eos, temperature, p, &m, &m)?.check_trivial_solution()?;
+            let rho_v = vle.vapor().density;
+            let rho_l = vle.liquid().density;
+
+            // the new pressure is the mean value of the isotherm between both phases
+            let area = isotherm_area(eos, temperature, rho_v, rho_l)?;
+            let delta_v = (1.0 / rho_v - 1.0 / rho_l).to_reduced();
+            let mut p_new = Pressure::from_reduced(area / delta_v);
+            if p_new >= p_max {
+                p_new = 0.5 * (p + p_max);
+            } else if p_new <= p_min {
+                p_new = 0.5 * (p + p_min);
+            }
+
+            let res = ((p_new - p) / p).into_value().abs();
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:12.8} | {:12.8} | {:12.8}",
+                i,
+                res,
+                p_new,
+                rho_l,
+                rho_v
+            );
+            p = p_new;
+            if res < tol {
+                log_result!(
+                    verbosity,
+                    "PhaseEquilibrium::maxwell_construction: calculation converged in {} step(s)\n",
+                    i
+                );
+                let vle = Self::new_npt(eos, temperature, p, &m, &m)?;
+                return Ok((p, [vle.vapor().density, vle.liquid().density]));
+            }
+        }
+        Err(EosError::NotConverged("maxwell_construction".to_owned()))
+    }
+
     fn init_pure_state(initial_state: &Self, temperature: Temperature) -> EosResult<Self> {
         let vapor = initial_state.vapor().update_temperature(temperature)?;
         let liquid = initial_state.liquid().update_temperature(temperature)?;
@@ -360,6 +436,33 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
             .collect()
     }
 
+    /// Calculate the vapor pressure of a pure component for
+    /// multiple temperatures.
+    ///
+    /// The phase equilibrium at each temperature is used as initial
+    /// guess for the next one. Returns an error if any of the
+    /// temperatures is not below the critical temperature.
+    pub fn pure_vapor_pressure(
+        eos: &Arc<E>,
+        temperatures: &Temperature<Array1<f64>>,
+    ) -> EosResult<Pressure<Array1<f64>>> {
+        if eos.components() != 1 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 1));
+        }
+        let cp = State::critical_point(eos, None, None, SolverOptions::default())?;
+        let mut pressures = Array1::zeros(temperatures.len());
+        let mut vle = None;
+        for (i, t) in temperatures.into_iter().enumerate() {
+            if t >= cp.temperature {
+                return Err(EosError::SuperCritical);
+            }
+            let vle_t = PhaseEquilibrium::pure_t(eos, t, vle.as_ref(), SolverOptions::default())?;
+            pressures[i] = vle_t.vapor().pressure(Contributions::Total).to_reduced();
+            vle = Some(vle_t);
+        }
+        Ok(Pressure::from_reduced(pressures))
+    }
+
     /// Calculate the pure component boiling temperatures of all
     /// components in the system for the given pressure.
     pub fn boiling_temperature(eos: &Arc<E>, pressure: Pressure) -> Vec<Option<Temperature>> {
@@ -414,3 +517,34 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
             .collect()
     }
 }
+
+/// Integrate the pressure of a pure component along an isotherm between the
+/// vapor and the liquid density in reduced units.
+///
+/// The integral $\int p\,\mathrm{d}v=\int\frac{p}{\rho}\,\mathrm{d}\ln\rho$ is
+/// evaluated with Simpson's rule in the logarithm of the density.
+fn isotherm_area<E: Residual>(
+    eos: &Arc<E>,
+    temperature: Temperature,
+    vapor_density: Density,
+    liquid_density: Density,
+) -> EosResult<f64> {
+    let ln_rho_v = vapor_density.to_reduced().ln();
+    let ln_rho_l = liquid_density.to_reduced().ln();
+    let h = (ln_rho_l - ln_rho_v) / N_INTERVALS_MAXWELL as f64;
+    let mut area = 0.0;
+    for k in 0..=N_INTERVALS_MAXWELL {
+        let rho = (ln_rho_v + k as f64 * h).exp();
+        let state = State::new_pure(eos, temperature, Density::from_reduced(rho))?;
+        let f = state.pressure(Contributions::Total).to_reduced() / rho;
+        let weight = if k == 0 || k == N_INTERVALS_MAXWELL {
+            1.0
+        } else if k % 2 == 1 {
+            4.0
+        } else {
+            2.0
+        };
+        area += weight * f;
+    }
+    Ok(area * h / 3.0)
+}
@@ -18,6 +18,189 @@ macro_rules! impl_equation_of_state {
                 let m = moles.map(|m| m.try_into()).transpose()?;
                 Ok(self.0.max_density(m.as_ref())?.into())
             }
+
+            /// Check that the amount of substance is compatible with the model.
+            ///
+            /// For a pure component, `moles` can be omitted and is set
+            /// to the constant reference value.
+            ///
+            /// Parameters
+            /// ----------
+            /// moles : SIArray1, optional
+            ///     The amount of substance in mol for each component.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the number of elements in `moles` does not
+            ///     match the number of components.
+            #[pyo3(text_signature = "(moles=None)", signature = (moles=None))]
+            fn validate_moles(&self, moles: Option<Moles<Array1<f64>>>) -> PyResult<Moles<Array1<f64>>> {
+                let m = moles.map(|m| m.try_into()).transpose()?;
+                Ok(self.0.validate_moles(m.as_ref())?)
+            }
+
+            /// Evaluate the reduced residual Helmholtz energy per particle
+            /// for a given reduced temperature and reduced density.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : float
+            ///     The reduced temperature in K.
+            /// density : float
+            ///     The reduced density in Angstrom^-3.
+            /// molefracs : numpy.ndarray[float], optional
+            ///     The mole fractions of all components.
+            ///     Can be omitted for pure components.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            #[pyo3(text_signature = "(temperature, density, molefracs=None)", signature = (temperature, density, molefracs=None))]
+            fn evaluate_reduced(
+                &self,
+                temperature: f64,
+                density: f64,
+                molefracs: Option<&Bound<'_, PyArray1<f64>>>,
+            ) -> PyResult<f64> {
+                let x = molefracs.map(|x| x.to_owned_array());
+                Ok(self.0.evaluate_reduced(temperature, density, x.as_ref())?)
+            }
+
+            /// Calculate the pressure on a grid of temperatures and densities.
+            ///
+            /// The grid is evaluated in parallel.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperatures : SIArray1
+            ///     The temperatures of the grid.
+            /// densities : SIArray1
+            ///     The densities of the grid.
+            /// molefracs : numpy.ndarray[float], optional
+            ///     The mole fractions of all components.
+            ///     Can be omitted for pure components.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray2
+            ///     The pressure with shape (len(temperatures), len(densities)).
+            #[pyo3(text_signature = "(temperatures, densities, molefracs=None)", signature = (temperatures, densities, molefracs=None))]
+            fn pressure_grid(
+                &self,
+                temperatures: Temperature<Array1<f64>>,
+                densities: Density<Array1<f64>>,
+                molefracs: Option<&Bound<'_, PyArray1<f64>>>,
+            ) -> PyResult<Pressure<Array2<f64>>> {
+                let x = molefracs.map(|x| x.to_owned_array());
+                Ok(self.0.pressure_grid(&temperatures, &densities, x.as_ref())?)
+            }
+
+            /// Calculate the fugacity coefficients on a grid of temperatures and pressures.
+            ///
+            /// A state is calculated for every combination of temperature and
+            /// pressure at the same composition and density initialization.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperatures : SIArray1
+            ///     The temperatures of the grid.
+            /// pressures : SIArray1
+            ///     The pressures of the grid.
+            /// molefracs : numpy.ndarray[float], optional
+            ///     The mole fractions of all components.
+            ///     Can be omitted for pure components.
+            /// density_initialization : {'vapor', 'liquid', SINumber, None}, optional
+            ///     Method used to initialize the density iterations.
+            ///     Defaults to None.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///     The fugacity coefficients with shape
+            ///     (len(temperatures), len(pressures), components).
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If a state can not be calculated. The message contains
+            ///     the index of the state in the grid.
+            #[pyo3(text_signature = "(temperatures, pressures, molefracs=None, density_initialization=None)", signature = (temperatures, pressures, molefracs=None, density_initialization=None))]
+            fn fugacity_coefficient_table<'py>(
+                &self,
+                py: Python<'py>,
+                temperatures: Temperature<Array1<f64>>,
+                pressures: Pressure<Array1<f64>>,
+                molefracs: Option<&Bound<'_, PyArray1<f64>>>,
+                density_initialization: Option<&Bound<'_, PyAny>>,
+            ) -> PyResult<Bound<'py, numpy::PyArray3<f64>>> {
+                let density_initialization =
+                    $crate::python::parse_density_initialization(density_initialization)?;
+                let x = molefracs.map(|x| x.to_owned_array());
+                let phi = State::fugacity_coefficient_table(
+                    &self.0,
+                    &temperatures,
+                    &pressures,
+                    x.as_ref(),
+                    density_initialization,
+                )?;
+                Ok(phi.into_pyarray_bound(py))
+            }
+
+            /// Return the number of components of the model.
+            ///
+            /// Returns
+            /// -------
+            /// int
+            fn components(&self) -> usize {
+                self.0.components()
+            }
+
+            /// Return a new model restricted to the given components.
+            ///
+            /// Parameters
+            /// ----------
+            /// component_list : [int]
+            ///     The indices of the components in the new model.
+            ///     Must be non-empty and free of duplicates.
+            ///
+            /// Returns
+            /// -------
+            /// The model for the subset of components.
+            ///
+            /// Raises
+            /// ------
+            /// ValueError
+            ///     If the component list is empty or contains duplicates.
+            /// IndexError
+            ///     If a component index is out of range.
+            #[pyo3(text_signature = "(component_list)")]
+            fn subset(&self, component_list: Vec<usize>) -> PyResult<Self> {
+                if component_list.is_empty() {
+                    return Err(PyValueError::new_err(
+                        "The component list must not be empty.",
+                    ));
+                }
+                let mut sorted = component_list.clone();
+                sorted.sort_unstable();
+                if let Some(w) = sorted.windows(2).find(|w| w[0] == w[1]) {
+                    return Err(PyValueError::new_err(format!(
+                        "Component index {} appears more than once in the component list.",
+                        w[0]
+                    )));
+                }
+                let n = self.0.components();
+                if let Some(i) = component_list.iter().find(|&&i| i >= n) {
+                    return Err(PyIndexError::new_err(format!(
+                        "Component index {i} is out of range for a model with {n} components."
+                    )));
+                }
+                Ok(Self(Arc::new(self.0.subset(&component_list))))
+            }
         }
     };
 }
@@ -7,8 +7,12 @@ mod equation_of_state;
 pub mod parameter;
 mod phase_equilibria;
 mod state;
+mod units;
 pub mod user_defined;
 
+pub use state::parse_density_initialization;
+pub use units::{reduce, to_si};
+
 impl From<EosError> for PyErr {
     fn from(e: EosError) -> PyErr {
         PyRuntimeError::new_err(e.to_string())
@@ -425,6 +425,33 @@ macro_rules! impl_pure_record {
                 )))
             }
 
+            /// Read the records of the given substances from multiple JSON files.
+            ///
+            /// Parameters
+            /// ----------
+            /// substances : List[str]
+            ///     The substances to search.
+            /// paths : List[str]
+            ///     Paths to files containing the pure records.
+            /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+            ///     Identifier that is used to search substance.
+            ///
+            /// Returns
+            /// -------
+            /// [PureRecord]
+            #[staticmethod]
+            #[pyo3(signature = (substances, paths, identifier_option=IdentifierOption::Name))]
+            fn from_multiple_json(
+                substances: Vec<String>,
+                paths: Vec<String>,
+                identifier_option: IdentifierOption,
+            ) -> Result<Vec<Self>, ParameterError> {
+                let substances: Vec<_> = substances.iter().map(|s| &**s).collect();
+                let records =
+                    PureRecord::from_multiple_json(&substances, &paths, identifier_option)?;
+                Ok(records.into_iter().map(Self).collect())
+            }
+
             #[getter]
             fn get_identifier(&self) -> PyIdentifier {
                 PyIdentifier(self.0.identifier.clone())
@@ -519,6 +546,24 @@ macro_rules! impl_segment_record {
                     .collect())
             }
 
+            /// Read and merge lists of `SegmentRecord`s from multiple JSON files.
+            ///
+            /// Parameters
+            /// ----------
+            /// paths : List[str]
+            ///     Paths to files containing the segment records.
+            ///
+            /// Returns
+            /// -------
+            /// [SegmentRecord]
+            #[staticmethod]
+            fn from_multiple_json(paths: Vec<String>) -> Result<Vec<Self>, ParameterError> {
+                Ok(SegmentRecord::from_multiple_json(&paths)?
+                    .into_iter()
+                    .map(Self)
+                    .collect())
+            }
+
             #[getter]
             fn get_identifier(&self) -> String {
                 self.0.identifier.clone()
@@ -753,8 +798,68 @@ macro_rules! impl_parameter {
 #[macro_export]
 macro_rules! impl_parameter_from_segments {
     ($parameter:ty, $py_parameter:ty) => {
+        $crate::impl_parameter_from_segments!($parameter, $py_parameter, without_from_smiles);
+
+        #[pymethods]
+        impl $py_parameter {
+            /// Creates parameters from SMILES and segment records.
+            ///
+            /// Requires an installation of rdkit.
+            ///
+            /// Properties of the groups identified by the SMARTS codes, including
+            /// association sites, are taken from the corresponding segment records.
+            /// E.g., an OH group with one site of type A and one of type B leads to
+            /// one A and one B site for every hydroxyl group in the molecule.
+            ///
+            /// Parameters
+            /// ----------
+            /// identifier : [str | Identifier]
+            ///     A list of SMILES codes or [Identifier] objects.
+            /// smarts_records : [SmartsRecord]
+            ///     A list of records containing the SMARTS codes used
+            ///     to fragment the molecule.
+            /// segment_records : [SegmentRecord]
+            ///     A list of records containing the parameters of
+            ///     all individual segments.
+            /// binary_segment_records : [BinarySegmentRecord], optional
+            ///     A list of binary segment-segment parameters.
+            #[staticmethod]
+            #[pyo3(text_signature = "(identifier, smarts_records, segment_records, binary_segment_records=None)")]
+            #[pyo3(signature = (identifier, smarts_records, segment_records, binary_segment_records=None))]
+            fn from_smiles(
+                identifier: Vec<Bound<'_,PyAny>>,
+                smarts_records: Vec<PySmartsRecord>,
+                segment_records: Vec<PySegmentRecord>,
+                binary_segment_records: Option<Vec<PyBinarySegmentRecord>>,
+            ) -> PyResult<Self> {
+                Self::from_smiles_segments(
+                    identifier,
+                    smarts_records,
+                    segment_records,
+                    binary_segment_records,
+                )
+            }
+        }
+    };
+    ($parameter:ty, $py_parameter:ty, without_from_smiles) => {
         use pyo3::pybacked::*;
 
+        impl $py_parameter {
+            /// Creates parameters from SMILES and segment records using rdkit.
+            pub fn from_smiles_segments(
+                identifier: Vec<Bound<'_,PyAny>>,
+                smarts_records: Vec<PySmartsRecord>,
+                segment_records: Vec<PySegmentRecord>,
+                binary_segment_records: Option<Vec<PyBinarySegmentRecord>>,
+            ) -> PyResult<Self> {
+                let chemical_records: Vec<_> = identifier
+                    .into_iter()
+                    .map(|i| PyChemicalRecord::from_smiles(&i, smarts_records.clone()))
+                    .collect::<PyResult<_>>()?;
+                Self::from_segments(chemical_records, segment_records, binary_segment_records)
+            }
+        }
+
         #[pymethods]
         impl $py_parameter {
             /// Creates parameters from segment records.
@@ -819,38 +924,6 @@ macro_rules! impl_parameter_from_segments {
                 )?)))
             }
 
-            /// Creates parameters from SMILES and segment records.
-            ///
-            /// Requires an installation of rdkit.
-            ///
-            /// Parameters
-            /// ----------
-            /// identifier : [str | Identifier]
-            ///     A list of SMILES codes or [Identifier] objects.
-            /// smarts_records : [SmartsRecord]
-            ///     A list of records containing the SMARTS codes used
-            ///     to fragment the molecule.
-            /// segment_records : [SegmentRecord]
-            ///     A list of records containing the parameters of
-            ///     all individual segments.
-            /// binary_segment_records : [BinarySegmentRecord], optional
-            ///     A list of binary segment-segment parameters.
-            #[staticmethod]
-            #[pyo3(text_signature = "(identifier, smarts_records, segment_records, binary_segment_records=None)")]
-            #[pyo3(signature = (identifier, smarts_records, segment_records, binary_segment_records=None))]
-            fn from_smiles(
-                identifier: Vec<Bound<'_,PyAny>>,
-                smarts_records: Vec<PySmartsRecord>,
-                segment_records: Vec<PySegmentRecord>,
-                binary_segment_records: Option<Vec<PyBinarySegmentRecord>>,
-            ) -> PyResult<Self> {
-                let chemical_records: Vec<_> = identifier
-                    .into_iter()
-                    .map(|i| PyChemicalRecord::from_smiles(&i, smarts_records.clone()))
-                    .collect::<PyResult<_>>()?;
-                Self::from_segments(chemical_records, segment_records, binary_segment_records)
-            }
-
             /// Creates parameters from SMILES using segments from json file.
             ///
             /// Requires an installation of rdkit.
@@ -879,7 +952,7 @@ macro_rules! impl_parameter_from_segments {
                 let smarts_records = PySmartsRecord::from_json(&smarts_path)?;
                 let segment_records = PySegmentRecord::from_json(&segments_path)?;
                 let binary_segment_records = binary_path.map(|p| PyBinarySegmentRecord::from_json(&p)).transpose()?;
-                Self::from_smiles(
+                Self::from_smiles_segments(
                     identifier,
                     smarts_records,
                     segment_records,
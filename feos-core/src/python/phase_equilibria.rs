@@ -68,6 +68,49 @@ macro_rules! impl_phase_equilibrium {
                 }
             }
 
+            /// Calculate the saturation pressure and the vapor and liquid
+            /// density of a pure substance using the Maxwell (equal-area)
+            /// construction.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (SINumber, SINumber, SINumber)
+            ///     Saturation pressure, vapor density and liquid density.
+            ///
+            /// Notes
+            /// -----
+            /// The saturation pressure is calculated from the equal-area
+            /// rule instead of the equality of chemical potentials and can
+            /// be used to cross-check the results of `PhaseEquilibrium.pure`.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, temperature, max_iter=None, tol=None, verbosity=None))]
+            pub fn maxwell_construction(
+                eos: $py_eos,
+                temperature: Temperature,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Pressure, Density, Density)> {
+                let options = (max_iter, tol, verbosity).into();
+                let (p, [rho_v, rho_l]) =
+                    PhaseEquilibrium::maxwell_construction(&eos.0, temperature, options)?;
+                Ok((p, rho_v, rho_l))
+            }
+
             /// Create a liquid and vapor state in equilibrium
             /// for given temperature, pressure and feed composition.
             ///
@@ -126,6 +169,64 @@ macro_rules! impl_phase_equilibrium {
                 )?))
             }
 
+            /// Create a liquid and vapor state in equilibrium
+            /// for given pressure, molar enthalpy and feed composition.
+            ///
+            /// For pure components, the vapor phase fraction is determined
+            /// from the enthalpies of the saturated phases.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// molar_enthalpy : SINumber
+            ///     The molar enthalpy of the feed.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// initial_temperature : SINumber
+            ///     The system temperature used as starting
+            ///     condition for the iteration.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the iteration fails or the feed is a single phase.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, molar_enthalpy, feed, initial_temperature, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, pressure, molar_enthalpy, feed, initial_temperature, max_iter=None, tol=None, verbosity=None))]
+            #[expect(clippy::too_many_arguments)]
+            pub fn ph_flash(
+                eos: $py_eos,
+                pressure: Pressure,
+                molar_enthalpy: MolarEnergy,
+                feed: Moles<Array1<f64>>,
+                initial_temperature: Temperature,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(PhaseEquilibrium::ph_flash(
+                    &eos.0,
+                    pressure,
+                    molar_enthalpy,
+                    &feed,
+                    initial_temperature,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
             /// Compute a phase equilibrium for given temperature
             /// or pressure and liquid mole fractions.
             ///
@@ -286,6 +387,128 @@ macro_rules! impl_phase_equilibrium {
                 }
             }
 
+            /// Compute the bubble point temperature for given pressure
+            /// and liquid mole fractions.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// liquid_molefracs : numpy.ndarray
+            ///     The mole fraction of the liquid phase.
+            /// initial_temperature : SINumber, optional
+            ///     The system temperature used as starting
+            ///     condition for the iteration.
+            /// vapor_molefracs : numpy.ndarray, optional
+            ///     The mole fraction of the vapor phase used as
+            ///     starting condition for iteration.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, liquid_molefracs, initial_temperature=None, vapor_molefracs=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            #[pyo3(signature = (eos, pressure, liquid_molefracs, initial_temperature=None, vapor_molefracs=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None))]
+            #[expect(clippy::too_many_arguments)]
+            pub fn bubble_point_temperature<'py>(
+                eos: $py_eos,
+                pressure: Pressure,
+                liquid_molefracs: &Bound<'py, PyArray1<f64>>,
+                initial_temperature: Option<Temperature>,
+                vapor_molefracs: Option<&Bound<'py, PyArray1<f64>>>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let y = vapor_molefracs.map(|m| m.to_owned_array());
+                Ok(Self(PhaseEquilibrium::bubble_point(
+                    &eos.0,
+                    pressure,
+                    &liquid_molefracs.to_owned_array(),
+                    initial_temperature,
+                    y.as_ref(),
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into()
+                    )
+                )?))
+            }
+
+            /// Compute the dew point temperature for given pressure
+            /// and vapor mole fractions.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// vapor_molefracs : numpy.ndarray
+            ///     The mole fraction of the vapor phase.
+            /// initial_temperature : SINumber, optional
+            ///     The system temperature used as starting
+            ///     condition for the iteration.
+            /// liquid_molefracs : numpy.ndarray, optional
+            ///     The mole fraction of the liquid phase used as
+            ///     starting condition for iteration.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, vapor_molefracs, initial_temperature=None, liquid_molefracs=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            #[pyo3(signature = (eos, pressure, vapor_molefracs, initial_temperature=None, liquid_molefracs=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None))]
+            #[expect(clippy::too_many_arguments)]
+            pub fn dew_point_temperature<'py>(
+                eos: $py_eos,
+                pressure: Pressure,
+                vapor_molefracs: &Bound<'py, PyArray1<f64>>,
+                initial_temperature: Option<Temperature>,
+                liquid_molefracs: Option<&Bound<'py, PyArray1<f64>>>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let x = liquid_molefracs.map(|m| m.to_owned_array());
+                Ok(Self(PhaseEquilibrium::dew_point(
+                    &eos.0,
+                    pressure,
+                    &vapor_molefracs.to_owned_array(),
+                    initial_temperature,
+                    x.as_ref(),
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into()
+                    )
+                )?))
+            }
+
             /// Creates a new PhaseEquilibrium that contains two states at the
             /// specified temperature, pressure and moles.
             ///
@@ -336,6 +559,36 @@ macro_rules! impl_phase_equilibrium {
                 PyState(self.0.liquid().clone())
             }
 
+            /// Return the distribution coefficients, i.e., the ratio
+            /// of vapor and liquid mole fractions.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray
+            fn k_values<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+                self.0.k_values().into_pyarray_bound(py)
+            }
+
+            /// Return the enthalpy of vaporization, i.e., the difference
+            /// between the molar enthalpies of the vapor and the liquid phase.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn enthalpy_of_vaporization(&self) -> MolarEnergy {
+                self.0.enthalpy_of_vaporization()
+            }
+
+            /// Return the entropy of vaporization, i.e., the difference
+            /// between the molar entropies of the vapor and the liquid phase.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn entropy_of_vaporization(&self) -> MolarEntropy {
+                self.0.entropy_of_vaporization()
+            }
+
             /// Calculate the pure component vapor-liquid equilibria for all
             /// components in the system.
             ///
@@ -387,6 +640,32 @@ macro_rules! impl_phase_equilibrium {
                 PhaseEquilibrium::vapor_pressure(&eos.0, temperature)
             }
 
+            /// Calculate the vapor pressure of a pure component
+            /// for multiple temperatures.
+            ///
+            /// Each phase equilibrium is used as initial guess
+            /// for the next temperature.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperatures : SIArray1
+            ///     The temperatures.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When a temperature is not below the critical temperature.
+            #[staticmethod]
+            fn pure_vapor_pressure(eos: $py_eos, temperatures: Temperature<Array1<f64>>) -> PyResult<Pressure<Array1<f64>>> {
+                Ok(PhaseEquilibrium::pure_vapor_pressure(&eos.0, &temperatures)?)
+            }
+
             /// Calculate the pure component boiling temperatures for all the
             /// components in the system.
             ///
@@ -405,6 +684,39 @@ macro_rules! impl_phase_equilibrium {
                 PhaseEquilibrium::boiling_temperature(&eos.0, pressure)
             }
 
+            /// Returns the properties of both phases as dictionary.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions : Contributions, optional
+            ///     The contributions to consider when calculating properties.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// Dict[str, float]
+            ///     Keys: property names prefixed with `vapor` or `liquid`,
+            ///     e.g., `vapor molar enthalpy`.
+            ///     Values: property of the respective phase.
+            ///
+            /// Notes
+            /// -----
+            /// - temperature : K
+            /// - pressure : Pa
+            /// - density : mol / m³
+            /// - mass density : kg / m³
+            /// - molar enthalpy : kJ / mol
+            /// - molar entropy : kJ / mol / K
+            /// - specific enthalpy : kJ / kg
+            /// - specific entropy : kJ / kg / K
+            /// - molefractions: keyed by the component name, or `xi` for component i
+            ///   if the name is not available.
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            pub fn to_dict(&self, contributions: Contributions) -> HashMap<String, f64> {
+                let identifiers = self.0.vapor().eos.residual.component_identifiers();
+                self.0.to_dict(contributions, identifiers.as_deref())
+            }
+
             fn _repr_markdown_(&self) -> String {
                 self.0._repr_markdown_()
             }
@@ -501,6 +813,51 @@ macro_rules! impl_phase_equilibrium {
                     )))
                 }
             }
+
+            /// Calculate the vapor-liquid-liquid equilibrium of a binary
+            /// mixture for a given temperature.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance) within
+            ///     the liquid-liquid miscibility gap.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// ThreePhaseEquilibrium
+            ///     The vapor phase and the two liquid phases.
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If the liquid with the feed composition is stable, i.e.,
+            ///     no second liquid phase exists.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, feed, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, temperature, feed, max_iter=None, tol=None, verbosity=None))]
+            fn vlle(
+                eos: $py_eos,
+                temperature: Temperature,
+                feed: Moles<Array1<f64>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyThreePhaseEquilibrium> {
+                let options = (max_iter, tol, verbosity).into();
+                let vlle = PhaseEquilibrium::vlle(&eos.0, temperature, &feed, options)?;
+                Ok(PyThreePhaseEquilibrium(vlle))
+            }
         }
 
         #[pymethods]
@@ -569,6 +926,43 @@ macro_rules! impl_phase_equilibrium {
                     non_volatile_components
                 )?))
             }
+
+            /// Calculates a ph-flash for the isenthalpic expansion
+            /// (throttling) of the state to the given pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// pressure : SINumber
+            ///     The pressure after the expansion.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When the iteration fails or the expanded state is a single phase.
+            #[pyo3(text_signature = "($self, pressure, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (pressure, max_iter=None, tol=None, verbosity=None))]
+            pub fn ph_flash(
+                &self,
+                pressure: Pressure,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyPhaseEquilibrium> {
+                Ok(PyPhaseEquilibrium(self.0.ph_flash(
+                    pressure,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
         }
 
         /// Phase diagram for a pure component or a binary mixture.
@@ -1,3 +1,34 @@
+use crate::DensityInitialization;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use quantity::Density;
+
+/// Parse the `density_initialization` argument of the Python interface.
+///
+/// Accepts 'vapor', 'liquid' or a molar density; `None` means no initialization.
+pub fn parse_density_initialization(
+    density_initialization: Option<&Bound<'_, PyAny>>,
+) -> PyResult<DensityInitialization> {
+    let Some(di) = density_initialization else {
+        return Ok(DensityInitialization::None);
+    };
+    if let Ok(d) = di.extract::<String>().as_deref() {
+        match d {
+            "vapor" => Ok(DensityInitialization::Vapor),
+            "liquid" => Ok(DensityInitialization::Liquid),
+            _ => Err(PyValueError::new_err(
+                "`density_initialization` must be 'vapor' or 'liquid'.",
+            )),
+        }
+    } else if let Ok(d) = di.extract::<Density>() {
+        Ok(DensityInitialization::InitialDensity(d))
+    } else {
+        Err(PyValueError::new_err(
+            "`density_initialization` must be 'vapor' or 'liquid' or a molar density as `SINumber`.",
+        ))
+    }
+}
+
 #[macro_export]
 macro_rules! impl_state {
     ($eos:ty, $py_eos:ty) => {
@@ -32,6 +63,7 @@ macro_rules! impl_state {
         /// density_initialization : {'vapor', 'liquid', SINumber, None}, optional
         ///     Method used to initialize density for density iteration.
         ///     'vapor' and 'liquid' are inferred from the maximum density of the equation of state.
+        ///     The initial density can be provided either as molar or as mass density.
         ///     If no density or keyword is provided, the vapor and liquid phase is tested and, if
         ///     different, the result with the lower free energy is returned.
         /// initial_temperature : SINumber, optional
@@ -73,24 +105,26 @@ macro_rules! impl_state {
                 initial_temperature: Option<Temperature>,
             ) -> PyResult<Self> {
                 let x = molefracs.and_then(|m| Some(m.to_owned_array()));
-                let density_init = if let Some(di) = density_initialization {
-                    if let Ok(d) = di.extract::<String>().as_deref() {
-                        match d {
-                            "vapor" => Ok(DensityInitialization::Vapor),
-                            "liquid" => Ok(DensityInitialization::Liquid),
-                            _ => Err(PyErr::new::<PyValueError, _>(format!(
-                                "`density_initialization` must be 'vapor' or 'liquid'."
-                            ))),
-                        }
-                    } else if let Ok(d) = di.extract::<Density>() {
-                        Ok(DensityInitialization::InitialDensity(d.try_into()?))
-                    } else {
-                        Err(PyErr::new::<PyValueError, _>(format!(
-                            "`density_initialization` must be 'vapor' or 'liquid' or a molar density as `SINumber` has to be provided."
-                        )))
+                if let (Some(m), Some(x)) = (molefracs, &x) {
+                    let sum = x.sum();
+                    if x.iter().all(|&xi| xi >= 0.0) && (sum - 1.0).abs() > 1e-8 {
+                        PyErr::warn_bound(
+                            m.py(),
+                            &m.py().get_type_bound::<pyo3::exceptions::PyUserWarning>(),
+                            &format!("The mole fractions sum to {sum} and are normalized."),
+                            1,
+                        )?;
                     }
-                } else {
-                    Ok(DensityInitialization::None)
+                }
+                let density_init = match density_initialization.map(|di| di.extract::<MassDensity>()) {
+                    Some(Ok(d)) => {
+                        let composition = x
+                            .clone()
+                            .or_else(|| moles.as_ref().map(|m| m.to_reduced()))
+                            .or_else(|| partial_density.as_ref().map(|rho| rho.to_reduced()));
+                        DensityInitialization::from_mass_density(&*eos.0, d, composition.as_ref())?
+                    }
+                    _ => $crate::python::parse_density_initialization(density_initialization)?,
                 };
                 let s = State::new_full(
                     &eos.0,
@@ -105,12 +139,167 @@ macro_rules! impl_state {
                     molar_enthalpy.map(|s| s.try_into()).transpose()?,
                     molar_entropy.map(|s| s.try_into()).transpose()?,
                     molar_internal_energy.map(|s| s.try_into()).transpose()?,
-                    density_init?,
+                    density_init,
                     initial_temperature.map(|s| s.try_into()).transpose()?,
                 )?;
                 Ok(Self(s))
             }
 
+            /// Create a list of states from arrays of temperatures, pressures
+            /// and initial densities.
+            ///
+            /// Every state is calculated with a density iteration that starts
+            /// at the corresponding initial density.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state to use.
+            /// temperature : SIArray1
+            ///     Temperatures of the states.
+            /// pressure : SIArray1
+            ///     Pressures of the states.
+            /// initial_density : SIArray1
+            ///     Initial (molar) densities of the density iterations.
+            /// molefracs : numpy.ndarray[float], optional
+            ///     Mole fractions with one row per state.
+            ///     Can be omitted for pure components.
+            ///
+            /// Returns
+            /// -------
+            /// StateVec
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, initial_density, molefracs=None)")]
+            #[pyo3(signature = (eos, temperature, pressure, initial_density, molefracs=None))]
+            fn new_batch(
+                eos: $py_eos,
+                temperature: Temperature<Array1<f64>>,
+                pressure: Pressure<Array1<f64>>,
+                initial_density: Density<Array1<f64>>,
+                molefracs: Option<&Bound<'_, PyArray2<f64>>>,
+            ) -> PyResult<PyStateVec> {
+                let x = molefracs.map(|x| x.to_owned_array());
+                let states = State::new_batch(
+                    &eos.0,
+                    &temperature.try_into()?,
+                    &pressure.try_into()?,
+                    x.as_ref(),
+                    &initial_density.try_into()?,
+                )?;
+                Ok(PyStateVec(states))
+            }
+
+            /// Calculate the compressibility factor along an isobar.
+            ///
+            /// A state is calculated for every temperature using the
+            /// same density initialization.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state to use.
+            /// pressure : SINumber
+            ///     Pressure of the isobar.
+            /// temperatures : SIArray1
+            ///     Temperatures at which the compressibility factor is calculated.
+            /// molefracs : numpy.ndarray[float], optional
+            ///     Mole fractions of the system.
+            ///     Can be omitted for pure components.
+            /// density_initialization : {'vapor', 'liquid', SINumber, None}, optional
+            ///     Method used to initialize the density iterations.
+            ///     Defaults to None.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[float]
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If a state can not be calculated. The message
+            ///     contains the index of the temperature.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, temperatures, molefracs=None, density_initialization=None)")]
+            #[pyo3(signature = (eos, pressure, temperatures, molefracs=None, density_initialization=None))]
+            fn compressibility_isobar<'py>(
+                py: Python<'py>,
+                eos: $py_eos,
+                pressure: Pressure,
+                temperatures: Temperature<Array1<f64>>,
+                molefracs: Option<&Bound<'_, PyArray1<f64>>>,
+                density_initialization: Option<&Bound<'_, PyAny>>,
+            ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+                let density_initialization =
+                    $crate::python::parse_density_initialization(density_initialization)?;
+                let x = molefracs.map(|x| x.to_owned_array());
+                let z = State::compressibility_isobar(
+                    &eos.0,
+                    pressure,
+                    x.as_ref(),
+                    &temperatures,
+                    density_initialization,
+                )?;
+                Ok(z.into_pyarray_bound(py))
+            }
+
+            /// Create a pure component state from temperature and either
+            /// density or pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state of a pure component.
+            /// temperature : SINumber
+            ///     Temperature.
+            /// density : SINumber, optional
+            ///     Molar density.
+            /// pressure : SINumber, optional
+            ///     Pressure. The stable phase is determined by a density iteration.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            ///
+            /// Raises
+            /// ------
+            /// ValueError
+            ///     If not exactly one of density and pressure is provided.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, density=None, pressure=None)")]
+            #[pyo3(signature = (eos, temperature, density=None, pressure=None))]
+            fn pure(
+                eos: $py_eos,
+                temperature: Temperature,
+                density: Option<Density>,
+                pressure: Option<Pressure>,
+            ) -> PyResult<Self> {
+                let spec = match (density, pressure) {
+                    (Some(d), None) => DPSpec::Density(d),
+                    (None, Some(p)) => DPSpec::Pressure(p),
+                    _ => {
+                        return Err(PyErr::new::<PyValueError, _>(
+                            "Exactly one of `density` and `pressure` has to be provided.",
+                        ))
+                    }
+                };
+                Ok(Self(State::new_pure(&eos.0, temperature, spec)?))
+            }
+
+            /// Return a state at the given pressure with the same molar
+            /// entropy and composition.
+            ///
+            /// Parameters
+            /// ----------
+            /// pressure : SINumber
+            ///     The pressure of the new state.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            fn isentropic_pressure_change(&self, pressure: Pressure) -> PyResult<Self> {
+                Ok(Self(self.0.isentropic_pressure_change(pressure)?))
+            }
+
             /// Return a list of thermodynamic state at critical conditions
             /// for each pure substance in the system.
             ///
@@ -120,6 +309,9 @@ macro_rules! impl_state {
             ///     The equation of state to use.
             /// initial_temperature: SINumber, optional
             ///     The initial temperature.
+            /// components: [int], optional
+            ///     Indices of the components for which the critical
+            ///     points are calculated. Defaults to all components.
             /// max_iter : int, optional
             ///     The maximum number of iterations.
             /// tol: float, optional
@@ -131,17 +323,24 @@ macro_rules! impl_state {
             /// -------
             /// State : tate at critical conditions
             #[staticmethod]
-            #[pyo3(text_signature = "(eos, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
-            #[pyo3(signature = (eos, initial_temperature=None, max_iter=None, tol=None, verbosity=None))]
+            #[pyo3(text_signature = "(eos, initial_temperature=None, components=None, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, initial_temperature=None, components=None, max_iter=None, tol=None, verbosity=None))]
             fn critical_point_pure(
                 eos: $py_eos,
                 initial_temperature: Option<Temperature>,
+                components: Option<Vec<usize>>,
                 max_iter: Option<usize>,
                 tol: Option<f64>,
                 verbosity: Option<Verbosity>,
             ) -> PyResult<Vec<Self>> {
                 let t = initial_temperature.map(|t0| t0.try_into()).transpose()?;
-                let cp = State::critical_point_pure(&eos.0, t, (max_iter, tol, verbosity).into())?;
+                let options = (max_iter, tol, verbosity).into();
+                let cp = match components {
+                    Some(components) => {
+                        State::critical_point_pure_subset(&eos.0, t, &components, options)?
+                    }
+                    None => State::critical_point_pure(&eos.0, t, options)?,
+                };
                 Ok(cp.into_iter().map(Self).collect())
             }
 
@@ -166,6 +365,12 @@ macro_rules! impl_state {
             /// Returns
             /// -------
             /// State : State at critical conditions.
+            ///
+            /// Notes
+            /// -----
+            /// The result is a regular state, i.e., the critical
+            /// compressibility factor is available via
+            /// `State.compressibility()`.
             #[staticmethod]
             #[pyo3(text_signature = "(eos, moles=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
             #[pyo3(signature = (eos, moles=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None))]
@@ -185,6 +390,69 @@ macro_rules! impl_state {
                 )?))
             }
 
+            /// Create a thermodynamic state at critical conditions and
+            /// return the convergence statistics of the iteration.
+            ///
+            /// In contrast to `critical_point`, no error is raised if the
+            /// iteration does not converge within `max_iter` iterations.
+            /// Instead, the last iterate is returned and the `converged`
+            /// attribute of the statistics is False.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// initial_temperature: SINumber, optional
+            ///     The initial temperature.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// (State, SolverStats)
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, moles=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, moles=None, initial_temperature=None, max_iter=None, tol=None, verbosity=None))]
+            fn critical_point_with_stats(
+                eos: $py_eos,
+                moles: Option<Moles<Array1<f64>>>,
+                initial_temperature: Option<Temperature>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<(Self, SolverStats)> {
+                let (state, stats) = State::critical_point_with_stats(
+                    &eos.0,
+                    moles.map(|m| m.try_into()).transpose()?.as_ref(),
+                    initial_temperature.map(|t| t.try_into()).transpose()?,
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok((PyState(state), stats))
+            }
+
+            /// Evaluate the criticality conditions from the derivatives of the state.
+            ///
+            /// The values are independent of the objective function used by
+            /// the critical point solvers and can be used to verify their
+            /// results.
+            ///
+            /// Returns
+            /// -------
+            /// (float, float)
+            ///     The smallest eigenvalue of the stability matrix and the
+            ///     cubic form along the corresponding eigenvector.
+            ///     Both vanish at a critical point.
+            fn critical_point_residuals(&self) -> (f64, f64) {
+                self.0.critical_point_residuals()
+            }
+
             /// Create a thermodynamic state at critical conditions for a binary system.
             ///
             /// Parameters
@@ -197,6 +465,10 @@ macro_rules! impl_state {
             ///     An initial guess for the temperature.
             /// initial_molefracs: [float], optional
             ///     An initial guess for the composition.
+            /// initial_state: State, optional
+            ///     A critical point at a nearby temperature or pressure
+            ///     used as initial guess, e.g., when tracing a critical line.
+            ///     Overrides `initial_temperature` and `initial_molefracs`.
             /// max_iter : int, optional
             ///     The maximum number of iterations.
             /// tol: float, optional
@@ -208,33 +480,42 @@ macro_rules! impl_state {
             /// -------
             /// State : State at critical conditions.
             #[staticmethod]
-            #[pyo3(text_signature = "(eos, temperature_or_pressure, initial_temperature=None, initial_molefracs=None, max_iter=None, tol=None, verbosity=None)")]
-            #[pyo3(signature = (eos, temperature_or_pressure, initial_temperature=None, initial_molefracs=None, max_iter=None, tol=None, verbosity=None))]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, initial_temperature=None, initial_molefracs=None, initial_state=None, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, temperature_or_pressure, initial_temperature=None, initial_molefracs=None, initial_state=None, max_iter=None, tol=None, verbosity=None))]
+            #[expect(clippy::too_many_arguments)]
             fn critical_point_binary(
                 eos: $py_eos,
                 temperature_or_pressure: Bound<'_, PyAny>,
                 initial_temperature: Option<Temperature>,
                 initial_molefracs: Option<[f64; 2]>,
+                initial_state: Option<&PyState>,
                 max_iter: Option<usize>,
                 tol: Option<f64>,
                 verbosity: Option<Verbosity>,
             ) -> PyResult<Self> {
+                let options = (max_iter, tol, verbosity).into();
                 if let Ok(t) = temperature_or_pressure.extract::<Temperature>() {
-                    Ok(PyState(State::critical_point_binary(
-                        &eos.0,
-                        t,
-                        initial_temperature.map(|t| t.try_into()).transpose()?,
-                        initial_molefracs,
-                        (max_iter, tol, verbosity).into(),
-                    )?))
+                    Ok(PyState(match initial_state {
+                        Some(s) => State::critical_point_binary_from_state(&eos.0, t, &s.0, options),
+                        None => State::critical_point_binary(
+                            &eos.0,
+                            t,
+                            initial_temperature.map(|t| t.try_into()).transpose()?,
+                            initial_molefracs,
+                            options,
+                        ),
+                    }?))
                 } else if let Ok(p) = temperature_or_pressure.extract::<Pressure>() {
-                    Ok(PyState(State::critical_point_binary(
-                        &eos.0,
-                        p,
-                        initial_temperature.map(|t| t.try_into()).transpose()?,
-                        initial_molefracs,
-                        (max_iter, tol, verbosity).into(),
-                    )?))
+                    Ok(PyState(match initial_state {
+                        Some(s) => State::critical_point_binary_from_state(&eos.0, p, &s.0, options),
+                        None => State::critical_point_binary(
+                            &eos.0,
+                            p,
+                            initial_temperature.map(|t| t.try_into()).transpose()?,
+                            initial_molefracs,
+                            options,
+                        ),
+                    }?))
                 } else {
                     Err(PyErr::new::<PyValueError, _>(format!(
                         "Wrong units! Expected K or Pa, got {}.",
@@ -284,6 +565,53 @@ macro_rules! impl_state {
                 Ok((PyState(state1), PyState(state2)))
             }
 
+            /// Calculate spinodal states for a given temperature and composition
+            /// and return the convergence statistics of both iterations.
+            ///
+            /// In contrast to `spinodal`, no error is raised if an iteration
+            /// does not converge within `max_iter` iterations. Instead, the
+            /// last iterate is returned and the `converged` attribute of the
+            /// corresponding statistics is False.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// moles: SIArray1, optional
+            ///     Amount of substance of each component.
+            ///     Only optional for a pure component.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// ((State, SolverStats), (State, SolverStats))
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, moles=None, max_iter=None, tol=None, verbosity=None)")]
+            #[pyo3(signature = (eos, temperature, moles=None, max_iter=None, tol=None, verbosity=None))]
+            fn spinodal_with_stats(
+                eos: $py_eos,
+                temperature: Temperature,
+                moles: Option<Moles<Array1<f64>>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<((Self, SolverStats), (Self, SolverStats))> {
+                let [(state1, stats1), (state2, stats2)] = State::spinodal_with_stats(
+                    &eos.0,
+                    temperature,
+                    moles.map(|m| m.try_into()).transpose()?.as_ref(),
+                    (max_iter, tol, verbosity).into(),
+                )?;
+                Ok(((PyState(state1), stats1), (PyState(state2), stats2)))
+            }
+
             /// Performs a stability analysis and returns a list of stable
             /// candidate states.
             ///
@@ -295,20 +623,37 @@ macro_rules! impl_state {
             ///     The solution tolerance.
             /// verbosity : Verbosity, optional
             ///     The verbosity.
+            /// trial_states : [(numpy.ndarray[float], {'vapor', 'liquid', SINumber})], optional
+            ///     Mole fractions and density initializations of the trial phases.
+            ///     Defaults to a nearly pure liquid phase of every component and
+            ///     an ideal vapor phase.
             ///
             /// Returns
             /// -------
             /// State
-            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None)")]
-            #[pyo3(signature = (max_iter=None, tol=None, verbosity=None))]
-            fn stability_analysis(&self,
+            #[pyo3(text_signature = "(max_iter=None, tol=None, verbosity=None, trial_states=None)")]
+            #[pyo3(signature = (max_iter=None, tol=None, verbosity=None, trial_states=None))]
+            fn stability_analysis<'py>(&self,
                 max_iter: Option<usize>,
                 tol: Option<f64>,
                 verbosity: Option<Verbosity>,
+                trial_states: Option<Vec<(Bound<'py, PyArray1<f64>>, Bound<'py, PyAny>)>>,
             ) -> PyResult<Vec<Self>> {
-                Ok(self
-                    .0
-                    .stability_analysis((max_iter, tol, verbosity).into())?
+                let options = (max_iter, tol, verbosity).into();
+                let states = match trial_states {
+                    Some(trial_states) => {
+                        let trial_states = trial_states
+                            .into_iter()
+                            .map(|(x, di)| {
+                                let di = $crate::python::parse_density_initialization(Some(&di))?;
+                                Ok((x.to_owned_array(), di))
+                            })
+                            .collect::<PyResult<Vec<_>>>()?;
+                        self.0.stability_analysis_with_trial_states(&trial_states, options)?
+                    }
+                    None => self.0.stability_analysis(options)?,
+                };
+                Ok(states
                     .into_iter()
                     .map(Self)
                     .collect())
@@ -364,6 +709,17 @@ macro_rules! impl_state {
                 self.0.pressure_contributions()
             }
 
+            /// Return pressure contributions keyed by the name of the contribution.
+            ///
+            /// Duplicate names are disambiguated by appending an index.
+            ///
+            /// Returns
+            /// -------
+            /// Dict[str, SINumber]
+            fn pressure_contributions_dict(&self) -> HashMap<String, Pressure> {
+                self.0.pressure_contributions_map()
+            }
+
             /// Return compressibility.
             ///
             /// Parameters
@@ -412,6 +768,38 @@ macro_rules! impl_state {
                 self.0.dp_drho(contributions)
             }
 
+            /// Return partial derivative of density w.r.t. pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions: Contributions, optional
+            ///     the contributions of the Helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            fn drho_dp(&self, contributions: Contributions) -> Quot<Density, Pressure> {
+                self.0.drho_dp(contributions)
+            }
+
+            /// Return partial derivative of density w.r.t. temperature.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions: Contributions, optional
+            ///     the contributions of the Helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            fn drho_dt(&self, contributions: Contributions) -> Quot<Density, Temperature> {
+                self.0.drho_dt(contributions)
+            }
+
             /// Return partial derivative of pressure w.r.t. temperature.
             ///
             /// Parameters
@@ -476,6 +864,22 @@ macro_rules! impl_state {
                 self.0.d2p_drho2(contributions)
             }
 
+            /// Return second partial derivative of pressure w.r.t. temperature and volume.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions: Contributions, optional
+            ///     the contributions of the Helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            fn d2p_dtdv(&self, contributions: Contributions) -> Quot<Quot<Pressure, Temperature>, Volume> {
+                self.0.d2p_dtdv(contributions)
+            }
+
             /// Return partial molar volume of each component.
             ///
             /// Returns
@@ -501,6 +905,32 @@ macro_rules! impl_state {
                 self.0.chemical_potential(contributions)
             }
 
+            /// Return residual chemical potential of each component.
+            ///
+            /// The reference is the ideal gas at the same temperature,
+            /// volume and composition, so that the total chemical potential
+            /// is the sum of the residual and the ideal gas contributions.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn residual_chemical_potential(&self) -> MolarEnergy<Array1<f64>> {
+                self.0.residual_chemical_potential()
+            }
+
+            /// Return partial residual molar entropy of each component.
+            ///
+            /// The reference is the ideal gas at the same temperature,
+            /// volume and composition, so that the mole fraction weighted
+            /// sum is the residual molar entropy.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn partial_residual_molar_entropy(&self) -> MolarEntropy<Array1<f64>> {
+                self.0.partial_residual_molar_entropy()
+            }
+
             /// Return chemical potential contributions.
             ///
             /// Parameters
@@ -520,6 +950,27 @@ macro_rules! impl_state {
                 self.0.chemical_potential_contributions(component, contributions)
             }
 
+            /// Return chemical potential contributions keyed by the name of the contribution.
+            ///
+            /// Duplicate names are disambiguated by appending an index.
+            ///
+            /// Parameters
+            /// ----------
+            /// component: int
+            ///     the component for which the contributions
+            ///     are calculated
+            /// contributions: Contributions, optional
+            ///     the contributions of the Helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// Dict[str, SINumber]
+            #[pyo3(signature = (component, contributions=Contributions::Total), text_signature = "($self, component, contributions)")]
+            fn chemical_potential_contributions_dict(&self, component: usize, contributions: Contributions) -> HashMap<String, MolarEnergy> {
+                self.0.chemical_potential_contributions_map(component, contributions)
+            }
+
             /// Return derivative of chemical potential w.r.t temperature.
             ///
             /// Parameters
@@ -599,6 +1050,27 @@ macro_rules! impl_state {
                 Ok(State::henrys_law_constant(&eos.0, temperature, &molefracs.to_owned_array())?)
             }
 
+            /// Return the temperature derivative of Henry's law constant of every
+            /// solute (x_i=0) for a given solvent (x_i>0) along the bubble point
+            /// line of the solvent.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : Eos
+            ///     The equation of state to use.
+            /// temperature : SINumber
+            ///     Temperature.
+            /// molefracs : np.ndarray[float]
+            ///     Composition of the solvent including x_i=0 for solutes.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            #[staticmethod]
+            fn henrys_law_constant_temperature_derivative(eos: $py_eos, temperature: Temperature, molefracs: &Bound<'_, PyArray1<f64>>) -> PyResult<Quot<Pressure<Array1<f64>>, Temperature>> {
+                Ok(State::henrys_law_constant_temperature_derivative(&eos.0, temperature, &molefracs.to_owned_array())?)
+            }
+
             /// Return Henry's law constant of a binary system, assuming the first
             /// component is the solute and the second component is the solvent.
             ///
@@ -617,6 +1089,38 @@ macro_rules! impl_state {
                 Ok(State::henrys_law_constant_binary(&eos.0, temperature)?)
             }
 
+            /// Return the molar Gibbs energy of mixing of a binary system
+            /// at fixed temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : Eos
+            ///     The equation of state to use.
+            /// temperature : SINumber
+            ///     Temperature.
+            /// pressure : SINumber
+            ///     Pressure.
+            /// molefracs : numpy.ndarray[float]
+            ///     Mole fractions of the first component.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            #[staticmethod]
+            fn molar_gibbs_energy_of_mixing(
+                eos: $py_eos,
+                temperature: Temperature,
+                pressure: Pressure,
+                molefracs: &Bound<'_, PyArray1<f64>>,
+            ) -> PyResult<MolarEnergy<Array1<f64>>> {
+                Ok(State::molar_gibbs_energy_of_mixing(
+                    &eos.0,
+                    temperature,
+                    pressure,
+                    &molefracs.to_owned_array(),
+                )?)
+            }
+
             /// Return derivative of logarithmic fugacity coefficient w.r.t. temperature.
             ///
             /// Returns
@@ -644,6 +1148,32 @@ macro_rules! impl_state {
                 self.0.dln_phi_dnj()
             }
 
+            /// Return the residual of the Gibbs-Duhem equation.
+            ///
+            /// The maximum absolute value of
+            /// :math:`\sum_iN_i\left(\frac{\partial\ln\varphi_i}{\partial N_j}\right)_{T,p,N_k}`,
+            /// which vanishes for a thermodynamically consistent model.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn gibbs_duhem_residual(&self) -> f64 {
+                self.0.gibbs_duhem_residual()
+            }
+
+            /// Return the maximum relative violation of the Maxwell relations.
+            ///
+            /// The analytic second derivatives of the residual Helmholtz energy
+            /// are compared to central finite differences of its first derivatives.
+            /// Useful to verify the implementation of new Helmholtz energy contributions.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn self_consistency_check(&self) -> f64 {
+                self.0.self_consistency_check()
+            }
+
             /// Return thermodynamic factor.
             ///
             /// Returns
@@ -653,6 +1183,20 @@ macro_rules! impl_state {
                 self.0.thermodynamic_factor().into_pyarray_bound(py)
             }
 
+            /// Return thermodynamic factor of a binary mixture.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If the state does not contain exactly two components.
+            fn thermodynamic_factor_binary(&self) -> PyResult<f64> {
+                Ok(self.0.thermodynamic_factor_binary()?)
+            }
+
             /// Return molar isochoric heat capacity.
             ///
             /// Parameters
@@ -701,6 +1245,18 @@ macro_rules! impl_state {
                 self.0.molar_isobaric_heat_capacity(contributions)
             }
 
+            /// Return molar isobaric heat capacity of the ideal gas.
+            ///
+            /// Equivalent to
+            /// ``molar_isobaric_heat_capacity(Contributions.IdealGas)``.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn ideal_gas_heat_capacity(&self) -> MolarEntropy {
+                self.0.ideal_gas_heat_capacity()
+            }
+
 	        /// Return entropy.
             ///
             /// Parameters
@@ -842,6 +1398,38 @@ macro_rules! impl_state {
                 self.0.residual_helmholtz_energy_contributions()
             }
 
+            /// Return association contribution to the residual Helmholtz energy.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber, optional
+            ///     The association contribution or None, if the model
+            ///     has no association contribution.
+            fn association_helmholtz_energy(&self) -> Option<Energy> {
+                self.0.association_helmholtz_energy()
+            }
+
+            /// Return residual Helmholtz energy and its partial derivatives
+            /// w.r.t. density and temperature.
+            ///
+            /// Returns
+            /// -------
+            /// Tuple[SINumber, SINumber, SINumber, SINumber]
+            ///     The residual Helmholtz energy, its first and second partial
+            ///     derivative w.r.t. density at constant temperature and its
+            ///     partial derivative w.r.t. temperature at constant volume.
+            #[expect(clippy::type_complexity)]
+            fn residual_helmholtz_energy_derivatives(
+                &self,
+            ) -> (
+                Energy,
+                Quot<Energy, Density>,
+                Quot<Quot<Energy, Density>, Density>,
+                Quot<Energy, Temperature>,
+            ) {
+                self.0.residual_helmholtz_energy_derivatives()
+            }
+
             /// Return Gibbs energy.
             ///
             /// Parameters
@@ -916,6 +1504,17 @@ macro_rules! impl_state {
                 self.0.joule_thomson()
             }
 
+            /// Return whether the state cools down during an isenthalpic expansion.
+            ///
+            /// This is the case, if the Joule Thomson coefficient is positive.
+            ///
+            /// Returns
+            /// -------
+            /// bool
+            fn is_cooling_on_expansion(&self) -> bool {
+                self.0.is_cooling_on_expansion()
+            }
+
             /// Return isentropy compressibility coefficient.
             ///
             /// Returns
@@ -934,6 +1533,26 @@ macro_rules! impl_state {
                 self.0.isothermal_compressibility()
             }
 
+            /// Return isothermal compressibility coefficient calculated
+            /// from the density derivative of the pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// contributions: Contributions, optional
+            ///     the contributions of the Helmholtz energy.
+            ///     Defaults to Contributions.Total.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
+            fn isothermal_compressibility_from_density(
+                &self,
+                contributions: Contributions,
+            ) -> Quot<f64, Pressure> {
+                self.0.isothermal_compressibility_from_density(contributions)
+            }
+
             /// Return isenthalpic compressibility coefficient.
             ///
             /// Returns
@@ -952,6 +1571,45 @@ macro_rules! impl_state {
                 self.0.thermal_expansivity()
             }
 
+            /// Return partial derivative of volume w.r.t. temperature
+            /// at constant pressure.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn thermal_expansion_volume(&self) -> Quot<Volume, Temperature> {
+                self.0.thermal_expansion_volume()
+            }
+
+            /// Return molar volume.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn molar_volume(&self) -> MolarVolume {
+                self.0.molar_volume()
+            }
+
+            /// Return partial derivative of molar volume w.r.t. temperature
+            /// at constant pressure.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn dv_dt(&self) -> Quot<MolarVolume, Temperature> {
+                self.0.dv_dt()
+            }
+
+            /// Return partial derivative of molar volume w.r.t. pressure
+            /// at constant temperature.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn dv_dp(&self) -> Quot<MolarVolume, Pressure> {
+                self.0.dv_dp()
+            }
+
             /// Return Grueneisen parameter.
             ///
             /// Returns
@@ -970,6 +1628,82 @@ macro_rules! impl_state {
                 self.0.structure_factor()
             }
 
+            /// Return the packing fraction of the hard-sphere reference fluid.
+            ///
+            /// Returns
+            /// -------
+            /// float or None
+            ///     The packing fraction, or None if the equation of state
+            ///     is not based on a hard-sphere reference.
+            fn packing_fraction(&self) -> Option<f64> {
+                self.0.packing_fraction()
+            }
+
+            /// Return the phase identification parameter.
+            ///
+            /// Values larger than 1 indicate liquid-like states,
+            /// values smaller than 1 vapor-like states.
+            ///
+            /// Returns
+            /// -------
+            /// float
+            fn phase_identification_parameter(&self) -> f64 {
+                self.0.phase_identification_parameter()
+            }
+
+            /// Classify the state as liquid, vapor or supercritical.
+            ///
+            /// The critical point of the same composition is calculated
+            /// once per call. Use `is_liquid`, `is_vapor` or
+            /// `is_supercritical` only if a single check is needed.
+            ///
+            /// Parameters
+            /// ----------
+            /// critical_point : State, optional
+            ///     A precomputed critical point of the same composition.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseIdentification
+            #[pyo3(signature = (critical_point=None), text_signature = "($self, critical_point=None)")]
+            fn phase_identification(
+                &self,
+                critical_point: Option<PyRef<Self>>,
+            ) -> PyResult<PhaseIdentification> {
+                Ok(match critical_point {
+                    Some(cp) => self.0.phase_identification_with_critical_point(&cp.0),
+                    None => self.0.phase_identification()?,
+                })
+            }
+
+            /// Return whether the state is a subcritical liquid.
+            ///
+            /// Returns
+            /// -------
+            /// bool
+            fn is_liquid(&self) -> PyResult<bool> {
+                Ok(self.0.is_liquid()?)
+            }
+
+            /// Return whether the state is a subcritical vapor.
+            ///
+            /// Returns
+            /// -------
+            /// bool
+            fn is_vapor(&self) -> PyResult<bool> {
+                Ok(self.0.is_vapor()?)
+            }
+
+            /// Return whether the temperature and pressure are above the
+            /// critical point of the same composition.
+            ///
+            /// Returns
+            /// -------
+            /// bool
+            fn is_supercritical(&self) -> PyResult<bool> {
+                Ok(self.0.is_supercritical()?)
+            }
+
             /// Return total molar weight.
             ///
             /// Returns
@@ -988,6 +1722,18 @@ macro_rules! impl_state {
                 self.0.speed_of_sound()
             }
 
+            /// Return speed of sound of the ideal gas and of the total equation of state.
+            ///
+            /// The residual contributions alone do not define a speed of sound
+            /// and are only included in the total value.
+            ///
+            /// Returns
+            /// -------
+            /// List[Tuple[str, SINumber]]
+            fn speed_of_sound_contributions(&self) -> Vec<(String, Velocity)> {
+                self.0.speed_of_sound_contributions()
+            }
+
             /// Returns mass of each component in the system.
             ///
             /// Returns
@@ -1006,6 +1752,24 @@ macro_rules! impl_state {
                 self.0.total_mass()
             }
 
+            /// Return number density.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber
+            fn number_density(&self) -> Quot<f64, Volume> {
+                self.0.number_density()
+            }
+
+            /// Return partial number density for each component.
+            ///
+            /// Returns
+            /// -------
+            /// SIArray1
+            fn partial_number_density(&self) -> Quot<f64, Volume<Array1<f64>>> {
+                self.0.partial_number_density()
+            }
+
             /// Returns system's mass density.
             ///
             /// Returns
@@ -1205,6 +1969,28 @@ macro_rules! impl_state {
         #[pyclass(name = "StateVec")]
         pub struct PyStateVec(Vec<State<$eos>>);
 
+        /// Iterator over the states of a `StateVec`.
+        ///
+        /// The states are copied one at a time while iterating.
+        #[pyclass(name = "StateVecIterator")]
+        pub struct PyStateVecIterator {
+            states: Py<PyStateVec>,
+            index: usize,
+        }
+
+        #[pymethods]
+        impl PyStateVecIterator {
+            fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+                slf
+            }
+
+            fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<PyState> {
+                let state = slf.states.borrow(slf.py()).0.get(slf.index).cloned()?;
+                slf.index += 1;
+                Some(PyState(state))
+            }
+        }
+
         impl From<StateVec<'_, $eos>> for PyStateVec {
             fn from(vec: StateVec<$eos>) -> Self {
                 Self(vec.into_iter().map(|s| s.clone()).collect())
@@ -1224,6 +2010,58 @@ macro_rules! impl_state {
                 Self(states.into_iter().map(|s| s.0).collect())
             }
 
+            /// Create the states on a grid of temperatures and pressures.
+            ///
+            /// The states are ordered with the temperature as outer and
+            /// the pressure as inner index.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state to use.
+            /// temperatures : SIArray1
+            ///     Temperatures of the grid.
+            /// pressures : SIArray1
+            ///     Pressures of the grid.
+            /// molefracs : numpy.ndarray[float], optional
+            ///     Mole fractions of all states.
+            ///     Can be omitted for pure components.
+            /// density_initialization : {'vapor', 'liquid', SINumber, None}, optional
+            ///     Method used to initialize the density iterations.
+            ///     Defaults to None.
+            ///
+            /// Returns
+            /// -------
+            /// StateVec
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If a state can not be calculated. The message
+            ///     contains the index of the state in the grid.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperatures, pressures, molefracs=None, density_initialization=None)")]
+            #[pyo3(signature = (eos, temperatures, pressures, molefracs=None, density_initialization=None))]
+            fn from_grid(
+                eos: $py_eos,
+                temperatures: Temperature<Array1<f64>>,
+                pressures: Pressure<Array1<f64>>,
+                molefracs: Option<&Bound<'_, PyArray1<f64>>>,
+                density_initialization: Option<&Bound<'_, PyAny>>,
+            ) -> PyResult<Self> {
+                let density_initialization =
+                    $crate::python::parse_density_initialization(density_initialization)?;
+                let x = molefracs.map(|x| x.to_owned_array());
+                let states = State::new_grid(
+                    &eos.0,
+                    &temperatures,
+                    &pressures,
+                    x.as_ref(),
+                    density_initialization,
+                )?;
+                Ok(Self(states))
+            }
+
             fn __len__(&self) -> PyResult<usize> {
                 Ok(self.0.len())
             }
@@ -1241,6 +2079,13 @@ macro_rules! impl_state {
                 }
             }
 
+            fn __iter__(slf: Bound<'_, Self>) -> PyStateVecIterator {
+                PyStateVecIterator {
+                    states: slf.unbind(),
+                    index: 0,
+                }
+            }
+
             /// Return molar entropy.
             ///
             /// Parameters
@@ -1306,6 +2151,16 @@ macro_rules! impl_state {
             }
 
 
+            /// Return natural logarithm of fugacity coefficients of all states.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray
+            ///     Array with one row per state and one column per component.
+            fn ln_phi<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+                StateVec::from(self).ln_phi().into_pyarray_bound(py)
+            }
+
             #[getter]
             fn get_temperature(&self) -> Temperature<Array1<f64>> {
                 StateVec::from(self).temperature()
@@ -1353,12 +2208,23 @@ macro_rules! impl_state {
             /// contributions : Contributions, optional
             ///     The contributions to consider when calculating properties.
             ///     Defaults to Contributions.Total.
+            /// numeric_keys : bool, optional
+            ///     Use `x0`, `x1`, ... as keys for the molefractions
+            ///     instead of the component names. Defaults to False.
+            /// mass_basis : bool, optional
+            ///     Report all properties per unit mass instead of per mole.
+            ///     Requires molar weights. Defaults to False.
             ///
             /// Returns
             /// -------
             /// Dict[str, List[float]]
             ///     Keys: property names. Values: property for each state.
             ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If `mass_basis` is set and the molar weights are not available.
+            ///
             /// Notes
             /// -----
             /// - temperature : K
@@ -1367,32 +2233,191 @@ macro_rules! impl_state {
             /// - mass densities : kg / m³
             /// - molar enthalpies : kJ / mol
             /// - molar entropies : kJ / mol / K
+            /// - specific volumes : m³ / kg (only for `mass_basis`)
             /// - specific enthalpies : kJ / kg
             /// - specific entropies : kJ / kg / K
-            /// - xi: molefraction of component i
+            /// - specific Gibbs energies : kJ / kg (only for `mass_basis`)
+            /// - molefractions: keyed by the component name, or `xi` for component i
+            ///   if the name is not available or `numeric_keys` is set.
             /// - component index `i` matches to order of components in parameters.
-            #[pyo3(signature = (contributions=Contributions::Total), text_signature = "($self, contributions)")]
-            pub fn to_dict(&self, contributions: Contributions) -> HashMap<String, Vec<f64>> {
+            /// - with `mass_basis`, the density, molar enthalpy and molar entropy are omitted.
+            #[pyo3(signature = (contributions=Contributions::Total, numeric_keys=false, mass_basis=false), text_signature = "($self, contributions, numeric_keys=False, mass_basis=False)")]
+            pub fn to_dict(&self, contributions: Contributions, numeric_keys: bool, mass_basis: bool) -> PyResult<HashMap<String, Vec<f64>>> {
                 let states = StateVec::from(self);
-                let n = states.0[0].eos.components();
-                let mut dict = HashMap::with_capacity(8 + n);
-                if n != 1 {
-                    let xs = states.molefracs();
-                    for i in 0..n {
-                        dict.insert(String::from(format!("x{}", i)), xs.column(i).to_vec());
-                    }
-                }
-                dict.insert(String::from("temperature"), states.temperature().convert_to(KELVIN).into_raw_vec_and_offset().0);
-                dict.insert(String::from("pressure"), states.pressure().convert_to(PASCAL).into_raw_vec_and_offset().0);
-                dict.insert(String::from("density"), states.density().convert_to(MOL / METER.powi::<P3>()).into_raw_vec_and_offset().0);
-                dict.insert(String::from("molar enthalpy"), states.molar_enthalpy(contributions).convert_to(KILO * JOULE / MOL).into_raw_vec_and_offset().0);
-                dict.insert(String::from("molar entropy"), states.molar_entropy(contributions).convert_to(KILO * JOULE / KELVIN / MOL).into_raw_vec_and_offset().0);
-                if states.0[0].eos.residual.has_molar_weight() {
-                    dict.insert(String::from("mass density"), states.mass_density().convert_to(KILOGRAM / METER.powi::<P3>()).into_raw_vec_and_offset().0);
-                    dict.insert(String::from("specific enthalpy"), states.specific_enthalpy(contributions).convert_to(KILO * JOULE / KILOGRAM).into_raw_vec_and_offset().0);
-                    dict.insert(String::from("specific entropy"), states.specific_entropy(contributions).convert_to(KILO * JOULE / KELVIN / KILOGRAM).into_raw_vec_and_offset().0);
+                let identifiers = match (numeric_keys, states.first()) {
+                    (false, Some(state)) => state.eos.residual.component_identifiers(),
+                    _ => None,
+                };
+                Ok(states.to_dict(contributions, identifiers.as_deref(), mass_basis)?)
+            }
+        }
+
+        /// A builder to construct thermodynamic states step by step.
+        ///
+        /// All setters return the builder, so that calls can be chained.
+        /// The state is constructed with the same rules as the `State`
+        /// constructor once `build` is called.
+        ///
+        /// Parameters
+        /// ----------
+        /// eos : Eos
+        ///     The equation of state to use.
+        ///
+        /// Returns
+        /// -------
+        /// StateBuilder
+        #[pyclass(name = "StateBuilder")]
+        pub struct PyStateBuilder {
+            eos: $py_eos,
+            temperature: Option<Temperature>,
+            volume: Option<Volume>,
+            density: Option<Density>,
+            partial_density: Option<Density<Array1<f64>>>,
+            total_moles: Option<Moles>,
+            moles: Option<Moles<Array1<f64>>>,
+            molefracs: Option<Py<PyArray1<f64>>>,
+            pressure: Option<Pressure>,
+            molar_enthalpy: Option<MolarEnergy>,
+            molar_entropy: Option<MolarEntropy>,
+            molar_internal_energy: Option<MolarEnergy>,
+            density_initialization: Option<PyObject>,
+            initial_temperature: Option<Temperature>,
+        }
+
+        #[pymethods]
+        impl PyStateBuilder {
+            #[new]
+            fn new(eos: $py_eos) -> Self {
+                Self {
+                    eos,
+                    temperature: None,
+                    volume: None,
+                    density: None,
+                    partial_density: None,
+                    total_moles: None,
+                    moles: None,
+                    molefracs: None,
+                    pressure: None,
+                    molar_enthalpy: None,
+                    molar_entropy: None,
+                    molar_internal_energy: None,
+                    density_initialization: None,
+                    initial_temperature: None,
                 }
-                dict
+            }
+
+            /// Provide the temperature for the new state.
+            fn temperature(mut slf: PyRefMut<'_, Self>, temperature: Temperature) -> PyRefMut<'_, Self> {
+                slf.temperature = Some(temperature);
+                slf
+            }
+
+            /// Provide the volume for the new state.
+            fn volume(mut slf: PyRefMut<'_, Self>, volume: Volume) -> PyRefMut<'_, Self> {
+                slf.volume = Some(volume);
+                slf
+            }
+
+            /// Provide the molar density for the new state.
+            fn density(mut slf: PyRefMut<'_, Self>, density: Density) -> PyRefMut<'_, Self> {
+                slf.density = Some(density);
+                slf
+            }
+
+            /// Provide partial molar densities for the new state.
+            fn partial_density(mut slf: PyRefMut<'_, Self>, partial_density: Density<Array1<f64>>) -> PyRefMut<'_, Self> {
+                slf.partial_density = Some(partial_density);
+                slf
+            }
+
+            /// Provide the total amount of substance for the new state.
+            fn total_moles(mut slf: PyRefMut<'_, Self>, total_moles: Moles) -> PyRefMut<'_, Self> {
+                slf.total_moles = Some(total_moles);
+                slf
+            }
+
+            /// Provide the amount of substance of each component for the new state.
+            fn moles(mut slf: PyRefMut<'_, Self>, moles: Moles<Array1<f64>>) -> PyRefMut<'_, Self> {
+                slf.moles = Some(moles);
+                slf
+            }
+
+            /// Provide the molar fractions for the new state.
+            fn molefracs<'py>(mut slf: PyRefMut<'py, Self>, molefracs: &Bound<'py, PyArray1<f64>>) -> PyRefMut<'py, Self> {
+                slf.molefracs = Some(molefracs.clone().unbind());
+                slf
+            }
+
+            /// Provide the pressure for the new state.
+            fn pressure(mut slf: PyRefMut<'_, Self>, pressure: Pressure) -> PyRefMut<'_, Self> {
+                slf.pressure = Some(pressure);
+                slf
+            }
+
+            /// Provide the molar enthalpy for the new state.
+            fn molar_enthalpy(mut slf: PyRefMut<'_, Self>, molar_enthalpy: MolarEnergy) -> PyRefMut<'_, Self> {
+                slf.molar_enthalpy = Some(molar_enthalpy);
+                slf
+            }
+
+            /// Provide the molar entropy for the new state.
+            fn molar_entropy(mut slf: PyRefMut<'_, Self>, molar_entropy: MolarEntropy) -> PyRefMut<'_, Self> {
+                slf.molar_entropy = Some(molar_entropy);
+                slf
+            }
+
+            /// Provide the molar internal energy for the new state.
+            fn molar_internal_energy(mut slf: PyRefMut<'_, Self>, molar_internal_energy: MolarEnergy) -> PyRefMut<'_, Self> {
+                slf.molar_internal_energy = Some(molar_internal_energy);
+                slf
+            }
+
+            /// Specify a vapor state.
+            fn vapor(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+                slf.density_initialization = Some("vapor".into_py(slf.py()));
+                slf
+            }
+
+            /// Specify a liquid state.
+            fn liquid(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+                slf.density_initialization = Some("liquid".into_py(slf.py()));
+                slf
+            }
+
+            /// Provide an initial molar or mass density used in density iterations.
+            fn initial_density<'py>(mut slf: PyRefMut<'py, Self>, initial_density: &Bound<'py, PyAny>) -> PyRefMut<'py, Self> {
+                slf.density_initialization = Some(initial_density.clone().unbind());
+                slf
+            }
+
+            /// Provide an initial temperature used in the Newton solver.
+            fn initial_temperature(mut slf: PyRefMut<'_, Self>, initial_temperature: Temperature) -> PyRefMut<'_, Self> {
+                slf.initial_temperature = Some(initial_temperature);
+                slf
+            }
+
+            /// Try to build the state with the given inputs.
+            ///
+            /// Returns
+            /// -------
+            /// State
+            fn build(&self, py: Python<'_>) -> PyResult<PyState> {
+                PyState::new(
+                    self.eos.clone(),
+                    self.temperature,
+                    self.volume,
+                    self.density,
+                    self.partial_density.clone(),
+                    self.total_moles,
+                    self.moles.clone(),
+                    self.molefracs.as_ref().map(|x| x.bind(py)),
+                    self.pressure,
+                    self.molar_enthalpy,
+                    self.molar_entropy,
+                    self.molar_internal_energy,
+                    self.density_initialization.as_ref().map(|d| d.bind(py)),
+                    self.initial_temperature,
+                )
             }
         }
     };
@@ -0,0 +1,68 @@
+// the wrappers generated by `#[pyfunction]` convert `PyErr` into itself
+#![allow(clippy::useless_conversion)]
+use crate::reference_value;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Reference value of the unit of an `SIObject`.
+///
+/// `si_units` stores the exponents in the order m, kg, s, A, mol, K, cd.
+fn si_reference_value<'py>(
+    unit: &Bound<'py, PyAny>,
+) -> PyResult<(Bound<'py, PyAny>, [i8; 7], f64)> {
+    let Ok((value, [l, m, t, i, n, theta, j])) = unit
+        .call_method0("__getnewargs__")
+        .and_then(|raw| raw.extract::<(Bound<'py, PyAny>, [i8; 7])>())
+    else {
+        return Err(PyValueError::new_err(format!(
+            "Missing units! Expected an SIObject, got {}.",
+            unit.repr()?
+        )));
+    };
+    let exponents = [t, l, m, i, theta, n, j].map(i32::from);
+    Ok((value, [l, m, t, i, n, theta, j], reference_value(exponents)))
+}
+
+/// Convert a quantity to the reduced units used internally in FeOs.
+///
+/// Parameters
+/// ----------
+/// quantity : SINumber | SIArray
+///     The quantity to convert.
+///
+/// Returns
+/// -------
+/// float | numpy.ndarray[float]
+#[pyfunction]
+#[pyo3(text_signature = "(quantity)")]
+pub fn reduce<'py>(quantity: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let (value, _, reference) = si_reference_value(quantity)?;
+    value.div(reference)
+}
+
+/// Convert a value in the reduced units used internally in FeOs to a quantity.
+///
+/// Parameters
+/// ----------
+/// reduced_value : float | numpy.ndarray[float]
+///     The value in reduced units.
+/// unit : SINumber
+///     Any quantity with the units of the result, e.g., `si.PASCAL`.
+///     Only the units are used, not the value.
+///
+/// Returns
+/// -------
+/// SINumber | SIArray
+#[pyfunction]
+#[pyo3(text_signature = "(reduced_value, unit)")]
+pub fn to_si<'py>(
+    reduced_value: &Bound<'py, PyAny>,
+    unit: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = unit.py();
+    let (_, exponents, reference) = si_reference_value(unit)?;
+    let value = reduced_value.mul(reference)?;
+    py.import_bound("si_units")?
+        .getattr("SIObject")?
+        .call1((value, exponents))
+}
@@ -112,6 +112,9 @@ impl<'a, E: Residual, const IG: bool> StateBuilder<'a, E, IG> {
     }
 
     /// Provide partial densities for the new state.
+    ///
+    /// The partial densities determine the density and the composition of the state
+    /// and can therefore not be combined with a density, moles or mole fractions.
     pub fn partial_density(mut self, partial_density: &'a Density<Array1<f64>>) -> Self {
         self.partial_density = Some(partial_density);
         self
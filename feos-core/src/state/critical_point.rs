@@ -1,7 +1,9 @@
 use super::{DensityInitialization, State, StateHD, TPSpec};
 use crate::equation_of_state::Residual;
-use crate::errors::{EosError, EosResult};
-use crate::{ReferenceSystem, SolverOptions, TemperatureOrPressure, Verbosity};
+use crate::errors::{CriticalPointSpecification, CriticalityCondition, EosError, EosResult};
+use crate::{
+    Contributions, ReferenceSystem, SolverOptions, SolverStats, TemperatureOrPressure, Verbosity,
+};
 use nalgebra::SVector;
 use ndarray::{arr1, Array1, Array2};
 use num_dual::linalg::smallest_ev;
@@ -25,8 +27,27 @@ impl<R: Residual> State<R> {
         initial_temperature: Option<Temperature>,
         options: SolverOptions,
     ) -> EosResult<Vec<Self>> {
-        (0..eos.components())
-            .map(|i| {
+        let components: Vec<_> = (0..eos.components()).collect();
+        Self::critical_point_pure_subset(eos, initial_temperature, &components, options)
+    }
+
+    /// Calculate the pure component critical points of the components with
+    /// the given indices (in the given order).
+    ///
+    /// An index that is out of range results in an
+    /// [EosError::IncompatibleComponents] error.
+    pub fn critical_point_pure_subset(
+        eos: &Arc<R>,
+        initial_temperature: Option<Temperature>,
+        components: &[usize],
+        options: SolverOptions,
+    ) -> EosResult<Vec<Self>> {
+        if let Some(&i) = components.iter().find(|&&i| i >= eos.components()) {
+            return Err(EosError::IncompatibleComponents(eos.components(), i + 1));
+        }
+        components
+            .iter()
+            .map(|&i| {
                 Self::critical_point(
                     &Arc::new(eos.subset(&[i])),
                     None,
@@ -37,6 +58,12 @@ impl<R: Residual> State<R> {
             .collect()
     }
 
+    /// Calculate the critical point of a binary system for given temperature
+    /// or pressure.
+    ///
+    /// If the iteration does not converge, an
+    /// [EosError::CriticalPointNotConverged] is returned that contains the
+    /// specified condition, the final residual norm and the number of iterations.
     pub fn critical_point_binary<TP: TemperatureOrPressure>(
         eos: &Arc<R>,
         temperature_or_pressure: TP,
@@ -44,43 +71,230 @@ impl<R: Residual> State<R> {
         initial_molefracs: Option<[f64; 2]>,
         options: SolverOptions,
     ) -> EosResult<Self> {
+        let x = initial_molefracs.unwrap_or([0.5, 0.5]);
+        let max_density = eos
+            .max_density(Some(&Moles::from_reduced(arr1(&x))))?
+            .to_reduced();
+        let rho = SVector::from(x) * 0.3 * max_density;
+        let t0 = initial_temperature.map_or(300.0, |t| t.to_reduced());
         match temperature_or_pressure.into() {
-            TPSpec::Temperature(t) => {
-                Self::critical_point_binary_t(eos, t, initial_molefracs, options)
-            }
-            TPSpec::Pressure(p) => Self::critical_point_binary_p(
-                eos,
-                p,
-                initial_temperature,
-                initial_molefracs,
-                options,
-            ),
+            TPSpec::Temperature(t) => Self::critical_point_binary_t(eos, t, rho, options),
+            TPSpec::Pressure(p) => Self::critical_point_binary_p(eos, p, t0, rho, options),
+        }
+    }
+
+    /// Calculate the critical point of a binary system for given temperature
+    /// or pressure using a critical point at different conditions as initial guess.
+    ///
+    /// When tracing a critical line, the critical point at a nearby temperature
+    /// or pressure is a much better initial guess than the default values used
+    /// in [State::critical_point_binary] and reduces the number of iterations.
+    pub fn critical_point_binary_from_state<TP: TemperatureOrPressure>(
+        eos: &Arc<R>,
+        temperature_or_pressure: TP,
+        initial_state: &Self,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let rho = initial_state.partial_density.to_reduced();
+        if rho.len() != 2 {
+            return Err(EosError::IncompatibleComponents(rho.len(), 2));
+        }
+        let rho = SVector::from([rho[0], rho[1]]);
+        let t0 = initial_state.temperature.to_reduced();
+        match temperature_or_pressure.into() {
+            TPSpec::Temperature(t) => Self::critical_point_binary_t(eos, t, rho, options),
+            TPSpec::Pressure(p) => Self::critical_point_binary_p(eos, p, t0, rho, options),
         }
     }
 
     /// Calculate the critical point of a system for given moles.
+    ///
+    /// For pure components, a bisection of the temperature is used as
+    /// fallback if the Newton iteration fails or converges to an
+    /// unphysical solution (see [State::critical_point_with_stats]).
+    ///
+    /// The result is a regular [State], i.e., derived quantities like the
+    /// critical compressibility factor $Z_c=\frac{p_c}{\rho_cRT_c}$ are
+    /// available via [State::compressibility].
     pub fn critical_point(
         eos: &Arc<R>,
         moles: Option<&Moles<Array1<f64>>>,
         initial_temperature: Option<Temperature>,
         options: SolverOptions,
     ) -> EosResult<Self> {
+        let (state, stats) =
+            Self::critical_point_with_stats(eos, moles, initial_temperature, options)?;
+        if !stats.converged {
+            let (eigenvalue, cubic) = state.critical_point_residuals();
+            return Err(EosError::CriticalPointNotConverged {
+                specification: CriticalPointSpecification::Composition,
+                condition: CriticalityCondition::from_residuals(&[eigenvalue, cubic]),
+                residual: stats.residual,
+                iterations: stats.iterations,
+            });
+        }
+        Ok(state)
+    }
+
+    /// Calculate the critical point of a system for given moles and
+    /// return the convergence statistics of the iteration.
+    ///
+    /// If the iteration does not converge within the maximum number of
+    /// iterations, the last iterate is returned with
+    /// [SolverStats::converged] set to `false`.
+    ///
+    /// For pure components, the critical temperature is bracketed and
+    /// bisected if the Newton iteration fails, does not converge or
+    /// converges to a state with non-positive pressure. The bisection
+    /// requires many more evaluations of the equation of state than the
+    /// Newton iteration, i.e., it trades speed for robustness. It is
+    /// controlled by the same `options`.
+    pub fn critical_point_with_stats(
+        eos: &Arc<R>,
+        moles: Option<&Moles<Array1<f64>>>,
+        initial_temperature: Option<Temperature>,
+        options: SolverOptions,
+    ) -> EosResult<(Self, SolverStats)> {
         let moles = eos.validate_moles(moles)?;
         let trial_temperatures = [
             Temperature::from_reduced(300.0),
             Temperature::from_reduced(700.0),
             Temperature::from_reduced(500.0),
         ];
-        if let Some(t) = initial_temperature {
-            return Self::critical_point_hkm(eos, &moles, t, options);
+        let result = if let Some(t) = initial_temperature {
+            Self::critical_point_hkm(eos, &moles, t, options)
+        } else {
+            let mut result = Err(EosError::CriticalPointNotConverged {
+                specification: CriticalPointSpecification::Composition,
+                condition: CriticalityCondition::StabilityLimit,
+                residual: f64::NAN,
+                iterations: 0,
+            });
+            for &t in trial_temperatures.iter() {
+                match Self::critical_point_hkm(eos, &moles, t, options) {
+                    Ok((state, stats)) if stats.converged && state.is_physical() => {
+                        return Ok((state, stats))
+                    }
+                    Ok(res) => result = Ok(res),
+                    Err(e) if result.is_err() => result = Err(e),
+                    Err(_) => (),
+                }
+            }
+            result
+        };
+        match result {
+            Ok((state, stats)) if stats.converged && state.is_physical() => Ok((state, stats)),
+            result if moles.len() == 1 => {
+                let t = initial_temperature.unwrap_or(trial_temperatures[0]);
+                Self::critical_point_bisection(eos, &moles, t, options).or(result)
+            }
+            result => result,
         }
-        for &t in trial_temperatures.iter() {
-            let s = Self::critical_point_hkm(eos, &moles, t, options);
-            if s.is_ok() {
-                return s;
+    }
+
+    fn is_physical(&self) -> bool {
+        self.pressure(Contributions::Total).to_reduced() > 0.0
+    }
+
+    /// Calculate the critical point of a pure component by bisection of
+    /// the temperature at which the stability limit vanishes.
+    fn critical_point_bisection(
+        eos: &Arc<R>,
+        moles: &Moles<Array1<f64>>,
+        initial_temperature: Temperature,
+        options: SolverOptions,
+    ) -> EosResult<(Self, SolverStats)> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_CRIT_POINT, TOL_CRIT_POINT);
+
+        let max_density = eos.max_density(Some(moles))?.to_reduced();
+        let n = moles.to_reduced();
+        let stability_limit = |t| minimum_stability_eigenvalue(eos, t, &n, max_density);
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |   temperature   |       density        "
+        );
+        log_iter!(verbosity, "{:-<64}", "");
+
+        // bracket the critical temperature
+        let mut t0 = initial_temperature.to_reduced();
+        let (mut q0, _) = stability_limit(t0)?;
+        let step = if q0 < 0.0 { 2.0 } else { 0.5 };
+        let mut t1 = t0 * step;
+        let (mut q1, mut rho) = stability_limit(t1)?;
+        let mut iter = 1;
+        while (q0 < 0.0) == (q1 < 0.0) {
+            if iter >= max_iter {
+                return Err(EosError::CriticalPointNotConverged {
+                    specification: CriticalPointSpecification::Composition,
+                    condition: CriticalityCondition::StabilityLimit,
+                    residual: q1.abs(),
+                    iterations: iter,
+                });
             }
+            (t0, q0) = (t1, q1);
+            t1 *= step;
+            (q1, rho) = stability_limit(t1)?;
+            iter += 1;
         }
-        Err(EosError::NotConverged(String::from("Critical point")))
+        let (mut t_low, mut t_high) = if q0 < 0.0 { (t0, t1) } else { (t1, t0) };
+
+        // bisection
+        let mut t = 0.5 * (t_low + t_high);
+        let mut residual = (t_high - t_low) / t;
+        while iter < max_iter && residual >= tol {
+            iter += 1;
+            let q;
+            (q, rho) = stability_limit(t)?;
+            if q < 0.0 {
+                t_low = t;
+            } else {
+                t_high = t;
+            }
+            t = 0.5 * (t_low + t_high);
+            residual = (t_high - t_low) / t;
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:13.8} | {:12.8}",
+                iter,
+                residual,
+                Temperature::from_reduced(t),
+                Density::from_reduced(rho),
+            );
+        }
+        let converged = residual < tol;
+
+        // refine the bracketed critical point using the Newton iteration
+        if converged {
+            if let Ok((state, stats)) =
+                Self::critical_point_hkm(eos, moles, Temperature::from_reduced(t), options)
+            {
+                if stats.converged && state.is_physical() {
+                    log_result!(
+                        verbosity,
+                        "Critical point calculation converged in {} bisection step(s)\n",
+                        iter
+                    );
+                    let stats = SolverStats {
+                        iterations: iter + stats.iterations,
+                        ..stats
+                    };
+                    return Ok((state, stats));
+                }
+            }
+        }
+        let state = State::new_nvt(
+            eos,
+            Temperature::from_reduced(t),
+            moles.sum() / Density::from_reduced(rho),
+            moles,
+        )?;
+        let stats = SolverStats {
+            iterations: iter,
+            residual,
+            converged,
+        };
+        Ok((state, stats))
     }
 
     fn critical_point_hkm(
@@ -88,13 +302,14 @@ impl<R: Residual> State<R> {
         moles: &Moles<Array1<f64>>,
         initial_temperature: Temperature,
         options: SolverOptions,
-    ) -> EosResult<Self> {
+    ) -> EosResult<(Self, SolverStats)> {
         let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_CRIT_POINT, TOL_CRIT_POINT);
 
         let mut t = initial_temperature.to_reduced();
         let max_density = eos.max_density(Some(moles))?.to_reduced();
         let mut rho = 0.3 * max_density;
         let n = moles.to_reduced();
+        let mut residual = f64::NAN;
 
         log_iter!(
             verbosity,
@@ -131,49 +346,66 @@ impl<R: Residual> State<R> {
             rho -= delta[1];
             rho = f64::max(rho, 1e-4 * max_density);
 
+            residual = res.norm();
             log_iter!(
                 verbosity,
                 " {:4} | {:14.8e} | {:13.8} | {:12.8}",
                 i,
-                res.norm(),
+                residual,
                 Temperature::from_reduced(t),
                 Density::from_reduced(rho),
             );
 
             // check convergence
-            if res.norm() < tol {
+            if residual < tol {
                 log_result!(
                     verbosity,
                     "Critical point calculation converged in {} step(s)\n",
                     i
                 );
-                return State::new_nvt(
+                let state = State::new_nvt(
                     eos,
                     Temperature::from_reduced(t),
                     moles.sum() / Density::from_reduced(rho),
                     moles,
-                );
+                )?;
+                let stats = SolverStats {
+                    iterations: i,
+                    residual,
+                    converged: true,
+                };
+                return Ok((state, stats));
             }
         }
-        Err(EosError::NotConverged(String::from("Critical point")))
+        let state = State::new_nvt(
+            eos,
+            Temperature::from_reduced(t),
+            moles.sum() / Density::from_reduced(rho),
+            moles,
+        )?;
+        let stats = SolverStats {
+            iterations: max_iter,
+            residual,
+            converged: false,
+        };
+        Ok((state, stats))
     }
 
     /// Calculate the critical point of a binary system for given temperature.
     fn critical_point_binary_t(
         eos: &Arc<R>,
         temperature: Temperature,
-        initial_molefracs: Option<[f64; 2]>,
+        initial_density: SVector<f64, 2>,
         options: SolverOptions,
     ) -> EosResult<Self> {
         let (max_iter, tol, verbosity) =
             options.unwrap_or(MAX_ITER_CRIT_POINT_BINARY, TOL_CRIT_POINT);
 
         let t = temperature.to_reduced();
-        let x = SVector::from(initial_molefracs.unwrap_or([0.5, 0.5]));
+        let mut rho = initial_density;
         let max_density = eos
-            .max_density(Some(&Moles::from_reduced(arr1(&x.data.0[0]))))?
+            .max_density(Some(&Moles::from_reduced(arr1(&rho.data.0[0]))))?
             .to_reduced();
-        let mut rho = x * 0.3 * max_density;
 
         log_iter!(
             verbosity,
@@ -188,10 +420,14 @@ impl<R: Residual> State<R> {
             Density::from_reduced(rho[1]),
         );
 
+        let mut residual = f64::NAN;
+        let mut condition = CriticalityCondition::StabilityLimit;
         for i in 1..=max_iter {
             // calculate residuals and derivatives w.r.t. partial densities
             let res = |rho| critical_point_objective_t(eos, t, rho);
             let (res, jac) = try_jacobian(res, rho)?;
+            residual = res.norm();
+            condition = CriticalityCondition::from_residuals(res.as_slice());
 
             // calculate Newton step
             let delta = jac.lu().solve(&res);
@@ -213,13 +449,13 @@ impl<R: Residual> State<R> {
                 verbosity,
                 " {:4} | {:14.8e} | {:12.8} | {:12.8}",
                 i,
-                res.norm(),
+                residual,
                 Density::from_reduced(rho[0]),
                 Density::from_reduced(rho[1]),
             );
 
             // check convergence
-            if res.norm() < tol {
+            if residual < tol {
                 log_result!(
                     verbosity,
                     "Critical point calculation converged in {} step(s)\n",
@@ -233,27 +469,33 @@ impl<R: Residual> State<R> {
                 );
             }
         }
-        Err(EosError::NotConverged(String::from("Critical point")))
+        Err(EosError::CriticalPointNotConverged {
+            specification: CriticalPointSpecification::Temperature,
+            condition,
+            residual,
+            iterations: max_iter,
+        })
     }
 
     /// Calculate the critical point of a binary system for given pressure.
     fn critical_point_binary_p(
         eos: &Arc<R>,
         pressure: Pressure,
-        initial_temperature: Option<Temperature>,
-        initial_molefracs: Option<[f64; 2]>,
+        initial_temperature: f64,
+        initial_density: SVector<f64, 2>,
         options: SolverOptions,
     ) -> EosResult<Self> {
         let (max_iter, tol, verbosity) =
             options.unwrap_or(MAX_ITER_CRIT_POINT_BINARY, TOL_CRIT_POINT);
 
         let p = pressure.to_reduced();
-        let mut t = initial_temperature.map(|t| t.to_reduced()).unwrap_or(300.0);
-        let x = SVector::from(initial_molefracs.unwrap_or([0.5, 0.5]));
+        let mut t = initial_temperature;
+        let mut rho = initial_density;
+        let mut residual = f64::NAN;
+        let mut condition = CriticalityCondition::StabilityLimit;
         let max_density = eos
-            .max_density(Some(&Moles::from_reduced(arr1(&x.data.0[0]))))?
+            .max_density(Some(&Moles::from_reduced(arr1(&rho.data.0[0]))))?
             .to_reduced();
-        let mut rho = x * 0.3 * max_density;
 
         log_iter!(
             verbosity,
@@ -276,6 +518,8 @@ impl<R: Residual> State<R> {
                 critical_point_objective_p(eos, p, x[0], r)
             };
             let (res, jac) = try_jacobian(res, SVector::from([t, rho[0], rho[1]]))?;
+            residual = res.norm();
+            condition = CriticalityCondition::from_residuals(res.as_slice());
 
             // calculate Newton step
             let delta = jac.lu().solve(&res);
@@ -303,14 +547,14 @@ impl<R: Residual> State<R> {
                 verbosity,
                 " {:4} | {:14.8e} | {:13.8} | {:12.8} | {:12.8}",
                 i,
-                res.norm(),
+                residual,
                 Temperature::from_reduced(t),
                 Density::from_reduced(rho[0]),
                 Density::from_reduced(rho[1]),
             );
 
             // check convergence
-            if res.norm() < tol {
+            if residual < tol {
                 log_result!(
                     verbosity,
                     "Critical point calculation converged in {} step(s)\n",
@@ -324,7 +568,53 @@ impl<R: Residual> State<R> {
                 );
             }
         }
-        Err(EosError::NotConverged(String::from("Critical point")))
+        Err(EosError::CriticalPointNotConverged {
+            specification: CriticalPointSpecification::Pressure,
+            condition,
+            residual,
+            iterations: max_iter,
+        })
+    }
+
+    /// Evaluate the criticality conditions from the derivatives of the state.
+    ///
+    /// Returns the smallest eigenvalue $\lambda$ of the stability matrix
+    /// $Q_{ij}=\frac{\sqrt{N_iN_j}}{k_BT}\left(\frac{\partial\mu_i}{\partial N_j}\right)_{T,V}$
+    /// and the cubic form $C=\sqrt{N}\sum_{ijk}\frac{\partial^3\beta A}{\partial N_i\partial N_j\partial N_k}\Delta N_i\Delta N_j\Delta N_k$
+    /// with $\Delta N_i=\sqrt{N_i}u_i$ and the corresponding eigenvector $u_i$. Both values
+    /// are independent of the size of the system and vanish
+    /// at a critical point. For a pure component, the conditions are equivalent to
+    /// $\left(\frac{\partial p}{\partial\rho}\right)_T=\left(\frac{\partial^2p}{\partial\rho^2}\right)_T=0$.
+    ///
+    /// The values are not obtained from the objective function of the critical point
+    /// solvers but from the chemical potential derivatives of the state, with the cubic
+    /// form evaluated using central finite differences. They can therefore be used to
+    /// verify the result of a solver: significant deviations of either value from zero
+    /// indicate that the solver did not converge to a critical point.
+    pub fn critical_point_residuals(&self) -> (f64, f64) {
+        let t = self.temperature.to_reduced();
+        let sqrt_n = self.moles.to_reduced().mapv(f64::sqrt);
+        let stability_matrix = |state: &Self| {
+            let dmu_dni = state.dmu_dni(Contributions::Total).to_reduced();
+            Array2::from_shape_fn(dmu_dni.raw_dim(), |(i, j)| {
+                dmu_dni[[i, j]] / t * sqrt_n[i] * sqrt_n[j]
+            })
+        };
+        let (eigenvalue, u) = smallest_ev(stability_matrix(self));
+
+        let h = 1e-5 * self.total_moles.to_reduced().sqrt();
+        let quadratic_form = |s: f64| {
+            let moles = self.moles.to_reduced() + s * &u * &sqrt_n;
+            let state = Self::new_nvt_unchecked(
+                &self.eos,
+                self.temperature,
+                self.volume,
+                &Moles::from_reduced(moles),
+            );
+            u.dot(&stability_matrix(&state).dot(&u))
+        };
+        let cubic = (quadratic_form(h) - quadratic_form(-h)) / (2.0 * h);
+        (eigenvalue, cubic * self.total_moles.to_reduced().sqrt())
     }
 
     pub fn spinodal(
@@ -333,6 +623,26 @@ impl<R: Residual> State<R> {
         moles: Option<&Moles<Array1<f64>>>,
         options: SolverOptions,
     ) -> EosResult<[Self; 2]> {
+        let [(spinodal_vapor, stats_vapor), (spinodal_liquid, stats_liquid)] =
+            Self::spinodal_with_stats(eos, temperature, moles, options)?;
+        if !(stats_vapor.converged && stats_liquid.converged) {
+            return Err(EosError::SuperCritical);
+        }
+        Ok([spinodal_vapor, spinodal_liquid])
+    }
+
+    /// Calculate the vapor and liquid spinodal states and return the
+    /// convergence statistics of both iterations.
+    ///
+    /// If an iteration does not converge within the maximum number of
+    /// iterations, the last iterate is returned with
+    /// [SolverStats::converged] set to `false`.
+    pub fn spinodal_with_stats(
+        eos: &Arc<R>,
+        temperature: Temperature,
+        moles: Option<&Moles<Array1<f64>>>,
+        options: SolverOptions,
+    ) -> EosResult<[(Self, SolverStats); 2]> {
         let critical_point = Self::critical_point(eos, moles, None, options)?;
         let moles = eos.validate_moles(moles)?;
         let spinodal_vapor = Self::calculate_spinodal(
@@ -342,7 +652,7 @@ impl<R: Residual> State<R> {
             DensityInitialization::Vapor,
             options,
         )?;
-        let rho = 2.0 * critical_point.density - spinodal_vapor.density;
+        let rho = 2.0 * critical_point.density - spinodal_vapor.0.density;
         let spinodal_liquid = Self::calculate_spinodal(
             eos,
             temperature,
@@ -359,7 +669,7 @@ impl<R: Residual> State<R> {
         moles: &Moles<Array1<f64>>,
         density_initialization: DensityInitialization,
         options: SolverOptions,
-    ) -> EosResult<Self> {
+    ) -> EosResult<(Self, SolverStats)> {
         let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_CRIT_POINT, TOL_CRIT_POINT);
 
         let max_density = eos.max_density(Some(moles))?.to_reduced();
@@ -371,6 +681,7 @@ impl<R: Residual> State<R> {
             DensityInitialization::None => unreachable!(),
         };
         let n = moles.to_reduced();
+        let mut residual = f64::NAN;
 
         log_iter!(verbosity, " iter |    residual    |       density        ");
         log_iter!(verbosity, "{:-<46}", "");
@@ -398,30 +709,48 @@ impl<R: Residual> State<R> {
             rho -= delta;
             rho = f64::max(rho, 1e-4 * max_density);
 
+            residual = f.abs();
             log_iter!(
                 verbosity,
                 " {:4} | {:14.8e} | {:12.8}",
                 i,
-                f.abs(),
+                residual,
                 Density::from_reduced(rho),
             );
 
             // check convergence
-            if f.abs() < tol {
+            if residual < tol {
                 log_result!(
                     verbosity,
                     "Spinodal calculation converged in {} step(s)\n",
                     i
                 );
-                return State::new_nvt(
+                let state = State::new_nvt(
                     eos,
                     temperature,
                     moles.sum() / Density::from_reduced(rho),
                     moles,
-                );
+                )?;
+                let stats = SolverStats {
+                    iterations: i,
+                    residual,
+                    converged: true,
+                };
+                return Ok((state, stats));
             }
         }
-        Err(EosError::SuperCritical)
+        let state = State::new_nvt(
+            eos,
+            temperature,
+            moles.sum() / Density::from_reduced(rho),
+            moles,
+        )?;
+        let stats = SolverStats {
+            iterations: max_iter,
+            residual,
+            converged: false,
+        };
+        Ok((state, stats))
     }
 }
 
@@ -465,6 +794,48 @@ fn critical_point_objective<R: Residual>(
     Ok(SVector::from([eval, (res + ig).v3]))
 }
 
+/// Minimize the smallest eigenvalue of the stability matrix w.r.t. density
+/// using a golden-section search. The minimum is negative below and
+/// positive above the critical temperature.
+fn minimum_stability_eigenvalue<R: Residual>(
+    eos: &Arc<R>,
+    temperature: f64,
+    moles: &Array1<f64>,
+    max_density: f64,
+) -> EosResult<(f64, f64)> {
+    let eigenvalue = |density: f64| -> EosResult<f64> {
+        let res = critical_point_objective(
+            eos,
+            DualSVec64::from(temperature),
+            DualSVec64::from(density),
+            moles,
+        )?;
+        let q = res[0].re;
+        if q.is_finite() {
+            Ok(q)
+        } else {
+            Err(EosError::IterationFailed(String::from("Critical point")))
+        }
+    };
+    let ratio = 0.5 * (5f64.sqrt() - 1.0);
+    let (mut a, mut b) = (1e-3 * max_density, 0.9 * max_density);
+    let mut c = b - ratio * (b - a);
+    let mut d = a + ratio * (b - a);
+    let (mut qc, mut qd) = (eigenvalue(c)?, eigenvalue(d)?);
+    while b - a > 1e-8 * max_density {
+        if qc < qd {
+            (b, d, qd) = (d, c, qc);
+            c = b - ratio * (b - a);
+            qc = eigenvalue(c)?;
+        } else {
+            (a, c, qc) = (c, d, qd);
+            d = a + ratio * (b - a);
+            qd = eigenvalue(d)?;
+        }
+    }
+    Ok(if qc < qd { (qc, c) } else { (qd, d) })
+}
+
 fn critical_point_objective_t<R: Residual>(
     eos: &Arc<R>,
     temperature: f64,
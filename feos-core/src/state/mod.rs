@@ -7,17 +7,18 @@
 //!
 //! Internally, all properties are computed using such states as input.
 use crate::density_iteration::density_iteration;
-use crate::equation_of_state::{IdealGas, Residual};
+use crate::equation_of_state::{Components, IdealGas, Molarweight, Residual};
 use crate::errors::{EosError, EosResult};
 use crate::ReferenceSystem;
 use cache::Cache;
 use ndarray::prelude::*;
 use num_dual::*;
 use quantity::*;
+use std::collections::HashMap;
 use std::fmt;
 use std::ops::Sub;
 use std::sync::{Arc, Mutex};
-use typenum::{N1, N2, P1, Z0};
+use typenum::{Quot, N1, N2, N3, P1, Z0};
 
 mod builder;
 mod cache;
@@ -41,8 +42,20 @@ pub enum Contributions {
     Total,
 }
 
+/// Classification of a state as liquid, vapor or supercritical.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum PhaseIdentification {
+    /// Subcritical state with a phase identification parameter larger than 1.
+    Liquid,
+    /// Subcritical state with a phase identification parameter smaller than 1.
+    Vapor,
+    /// Temperature and pressure above the critical point.
+    Supercritical,
+}
+
 /// Initial values in a density iteration.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum DensityInitialization {
     /// Calculate a vapor phase by initializing using the ideal gas.
     Vapor,
@@ -55,6 +68,58 @@ pub enum DensityInitialization {
     None,
 }
 
+impl fmt::Display for DensityInitialization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vapor => write!(f, "vapor"),
+            Self::Liquid => write!(f, "liquid"),
+            Self::InitialDensity(rho) => write!(f, "initial density {rho}"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+impl DensityInitialization {
+    /// Use the given mass density as initial value.
+    ///
+    /// The mass density is converted to a molar density using the molar
+    /// weight of a mixture with the given composition. The composition does
+    /// not have to be normalized; if it is not provided, an equimolar mixture
+    /// is assumed.
+    ///
+    /// Fails with [EosError::MissingMolarWeight] if the model does not
+    /// provide molar weights and with [EosError::InvalidState] if the mass
+    /// density is not finite and positive.
+    pub fn from_mass_density<E: Components + Molarweight>(
+        eos: &E,
+        mass_density: MassDensity,
+        composition: Option<&Array1<f64>>,
+    ) -> EosResult<Self> {
+        if !eos.has_molar_weight() {
+            return Err(EosError::MissingMolarWeight(String::from(
+                "A mass density as initial density",
+            )));
+        }
+        let rho = mass_density.to_reduced();
+        if !rho.is_finite() || rho <= 0.0 {
+            return Err(EosError::InvalidState(
+                String::from("State"),
+                String::from("mass density"),
+                rho,
+            ));
+        }
+        let n = eos.components();
+        let x = match composition {
+            Some(x) if x.len() != n => return Err(EosError::IncompatibleComposition(n, x.len())),
+            Some(x) => x / x.sum(),
+            None => Array1::from_elem(n, 1.0 / n as f64),
+        };
+        Ok(Self::InitialDensity(
+            mass_density / eos.molar_weight_of_mixture(&x),
+        ))
+    }
+}
+
 /// Thermodynamic state of the system in reduced variables
 /// including their derivatives.
 ///
@@ -76,6 +141,10 @@ pub struct StateHD<D: DualNum<f64>> {
 
 impl<D: DualNum<f64> + Copy> StateHD<D> {
     /// Create a new `StateHD` for given temperature volume and moles.
+    ///
+    /// All variables are in reduced units: the temperature in Kelvin, the
+    /// volume in Angstrom^3 and the moles as number of particles. Derivatives
+    /// are obtained by choosing the appropriate dual number type.
     pub fn new(temperature: D, volume: D, moles: Array1<D>) -> Self {
         let total_moles = moles.sum();
         let partial_density = moles.mapv(|n| n / volume);
@@ -90,9 +159,12 @@ impl<D: DualNum<f64> + Copy> StateHD<D> {
         }
     }
 
-    // Since the molefracs can not be reproduced from moles if the density is zero,
-    // this constructor exists specifically for these cases.
-    pub(crate) fn new_virial(temperature: D, density: D, molefracs: Array1<f64>) -> Self {
+    /// Create a new `StateHD` for given temperature, density and mole fractions.
+    ///
+    /// Since the mole fractions can not be reproduced from the moles if the
+    /// density is zero, this constructor exists specifically for these cases,
+    /// e.g., for the calculation of virial coefficients. The volume is set to 1.
+    pub fn new_virial(temperature: D, density: D, molefracs: Array1<f64>) -> Self {
         let volume = D::one();
         let partial_density = molefracs.mapv(|x| density * x);
         let moles = partial_density.mapv(|pd| pd * volume);
@@ -267,15 +339,38 @@ impl<E: Residual> State<E> {
         }
     }
 
-    /// Return a new `State` for a pure component given a temperature and a density. The moles
-    /// are set to the reference value for each component.
+    /// Return a new `State` for a pure component given a temperature and either a density or
+    /// a pressure. The moles are set to the reference value.
     ///
-    /// This function will perform a validation of the given properties, i.e. test for signs
-    /// and if values are finite. It will **not** validate physics, i.e. if the resulting
-    /// densities are below the maximum packing fraction.
-    pub fn new_pure(eos: &Arc<E>, temperature: Temperature, density: Density) -> EosResult<Self> {
+    /// If a pressure is given, the stable phase is determined by a density iteration. If a
+    /// density is given, this function will perform a validation of the given properties,
+    /// i.e. test for signs and if values are finite. It will **not** validate physics, i.e.
+    /// if the resulting densities are below the maximum packing fraction.
+    ///
+    /// # Errors
+    ///
+    /// When the equation of state has more than one component.
+    pub fn new_pure(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        density_or_pressure: impl Into<DPSpec>,
+    ) -> EosResult<Self> {
+        if eos.components() != 1 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 1));
+        }
         let moles = Moles::from_reduced(arr1(&[1.0]));
-        Self::new_nvt(eos, temperature, Moles::from_reduced(1.0) / density, &moles)
+        match density_or_pressure.into() {
+            DPSpec::Density(density) => {
+                Self::new_nvt(eos, temperature, Moles::from_reduced(1.0) / density, &moles)
+            }
+            DPSpec::Pressure(pressure) => Self::new_npt(
+                eos,
+                temperature,
+                pressure,
+                &moles,
+                DensityInitialization::None,
+            ),
+        }
     }
 
     /// Return a new `State` for the combination of inputs.
@@ -335,9 +430,28 @@ impl<E: Residual> State<E> {
         // check for density
         if density.and(partial_density).is_some() {
             return Err(EosError::UndeterminedState(String::from(
-                "Both density and partial density given.",
+                "Both density and partial density given. The partial densities already determine the density.",
             )));
         }
+        if let Some(pd) = partial_density {
+            // partial densities determine the composition and must not be negative
+            let rho_i = pd.to_reduced();
+            if let Some(&r) = rho_i
+                .iter()
+                .find(|&&r| !r.is_finite() || r.is_sign_negative())
+            {
+                return Err(EosError::InvalidState(
+                    String::from("State"),
+                    String::from("partial_density"),
+                    r,
+                ));
+            }
+            if rho_i.sum() == 0.0 {
+                return Err(EosError::UndeterminedState(String::from(
+                    "All partial densities are zero.",
+                )));
+            }
+        }
         let rho = density.or_else(|| partial_density.map(|pd| pd.sum()));
 
         // check for total moles
@@ -359,7 +473,12 @@ impl<E: Residual> State<E> {
         // check for composition
         if partial_density.and(moles).is_some() {
             return Err(EosError::UndeterminedState(String::from(
-                "Composition is overdetermined.",
+                "Composition is overdetermined. Both partial densities and moles given.",
+            )));
+        }
+        if partial_density.and(molefracs).is_some() {
+            return Err(EosError::UndeterminedState(String::from(
+                "Composition is overdetermined. Both partial densities and mole fractions given.",
             )));
         }
         let x = partial_density
@@ -373,7 +492,25 @@ impl<E: Residual> State<E> {
                 )))
             }
             (Some(x), None, _) => x,
-            (None, Some(x), _) => x.clone(),
+            (None, Some(x), _) => {
+                // mole fractions are normalized, but must be finite and not negative
+                if let Some(&xi) = x.iter().find(|&&xi| !xi.is_finite() || xi < 0.0) {
+                    return Err(EosError::InvalidState(
+                        String::from("State"),
+                        String::from("molefracs"),
+                        xi,
+                    ));
+                }
+                let sum = x.sum();
+                if sum == 0.0 {
+                    return Err(EosError::InvalidState(
+                        String::from("State"),
+                        String::from("sum of molefracs"),
+                        sum,
+                    ));
+                }
+                x / sum
+            }
             (None, None, 1) => arr1(&[1.0]),
             _ => {
                 return Err(EosError::UndeterminedState(String::from(
@@ -420,44 +557,31 @@ impl<E: Residual> State<E> {
         moles: &Moles<Array1<f64>>,
         density_initialization: DensityInitialization,
     ) -> EosResult<Self> {
-        // calculate state from initial density or given phase
-        match density_initialization {
-            DensityInitialization::InitialDensity(rho0) => {
-                return density_iteration(eos, temperature, pressure, moles, rho0)
-            }
-            DensityInitialization::Vapor => {
-                return density_iteration(
-                    eos,
-                    temperature,
-                    pressure,
-                    moles,
-                    pressure / temperature / RGAS,
-                )
-            }
-            DensityInitialization::Liquid => {
-                return density_iteration(
-                    eos,
+        let iterate = |initial_density| {
+            density_iteration(eos, temperature, pressure, moles, initial_density).map_err(|e| {
+                EosError::DensityIteration(
                     temperature,
                     pressure,
-                    moles,
-                    eos.max_density(Some(moles))?,
+                    density_initialization,
+                    Box::new(e),
                 )
-            }
+            })
+        };
+
+        // calculate state from initial density or given phase
+        match density_initialization {
+            DensityInitialization::InitialDensity(rho0) => return iterate(rho0),
+            DensityInitialization::Vapor => return iterate(pressure / temperature / RGAS),
+            DensityInitialization::Liquid => return iterate(eos.max_density(Some(moles))?),
             DensityInitialization::None => (),
         }
 
         // calculate stable phase
         let max_density = eos.max_density(Some(moles))?;
-        let liquid = density_iteration(eos, temperature, pressure, moles, max_density);
+        let liquid = iterate(max_density);
 
         if pressure < max_density * temperature * RGAS {
-            let vapor = density_iteration(
-                eos,
-                temperature,
-                pressure,
-                moles,
-                pressure / temperature / RGAS,
-            );
+            let vapor = iterate(pressure / temperature / RGAS);
             match (&liquid, &vapor) {
                 (Ok(_), Err(_)) => liquid,
                 (Err(_), Ok(_)) => vapor,
@@ -468,15 +592,156 @@ impl<E: Residual> State<E> {
                         liquid
                     }
                 }
-                _ => Err(EosError::UndeterminedState(String::from(
-                    "Density iteration did not find a solution.",
-                ))),
+                _ => Err(EosError::DensityIteration(
+                    temperature,
+                    pressure,
+                    density_initialization,
+                    Box::new(EosError::UndeterminedState(String::from(
+                        "neither a vapor nor a liquid solution was found",
+                    ))),
+                )),
             }
         } else {
             liquid
         }
     }
 
+    /// Return a list of states for given temperatures, pressures and compositions.
+    ///
+    /// Every state is calculated with a density iteration that starts at the
+    /// corresponding initial density. Every row of `molefracs` contains the
+    /// composition of one state. For pure components, `molefracs` can be omitted.
+    pub fn new_batch(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        pressure: &Pressure<Array1<f64>>,
+        molefracs: Option<&Array2<f64>>,
+        initial_density: &Density<Array1<f64>>,
+    ) -> EosResult<Vec<Self>> {
+        let n = temperature.len();
+        let lengths = [
+            ("pressures", pressure.len()),
+            ("initial densities", initial_density.len()),
+            ("compositions", molefracs.map_or(n, |x| x.nrows())),
+        ];
+        if let Some(&(name, found)) = lengths.iter().find(|&&(_, found)| found != n) {
+            return Err(EosError::IncompatibleLength {
+                name: name.to_owned(),
+                expected: n,
+                found,
+            });
+        }
+        (0..n)
+            .map(|i| {
+                let moles = molefracs.map(|x| x.row(i).to_owned() * Moles::from_reduced(1.0));
+                let moles = eos.validate_moles(moles.as_ref())?;
+                Self::new_npt(
+                    eos,
+                    temperature.get(i),
+                    pressure.get(i),
+                    &moles,
+                    DensityInitialization::InitialDensity(initial_density.get(i)),
+                )
+            })
+            .collect()
+    }
+
+    /// Return the states on a grid of temperatures and pressures for a given composition.
+    ///
+    /// The states are ordered with the temperature as outer and the pressure as
+    /// inner index, i.e., state `i * pressure.len() + j` corresponds to
+    /// `temperature.get(i)` and `pressure.get(j)`. For pure components, `molefracs`
+    /// can be omitted. If a state can not be calculated, an
+    /// [EosError::GridPointFailed] with the index of the state in the grid is returned.
+    pub fn new_grid(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        pressure: &Pressure<Array1<f64>>,
+        molefracs: Option<&Array1<f64>>,
+        density_initialization: DensityInitialization,
+    ) -> EosResult<Vec<Self>> {
+        let moles = molefracs.map(|x| x * Moles::from_reduced(1.0));
+        let moles = eos.validate_moles(moles.as_ref())?;
+        let np = pressure.len();
+        let mut states = Vec::with_capacity(temperature.len() * np);
+        for (i, t) in temperature.into_iter().enumerate() {
+            for (j, p) in pressure.into_iter().enumerate() {
+                let state =
+                    Self::new_npt(eos, t, p, &moles, density_initialization).map_err(|e| {
+                        EosError::GridPointFailed {
+                            index: i * np + j,
+                            source: Box::new(e),
+                        }
+                    })?;
+                states.push(state);
+            }
+        }
+        Ok(states)
+    }
+
+    /// Return the compressibility factor along an isobar.
+    ///
+    /// A state is calculated for every temperature at the given pressure
+    /// and composition using the same `density_initialization`. For pure
+    /// components, `molefracs` can be omitted. If a state can not be
+    /// calculated, an [EosError::GridPointFailed] with the index of the
+    /// temperature is returned.
+    pub fn compressibility_isobar(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        molefracs: Option<&Array1<f64>>,
+        temperature: &Temperature<Array1<f64>>,
+        density_initialization: DensityInitialization,
+    ) -> EosResult<Array1<f64>> {
+        let moles = molefracs.map(|x| x * Moles::from_reduced(1.0));
+        let moles = eos.validate_moles(moles.as_ref())?;
+        temperature
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| {
+                Self::new_npt(eos, t, pressure, &moles, density_initialization)
+                    .map(|s| s.compressibility(Contributions::Total))
+                    .map_err(|e| EosError::GridPointFailed {
+                        index: i,
+                        source: Box::new(e),
+                    })
+            })
+            .collect()
+    }
+
+    /// Return the fugacity coefficients on a grid of temperatures and pressures.
+    ///
+    /// The element `[i, j, k]` of the result is the fugacity coefficient of
+    /// component `k` at `temperature[i]` and `pressure[j]`. All states are
+    /// calculated at the same composition using the same `density_initialization`.
+    /// For pure components, `molefracs` can be omitted. If a state can not be
+    /// calculated, an [EosError::GridPointFailed] with its index in the grid
+    /// is returned (see [State::new_grid]).
+    pub fn fugacity_coefficient_table(
+        eos: &Arc<E>,
+        temperature: &Temperature<Array1<f64>>,
+        pressure: &Pressure<Array1<f64>>,
+        molefracs: Option<&Array1<f64>>,
+        density_initialization: DensityInitialization,
+    ) -> EosResult<Array3<f64>> {
+        let states = Self::new_grid(
+            eos,
+            temperature,
+            pressure,
+            molefracs,
+            density_initialization,
+        )?;
+        let phi: Vec<_> = states
+            .iter()
+            .map(|state| state.ln_phi().mapv(f64::exp))
+            .collect();
+        let np = pressure.len();
+        Ok(Array3::from_shape_fn(
+            (temperature.len(), np, eos.components()),
+            |(i, j, k)| phi[i * np + j][k],
+        ))
+    }
+
     /// Return a new `State` for given pressure $p$, volume $V$, temperature $T$ and composition $x_i$.
     pub fn new_npvx(
         eos: &Arc<E>,
@@ -502,6 +767,9 @@ impl<E: Residual + IdealGas> State<E> {
     /// 2. Use a density iteration for a given pressure.
     /// 3. Determine the state using a Newton iteration from (in this order): $(p, h)$, $(p, s)$, $(T, h)$, $(T, s)$, $(V, u)$
     ///
+    /// Partial densities $\rho_i$ determine both the density and the composition of the state,
+    /// i.e., they must not be combined with $\rho$, $N_i$ or $x_i$.
+    ///
     /// The [StateBuilder] provides a convenient way of calling this function without the need to provide
     /// all the optional input values.
     ///
@@ -675,6 +943,25 @@ impl<E: Residual + IdealGas> State<E> {
         };
         newton(t0, f, Temperature::from_reduced(1.0e-8))
     }
+
+    /// Return a new `State` at the given pressure $p$ with the same
+    /// molar entropy $s$ and composition, e.g., for an isentropic
+    /// compression or expansion.
+    pub fn isentropic_pressure_change(&self, pressure: Pressure) -> EosResult<Self> {
+        // ideal gas estimate of the temperature as initial value
+        let c = Contributions::Total;
+        let exponent = (RGAS / self.molar_isobaric_heat_capacity(c)).into_value();
+        let ratio = (pressure / self.pressure(c)).into_value();
+        let t0 = self.temperature * ratio.powf(exponent);
+        Self::new_nps(
+            &self.eos,
+            pressure,
+            self.molar_entropy(c),
+            &self.moles,
+            DensityInitialization::None,
+            Some(t0),
+        )
+    }
 }
 
 impl<E: Residual> State<E> {
@@ -683,6 +970,16 @@ impl<E: Residual> State<E> {
         Self::new_nvt(&self.eos, temperature, self.volume, &self.moles)
     }
 
+    /// Number density $\rho N_\mathrm{A}$
+    pub fn number_density(&self) -> Quot<f64, Volume> {
+        self.density * NAV
+    }
+
+    /// Partial number densities $\rho_i N_\mathrm{A}$
+    pub fn partial_number_density(&self) -> Quot<f64, Volume<Array1<f64>>> {
+        &self.partial_density * NAV
+    }
+
     /// Creates a [StateHD] cloning temperature, volume and moles.
     pub fn derive0(&self) -> StateHD<f64> {
         StateHD::new(
@@ -792,6 +1089,24 @@ where
     Err(EosError::NotConverged("newton".to_owned()))
 }
 
+/// Collect named contributions into a map.
+///
+/// Duplicate names are disambiguated by appending an index, e.g.
+/// `Dispersion`, `Dispersion_2`, `Dispersion_3`.
+fn contributions_map<T>(contributions: Vec<(String, T)>) -> HashMap<String, T> {
+    let mut map = HashMap::with_capacity(contributions.len());
+    for (name, value) in contributions {
+        let mut key = name.clone();
+        let mut index = 1;
+        while map.contains_key(&key) {
+            index += 1;
+            key = format!("{name}_{index}");
+        }
+        map.insert(key, value);
+    }
+    map
+}
+
 /// Validate the given temperature, mole numbers and volume.
 ///
 /// Properties are valid if
@@ -851,6 +1166,25 @@ impl From<Quantity<f64, SIUnit<N2, N1, P1, Z0, Z0, Z0, Z0>>> for TPSpec {
     }
 }
 
+/// Specification of a pure component state by either a density or a pressure.
+#[derive(Clone, Copy)]
+pub enum DPSpec {
+    Density(Density),
+    Pressure(Pressure),
+}
+
+impl From<Quantity<f64, SIUnit<Z0, N3, Z0, Z0, Z0, P1, Z0>>> for DPSpec {
+    fn from(density: Density) -> Self {
+        Self::Density(density)
+    }
+}
+
+impl From<Quantity<f64, SIUnit<N2, N1, P1, Z0, Z0, Z0, Z0>>> for DPSpec {
+    fn from(pressure: Pressure) -> Self {
+        Self::Pressure(pressure)
+    }
+}
+
 mod critical_point;
 
 #[cfg(test)]
@@ -866,6 +1200,22 @@ mod tests {
         assert!(validate(temperature, volume, &moles).is_ok());
     }
 
+    #[test]
+    fn test_contributions_map() {
+        let contributions = vec![
+            ("Hard-sphere".to_string(), 1.0),
+            ("Dispersion".to_string(), 2.0),
+            ("Dispersion".to_string(), 3.0),
+            ("Dispersion".to_string(), 4.0),
+        ];
+        let map = contributions_map(contributions);
+        assert_eq!(map.len(), 4);
+        assert_eq!(map["Hard-sphere"], 1.0);
+        assert_eq!(map["Dispersion"], 2.0);
+        assert_eq!(map["Dispersion_2"], 3.0);
+        assert_eq!(map["Dispersion_3"], 4.0);
+    }
+
     #[test]
     fn test_negative_temperature() {
         let temperature = -298.15 * KELVIN;
@@ -1,8 +1,9 @@
-use super::{Contributions, Derivative::*, PartialDerivative, State};
+use super::{contributions_map, Contributions, Derivative::*, PartialDerivative, State};
 use crate::equation_of_state::{IdealGas, Molarweight, Residual};
 use crate::ReferenceSystem;
 use ndarray::Array1;
 use quantity::*;
+use std::collections::HashMap;
 use std::ops::Div;
 use typenum::P2;
 
@@ -95,6 +96,14 @@ impl<E: Residual + IdealGas> State<E> {
         }
     }
 
+    /// Molar isobaric heat capacity of the ideal gas: $c_p^\mathrm{ig}=c_v^\mathrm{ig}+R$
+    ///
+    /// Equivalent to `molar_isobaric_heat_capacity(Contributions::IdealGas)`. The result
+    /// only depends on temperature and composition, not on the density of the state.
+    pub fn ideal_gas_heat_capacity(&self) -> MolarEntropy {
+        self.molar_isobaric_heat_capacity(Contributions::IdealGas)
+    }
+
     /// Entropy: $S=-\left(\frac{\partial A}{\partial T}\right)_{V,N_i}$
     pub fn entropy(&self, contributions: Contributions) -> Entropy {
         Entropy::from_reduced(
@@ -188,6 +197,12 @@ impl<E: Residual + IdealGas> State<E> {
             / (self.total_moles * self.molar_isobaric_heat_capacity(c))
     }
 
+    /// Returns `true`, if the Joule Thomson coefficient is positive, i.e.,
+    /// if the temperature decreases during an isenthalpic expansion.
+    pub fn is_cooling_on_expansion(&self) -> bool {
+        self.joule_thomson().to_reduced() > 0.0
+    }
+
     /// Isentropic compressibility: $\kappa_s=-\frac{1}{V}\left(\frac{\partial V}{\partial p}\right)_{S,N_i}$
     pub fn isentropic_compressibility(&self) -> <f64 as Div<Pressure>>::Output {
         let c = Contributions::Total;
@@ -240,6 +255,15 @@ impl<E: Residual + IdealGas> State<E> {
         }
         res
     }
+
+    /// Chemical potential $\mu_i$ evaluated for each contribution of the equation of state, keyed by the name of the contribution.
+    pub fn chemical_potential_contributions_map(
+        &self,
+        component: usize,
+        contributions: Contributions,
+    ) -> HashMap<String, MolarEnergy> {
+        contributions_map(self.chemical_potential_contributions(component, contributions))
+    }
 }
 
 impl<E: Residual + Molarweight + IdealGas> State<E> {
@@ -286,4 +310,24 @@ impl<E: Residual + Molarweight + IdealGas> State<E> {
         (1.0 / (self.density * self.total_molar_weight() * self.isentropic_compressibility()))
             .sqrt()
     }
+
+    /// Speed of sound of the ideal gas and of the total equation of state.
+    ///
+    /// The ideal gas contribution, labeled with the name of the ideal gas model, is
+    /// $c^\mathrm{ig}=\sqrt{\frac{c_p^\mathrm{ig}}{c_v^\mathrm{ig}}\frac{RT}{M}}$.
+    /// The residual contributions alone do not define a speed of sound and are
+    /// only included in the total value.
+    pub fn speed_of_sound_contributions(&self) -> Vec<(String, Velocity)> {
+        let ideal_gas = Contributions::IdealGas;
+        let gamma = (self.molar_isobaric_heat_capacity(ideal_gas)
+            / self.molar_isochoric_heat_capacity(ideal_gas))
+        .into_value();
+        let c_ig = (-gamma * self.dp_dv(ideal_gas) * self.volume.powi::<P2>()
+            / (self.total_moles * self.total_molar_weight()))
+        .sqrt();
+        vec![
+            (self.eos.ideal_gas_model(), c_ig),
+            ("Total".to_string(), self.speed_of_sound()),
+        ]
+    }
 }
@@ -1,10 +1,16 @@
-use super::{Contributions, Derivative::*, PartialDerivative, State};
+use super::{
+    contributions_map, Contributions, Derivative::*, PartialDerivative, PhaseIdentification, State,
+    StateHD,
+};
 use crate::equation_of_state::{EntropyScaling, Molarweight, Residual};
-use crate::errors::EosResult;
+use crate::errors::{EosError, EosResult};
 use crate::phase_equilibria::PhaseEquilibrium;
 use crate::ReferenceSystem;
-use ndarray::{arr1, Array1, Array2};
+use ndarray::{arr1, Array1, Array2, Zip};
+use num_dual::linalg::LU;
+use num_dual::HyperHyperDual64;
 use quantity::*;
+use std::collections::HashMap;
 use std::ops::{Add, Div};
 use std::sync::Arc;
 use typenum::P2;
@@ -83,6 +89,48 @@ impl<E: Residual> State<E> {
         res
     }
 
+    /// Association contribution to the residual Helmholtz energy $A^\text{assoc}$.
+    ///
+    /// The contribution is identified by its name `Association` in
+    /// [Residual::residual_helmholtz_energy_contributions]. Returns `None`, if the
+    /// equation of state has no (separate) association contribution.
+    pub fn association_helmholtz_energy(&self) -> Option<Energy> {
+        let new_state = self.derive0();
+        self.eos
+            .residual_helmholtz_energy_contributions(&new_state)
+            .into_iter()
+            .find_map(|(s, v)| {
+                (s == "Association").then(|| Energy::from_reduced(v * new_state.temperature))
+            })
+    }
+
+    /// Residual Helmholtz energy and its partial derivatives w.r.t. density and temperature:
+    /// $\left(A^\text{res},\left(\frac{\partial A^\text{res}}{\partial\rho}\right)_{T,N_i},\left(\frac{\partial^2A^\text{res}}{\partial\rho^2}\right)_{T,N_i},\left(\frac{\partial A^\text{res}}{\partial T}\right)_{V,N_i}\right)$
+    ///
+    /// Can be used to implement custom properties. For example, the residual pressure
+    /// is $p^\text{res}=\frac{\rho^2}{N}\left(\frac{\partial A^\text{res}}{\partial\rho}\right)_{T,N_i}$.
+    #[expect(clippy::type_complexity)]
+    pub fn residual_helmholtz_energy_derivatives(
+        &self,
+    ) -> (
+        Energy,
+        <Energy as Div<Density>>::Output,
+        <<Energy as Div<Density>>::Output as Div<Density>>::Output,
+        <Energy as Div<Temperature>>::Output,
+    ) {
+        let da_dv = -self.pressure(Contributions::Residual);
+        let d2a_dv2 = -self.dp_dv(Contributions::Residual);
+        let da_drho = -self.volume / self.density * da_dv;
+        let d2a_drho2 =
+            self.volume / (self.density * self.density) * (self.volume * d2a_dv2 + 2.0 * da_dv);
+        (
+            self.residual_helmholtz_energy(),
+            da_drho,
+            d2a_drho2,
+            -self.residual_entropy(),
+        )
+    }
+
     /// Residual entropy $S^\text{res}=\left(\frac{\partial A^\text{res}}{\partial T}\right)_{V,N_i}$
     pub fn residual_entropy(&self) -> Entropy {
         Entropy::from_reduced(
@@ -95,7 +143,44 @@ impl<E: Residual> State<E> {
         self.residual_entropy() / self.total_moles
     }
 
+    /// Partial residual molar entropy: $s_i^\text{res}=\left(\frac{\partial S^\text{res}}{\partial N_i}\right)_{T,p,N_j}$
+    ///
+    /// The residual entropy is defined with respect to the ideal gas at the same
+    /// temperature, volume and composition (see [State::residual_molar_entropy]),
+    /// so that $\sum_ix_is_i^\text{res}=s^\text{res}$.
+    pub fn partial_residual_molar_entropy(&self) -> MolarEntropy<Array1<f64>> {
+        -(self.dmu_res_dt() - self.partial_molar_volume() * self.dp_dt(Contributions::Residual))
+    }
+
     /// Pressure: $p=-\left(\frac{\partial A}{\partial V}\right)_{T,N_i}$
+    ///
+    /// The ideal gas contribution is $p^\text{ig}=\rho RT$ independent of the
+    /// equation of state, i.e., it does not require an ideal gas model.
+    ///
+    /// # Example
+    /// ```
+    /// # use feos_core::{Contributions, EosResult, State};
+    /// # use feos_core::cubic::{PengRobinson, PengRobinsonParameters};
+    /// # use quantity::*;
+    /// # use std::sync::Arc;
+    /// # use approx::assert_relative_eq;
+    /// # use typenum::P3;
+    /// # fn main() -> EosResult<()> {
+    /// let parameters = PengRobinsonParameters::new_simple(&[369.8], &[41.9 * 1e5], &[0.15], &[15.0])?;
+    /// let eos = Arc::new(PengRobinson::new(Arc::new(parameters)));
+    /// let density = 500.0 * MOL / METER.powi::<P3>();
+    /// let state = State::new_pure(&eos, 300.0 * KELVIN, density)?;
+    /// assert_eq!(
+    ///     state.pressure(Contributions::IdealGas),
+    ///     density * RGAS * 300.0 * KELVIN
+    /// );
+    /// assert_relative_eq!(
+    ///     state.pressure(Contributions::Total),
+    ///     state.pressure(Contributions::IdealGas) + state.pressure(Contributions::Residual)
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn pressure(&self, contributions: Contributions) -> Pressure {
         let ideal_gas = self.density * RGAS * self.temperature;
         let residual = Pressure::from_reduced(
@@ -105,6 +190,11 @@ impl<E: Residual> State<E> {
     }
 
     /// Residual chemical potential: $\mu_i^\text{res}=\left(\frac{\partial A^\text{res}}{\partial N_i}\right)_{T,V,N_j}$
+    ///
+    /// The reference is the ideal gas at the same temperature, volume
+    /// and composition, i.e., $\mu_i^\text{res}=\mu_i-\mu_i^\text{ig}(T,\rho_i)$.
+    /// This is the quantity required for fugacity coefficients and entropy scaling.
+    /// It differs from the residual chemical potential at constant pressure by $RT\ln Z$.
     pub fn residual_chemical_potential(&self) -> MolarEnergy<Array1<f64>> {
         MolarEnergy::from_reduced(Array1::from_shape_fn(self.eos.components(), |i| {
             self.get_or_compute_derivative_residual(PartialDerivative::First(DN(i)))
@@ -158,6 +248,16 @@ impl<E: Residual> State<E> {
         Self::contributions(ideal_gas, residual, contributions)
     }
 
+    /// Partial derivative of density w.r.t. pressure: $\left(\frac{\partial \rho}{\partial p}\right)_{T,N_i}=\frac{1}{\left(\frac{\partial p}{\partial \rho}\right)_{T,N_i}}$
+    pub fn drho_dp(&self, contributions: Contributions) -> <Density as Div<Pressure>>::Output {
+        1.0 / self.dp_drho(contributions)
+    }
+
+    /// Partial derivative of density w.r.t. temperature: $\left(\frac{\partial \rho}{\partial T}\right)_{p,N_i}=-\frac{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}{\left(\frac{\partial p}{\partial \rho}\right)_{T,N_i}}$
+    pub fn drho_dt(&self, contributions: Contributions) -> <Density as Div<Temperature>>::Output {
+        -self.dp_dt(contributions) / self.dp_drho(contributions)
+    }
+
     /// Partial derivative of pressure w.r.t. moles: $\left(\frac{\partial p}{\partial N_i}\right)_{T,V,N_j}$
     pub fn dp_dni(
         &self,
@@ -194,12 +294,101 @@ impl<E: Residual> State<E> {
             * (self.volume * self.d2p_dv2(contributions) + 2.0 * self.dp_dv(contributions))
     }
 
+    /// Second partial derivative of pressure w.r.t. temperature and volume: $\left(\frac{\partial^2 p}{\partial T\partial V}\right)_{N_j}$
+    pub fn d2p_dtdv(
+        &self,
+        contributions: Contributions,
+    ) -> <<Pressure as Div<Temperature>>::Output as Div<Volume>>::Output {
+        let ideal_gas = -self.density * RGAS / self.volume;
+        let t = HyperHyperDual64::from(self.reduced_temperature).derivative3();
+        let v = HyperHyperDual64::from(self.reduced_volume)
+            .derivative1()
+            .derivative2();
+        let n = self.reduced_moles.mapv(HyperHyperDual64::from);
+        let new_state = StateHD::new(t, v, n);
+        let a = self.eos.residual_helmholtz_energy(&new_state) * new_state.temperature;
+        let residual = Quantity::from_reduced(-a.eps1eps2eps3);
+        Self::contributions(ideal_gas, residual, contributions)
+    }
+
+    /// Phase identification parameter: $\Pi=V\left(\frac{\left(\frac{\partial^2 p}{\partial T\partial V}\right)_{N_i}}{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}-\frac{\left(\frac{\partial^2 p}{\partial V^2}\right)_{T,N_i}}{\left(\frac{\partial p}{\partial V}\right)_{T,N_i}}\right)$
+    ///
+    /// The parameter by Venkatarathnam and Oellrich (2011) is larger than 1 for
+    /// liquid-like states and smaller than 1 for vapor-like states.
+    pub fn phase_identification_parameter(&self) -> f64 {
+        let c = Contributions::Total;
+        (self.volume * (self.d2p_dtdv(c) / self.dp_dt(c) - self.d2p_dv2(c) / self.dp_dv(c)))
+            .into_value()
+    }
+
+    /// Classify the state as liquid, vapor or supercritical.
+    ///
+    /// The critical point of the same composition is calculated once, which is
+    /// much more expensive than evaluating properties of the state. Use
+    /// [State::phase_identification_with_critical_point] to reuse a critical point.
+    /// Errors of the critical point calculation are returned.
+    pub fn phase_identification(&self) -> EosResult<PhaseIdentification> {
+        let cp = State::critical_point(&self.eos, Some(&self.moles), None, Default::default())?;
+        Ok(self.phase_identification_with_critical_point(&cp))
+    }
+
+    /// Classify the state as liquid, vapor or supercritical using a
+    /// precomputed critical point of the same composition.
+    ///
+    /// States with temperature and pressure above the critical point are
+    /// supercritical. Otherwise, the [phase identification parameter](Self::phase_identification_parameter)
+    /// distinguishes liquid and vapor states.
+    pub fn phase_identification_with_critical_point(
+        &self,
+        critical_point: &State<E>,
+    ) -> PhaseIdentification {
+        if self.temperature > critical_point.temperature
+            && self.pressure(Contributions::Total) > critical_point.pressure(Contributions::Total)
+        {
+            PhaseIdentification::Supercritical
+        } else if self.phase_identification_parameter() > 1.0 {
+            PhaseIdentification::Liquid
+        } else {
+            PhaseIdentification::Vapor
+        }
+    }
+
+    /// Returns `true` if the state is a subcritical liquid according to
+    /// [State::phase_identification].
+    pub fn is_liquid(&self) -> EosResult<bool> {
+        Ok(self.phase_identification()? == PhaseIdentification::Liquid)
+    }
+
+    /// Returns `true` if the state is a subcritical vapor according to
+    /// [State::phase_identification].
+    pub fn is_vapor(&self) -> EosResult<bool> {
+        Ok(self.phase_identification()? == PhaseIdentification::Vapor)
+    }
+
+    /// Returns `true` if the temperature and pressure of the state are above
+    /// the critical point of the same composition.
+    pub fn is_supercritical(&self) -> EosResult<bool> {
+        Ok(self.phase_identification()? == PhaseIdentification::Supercritical)
+    }
+
     /// Structure factor: $S(0)=k_BT\left(\frac{\partial\rho}{\partial p}\right)_{T,N_i}$
     pub fn structure_factor(&self) -> f64 {
         -(RGAS * self.temperature * self.density / (self.volume * self.dp_dv(Contributions::Total)))
             .into_value()
     }
 
+    /// Packing fraction of the hard-sphere reference fluid: $\eta=\frac{\pi}{6}\sum_i\rho_im_id_i^3$
+    ///
+    /// Values far above the freezing packing fraction of hard spheres ($\eta\approx0.49$)
+    /// indicate an unphysical state. Returns `None` if the equation of state is not
+    /// based on a hard-sphere reference.
+    pub fn packing_fraction(&self) -> Option<f64> {
+        self.eos.packing_fraction(
+            self.temperature.to_reduced(),
+            &self.partial_density.to_reduced(),
+        )
+    }
+
     // This function is designed specifically for use in density iterations
     pub(crate) fn p_dpdrho(&self) -> (Pressure, <Pressure as Div<Density>>::Output) {
         let dp_dv = self.dp_dv(Contributions::Total);
@@ -257,6 +446,35 @@ impl<E: Residual> State<E> {
         -1.0 / (self.dp_dv(Contributions::Total) * self.volume)
     }
 
+    /// Isothermal compressibility calculated from the density derivative of the pressure: $\kappa_T=\frac{1}{\rho}\left(\frac{\partial \rho}{\partial p}\right)_{T,N_i}$
+    pub fn isothermal_compressibility_from_density(
+        &self,
+        contributions: Contributions,
+    ) -> <f64 as Div<Pressure>>::Output {
+        1.0 / (self.density * self.dp_drho(contributions))
+    }
+
+    /// Thermal expansion of the volume: $\left(\frac{\partial V}{\partial T}\right)_{p,N_i}=-\frac{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}{\left(\frac{\partial p}{\partial V}\right)_{T,N_i}}$
+    pub fn thermal_expansion_volume(&self) -> <Volume as Div<Temperature>>::Output {
+        let c = Contributions::Total;
+        -self.dp_dt(c) / self.dp_dv(c)
+    }
+
+    /// Molar volume: $v=\frac{V}{N}$
+    pub fn molar_volume(&self) -> MolarVolume {
+        self.volume / self.total_moles
+    }
+
+    /// Partial derivative of the molar volume w.r.t. temperature: $\left(\frac{\partial v}{\partial T}\right)_{p,N_i}=-\frac{1}{N}\frac{\left(\frac{\partial p}{\partial T}\right)_{V,N_i}}{\left(\frac{\partial p}{\partial V}\right)_{T,N_i}}$
+    pub fn dv_dt(&self) -> <MolarVolume as Div<Temperature>>::Output {
+        self.thermal_expansion_volume() / self.total_moles
+    }
+
+    /// Partial derivative of the molar volume w.r.t. pressure: $\left(\frac{\partial v}{\partial p}\right)_{T,N_i}=\frac{1}{N}\frac{1}{\left(\frac{\partial p}{\partial V}\right)_{T,N_i}}$
+    pub fn dv_dp(&self) -> <MolarVolume as Div<Pressure>>::Output {
+        1.0 / (self.dp_dv(Contributions::Total) * self.total_moles)
+    }
+
     /// Pressure $p$ evaluated for each contribution of the equation of state.
     pub fn pressure_contributions(&self) -> Vec<(String, Pressure)> {
         let new_state = self.derive1(DV);
@@ -269,6 +487,11 @@ impl<E: Residual> State<E> {
         res
     }
 
+    /// Pressure $p$ evaluated for each contribution of the equation of state, keyed by the name of the contribution.
+    pub fn pressure_contributions_map(&self) -> HashMap<String, Pressure> {
+        contributions_map(self.pressure_contributions())
+    }
+
     // entropy derivatives
 
     /// Partial derivative of the residual entropy w.r.t. temperature: $\left(\frac{\partial S^\text{res}}{\partial T}\right)_{V,N_i}$
@@ -334,6 +557,82 @@ impl<E: Residual> State<E> {
         temperature: Temperature,
         molefracs: &Array1<f64>,
     ) -> EosResult<Pressure<Array1<f64>>> {
+        let (liquid, vapor) = Self::henrys_law_states(eos, temperature, molefracs)?;
+
+        // Determine the Henry's law coefficients and return only those of the Henry components
+        let p = vapor.pressure(Contributions::Total);
+        let h = (liquid.ln_phi() - vapor.ln_phi()).mapv(f64::exp) * p;
+        Ok(h.into_iter()
+            .zip(molefracs)
+            .filter_map(|(h, &x)| (x == 0.0).then_some(h))
+            .collect())
+    }
+
+    /// Temperature derivative of Henry's law constant $\frac{\mathrm{d}H_{i,s}}{\mathrm{d}T}$ along the bubble point line of the solvent.
+    ///
+    /// The composition of the (possibly mixed) solvent is determined by the molefracs. All components for which the composition is 0 are treated as solutes.
+    /// The change of the saturation pressure and of the vapor composition with temperature is obtained from the sensitivity of the phase equilibrium conditions,
+    /// and the derivatives of the fugacity coefficients are calculated with dual numbers.
+    pub fn henrys_law_constant_temperature_derivative(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<<Pressure<Array1<f64>> as Div<Temperature>>::Output> {
+        let (liquid, vapor) = Self::henrys_law_states(eos, temperature, molefracs)?;
+        let solvent: Vec<_> = (0..molefracs.len())
+            .filter(|&i| molefracs[i] != 0.0)
+            .collect();
+
+        // partial derivatives of the fugacity coefficients (in reduced units)
+        let p = vapor.pressure(Contributions::Total).to_reduced();
+        let dln_phi_dt = (liquid.dln_phi_dt() - vapor.dln_phi_dt()).to_reduced();
+        let dln_phi_dp = (liquid.dln_phi_dp() - vapor.dln_phi_dp()).to_reduced();
+        let dln_phi_dy = vapor.dln_phi_dnj().to_reduced() * vapor.total_moles.to_reduced();
+        let y = &vapor.molefracs;
+
+        // change of the pressure along the bubble point line (the change of the
+        // vapor composition drops out due to the Gibbs-Duhem equation)
+        let (num, den) = solvent.iter().fold((0.0, 0.0), |(num, den), &j| {
+            (num + y[j] * dln_phi_dt[j], den + y[j] * dln_phi_dp[j])
+        });
+        let dp_dt = -num / den;
+
+        // change of the vapor composition along the bubble point line
+        let n = solvent.len();
+        let jacobian = Array2::from_shape_fn((n, n), |(a, b)| {
+            let (j, k) = (solvent[a], solvent[b]);
+            let delta = if a == b { 1.0 } else { 0.0 };
+            delta + y[j] * dln_phi_dy[[j, k]]
+        });
+        let rhs = Array1::from_shape_fn(n, |a| {
+            let j = solvent[a];
+            y[j] * (dln_phi_dt[j] + dln_phi_dp[j] * dp_dt)
+        });
+        let dy_dt = LU::new(jacobian)?.solve(&rhs);
+
+        // derivative of the logarithm of the Henry's law constants
+        let h = Self::henrys_law_constant(eos, temperature, molefracs)?.to_reduced();
+        let dh_dt = (0..molefracs.len())
+            .filter(|&i| molefracs[i] == 0.0)
+            .zip(h)
+            .map(|(i, h)| {
+                let dy = solvent
+                    .iter()
+                    .zip(&dy_dt)
+                    .map(|(&k, &dy)| dln_phi_dy[[i, k]] * dy)
+                    .sum::<f64>();
+                h * (dp_dt / p + dln_phi_dt[i] + dln_phi_dp[i] * dp_dt - dy)
+            })
+            .collect();
+        Ok(Quantity::from_reduced(dh_dt))
+    }
+
+    /// Liquid and vapor state at the bubble point of the solvent, including the solutes at infinite dilution.
+    fn henrys_law_states(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<(Self, Self)> {
         // Calculate the phase equilibrium (bubble point) of the solvent only
         let (solvent_comps, solvent_molefracs): (Vec<_>, Vec<_>) = molefracs
             .iter()
@@ -375,14 +674,7 @@ impl<E: Residual> State<E> {
             vle.vapor().volume,
             &(molefracs_vapor * vle.vapor().total_moles),
         )?;
-
-        // Determine the Henry's law coefficients and return only those of the Henry components
-        let p = vle.vapor().pressure(Contributions::Total);
-        let h = (liquid.ln_phi() - vapor.ln_phi()).mapv(f64::exp) * p;
-        Ok(h.into_iter()
-            .zip(molefracs)
-            .filter_map(|(h, &x)| (x == 0.0).then_some(h))
-            .collect())
+        Ok((liquid, vapor))
     }
 
     /// Henry's law constant $H_{i,s}=\lim_{x_i\to 0}\frac{y_ip}{x_i}=p_s^\mathrm{sat}\frac{\varphi_i^{\infty,\mathrm{L}}}{\varphi_i^{\infty,\mathrm{V}}}$ for a binary system
@@ -395,6 +687,67 @@ impl<E: Residual> State<E> {
         Ok(Self::henrys_law_constant(eos, temperature, &arr1(&[0.0, 1.0]))?.get(0))
     }
 
+    /// Molar Gibbs energy of mixing $\Delta g^\mathrm{mix}=RT\sum_ix_i\ln\frac{x_i\varphi_i}{\varphi_{0i}}$ of a binary mixture at fixed temperature and pressure.
+    ///
+    /// `molefracs` contains the mole fractions of the first component, which have to be in $[0,1]$. The pure components and the mixtures are evaluated in their stable phase.
+    pub fn molar_gibbs_energy_of_mixing(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        molefracs: &Array1<f64>,
+    ) -> EosResult<MolarEnergy<Array1<f64>>> {
+        if eos.components() != 2 {
+            return Err(EosError::IncompatibleComponents(eos.components(), 2));
+        }
+        if let Some(&x1) = molefracs.iter().find(|&&x1| !(0.0..=1.0).contains(&x1)) {
+            return Err(EosError::InvalidState(
+                String::from("molar_gibbs_energy_of_mixing"),
+                String::from("molefracs"),
+                x1,
+            ));
+        }
+
+        // fugacity coefficients of the pure components
+        let ln_phi_pure = (0..2)
+            .map(|i| {
+                let eos = Arc::new(eos.subset(&[i]));
+                let state = Self::new_npt(
+                    &eos,
+                    temperature,
+                    pressure,
+                    &Moles::from_reduced(arr1(&[1.0])),
+                    crate::DensityInitialization::None,
+                )?;
+                Ok(state.ln_phi()[0])
+            })
+            .collect::<EosResult<Array1<f64>>>()?;
+
+        let g_mix = molefracs
+            .iter()
+            .map(|&x1| {
+                let x = arr1(&[x1, 1.0 - x1]);
+                let state = Self::new_npt(
+                    eos,
+                    temperature,
+                    pressure,
+                    &Moles::from_reduced(x.clone()),
+                    crate::DensityInitialization::None,
+                )?;
+                Ok(Zip::from(&x).and(&state.ln_phi()).and(&ln_phi_pure).fold(
+                    0.0,
+                    |acc, &x, &ln_phi, &ln_phi_pure| {
+                        if x > 0.0 {
+                            acc + x * (x.ln() + ln_phi - ln_phi_pure)
+                        } else {
+                            acc
+                        }
+                    },
+                ))
+            })
+            .collect::<EosResult<Array1<f64>>>()?;
+        Ok(g_mix * RGAS * temperature)
+    }
+
     /// Partial derivative of the logarithm of the fugacity coefficient w.r.t. temperature: $\left(\frac{\partial\ln\varphi_i}{\partial T}\right)_{p,N_i}$
     pub fn dln_phi_dt(&self) -> <f64 as Div<Temperature<Array1<f64>>>>::Output {
         let vi = self.partial_molar_volume();
@@ -431,6 +784,114 @@ impl<E: Residual> State<E> {
         })
     }
 
+    /// Thermodynamic factor of a binary mixture: $\Gamma=1+x_1\left(\frac{\partial\ln\gamma_1}{\partial x_1}\right)_{T,p}$
+    ///
+    /// Returns an error if the state does not contain exactly two components.
+    pub fn thermodynamic_factor_binary(&self) -> EosResult<f64> {
+        let n = self.eos.components();
+        if n != 2 {
+            return Err(EosError::IncompatibleComponents(n, 2));
+        }
+        Ok(self.thermodynamic_factor()[[0, 0]])
+    }
+
+    /// Residual of the Gibbs-Duhem equation: $\max_j\left|\sum_iN_i\left(\frac{\partial\ln\varphi_i}{\partial N_j}\right)_{T,p,N_k}\right|$
+    ///
+    /// At constant temperature and pressure, $\sum_ix_i\mathrm{d}\ln\gamma_i=\sum_ix_i\mathrm{d}\ln\varphi_i=0$
+    /// holds for every thermodynamically consistent model. The value should therefore be
+    /// close to machine precision.
+    pub fn gibbs_duhem_residual(&self) -> f64 {
+        let dln_phi_dnj = (self.dln_phi_dnj() * Moles::from_reduced(1.0)).into_value();
+        let moles = self.moles.to_reduced();
+        moles
+            .dot(&dln_phi_dnj)
+            .iter()
+            .fold(0.0, |acc, r| acc.max(r.abs()))
+    }
+
+    /// Maximum relative violation of the Maxwell relations of the residual Helmholtz energy.
+    ///
+    /// The relations
+    /// $\left(\frac{\partial S}{\partial V}\right)_{T,N_i}=\left(\frac{\partial p}{\partial T}\right)_{V,N_i}$,
+    /// $\left(\frac{\partial S}{\partial N_i}\right)_{T,V,N_j}=-\left(\frac{\partial\mu_i}{\partial T}\right)_{V,N_j}$,
+    /// $\left(\frac{\partial\mu_i}{\partial V}\right)_{T,N_j}=-\left(\frac{\partial p}{\partial N_i}\right)_{T,V,N_j}$ and
+    /// $\left(\frac{\partial\mu_i}{\partial N_j}\right)_{T,V,N_k}=\left(\frac{\partial\mu_j}{\partial N_i}\right)_{T,V,N_k}$
+    /// are evaluated by comparing the analytic second derivatives of the state to central
+    /// finite differences of the analytic first derivatives. For mole numbers smaller than the
+    /// step size, one-sided differences are used so that all evaluated mole numbers stay positive.
+    /// For a correctly implemented model, the value is limited by the accuracy of the finite
+    /// differences (approx. $10^{-8}$).
+    pub fn self_consistency_check(&self) -> f64 {
+        let t = self.temperature;
+        let v = self.volume;
+        let n = self.moles.to_reduced();
+        let h_v = 1e-5 * v;
+        let h_n = 1e-5 * n.sum();
+
+        let mut violation = 0.0;
+        let mut check = |analytic: f64, finite_difference: f64| {
+            let scale = analytic
+                .abs()
+                .max(finite_difference.abs())
+                .max(f64::EPSILON);
+            let deviation = (analytic - finite_difference).abs() / scale;
+            if deviation.is_nan() || deviation > violation {
+                violation = deviation;
+            }
+        };
+
+        // volume derivatives
+        let plus = Self::new_nvt_unchecked(&self.eos, t, v + h_v, &self.moles);
+        let minus = Self::new_nvt_unchecked(&self.eos, t, v - h_v, &self.moles);
+        let ds_dv = (plus.residual_entropy() - minus.residual_entropy()) / (2.0 * h_v);
+        check(
+            self.dp_dt(Contributions::Residual).to_reduced(),
+            ds_dv.to_reduced(),
+        );
+        let dmu_dv = ((plus.residual_chemical_potential() - minus.residual_chemical_potential())
+            / (2.0 * h_v))
+            .to_reduced();
+        let dp_dni = self.dp_dni(Contributions::Residual).to_reduced();
+        for (&dmu_dv, &dp_dni) in dmu_dv.iter().zip(dp_dni.iter()) {
+            check(-dp_dni, dmu_dv);
+        }
+
+        // mole number derivatives (one-sided for mole numbers close to zero)
+        let dmu_dt = self.dmu_res_dt().to_reduced();
+        let dmu_dni = self.dmu_dni(Contributions::Residual).to_reduced();
+        for i in 0..n.len() {
+            let shifted = |h: f64| {
+                let mut n_shifted = n.clone();
+                n_shifted[i] += h;
+                let state =
+                    Self::new_nvt_unchecked(&self.eos, t, v, &Moles::from_reduced(n_shifted));
+                (
+                    state.residual_entropy().to_reduced(),
+                    state.residual_chemical_potential().to_reduced(),
+                )
+            };
+            let (ds_dni, dmu_dni_fd) = if n[i] > h_n {
+                let ((s_plus, mu_plus), (s_minus, mu_minus)) = (shifted(h_n), shifted(-h_n));
+                (
+                    (s_plus - s_minus) / (2.0 * h_n),
+                    (mu_plus - mu_minus) / (2.0 * h_n),
+                )
+            } else {
+                let ((s_0, mu_0), (s_1, mu_1), (s_2, mu_2)) =
+                    (shifted(0.0), shifted(h_n), shifted(2.0 * h_n));
+                (
+                    (4.0 * s_1 - 3.0 * s_0 - s_2) / (2.0 * h_n),
+                    (4.0 * mu_1 - 3.0 * mu_0 - mu_2) / (2.0 * h_n),
+                )
+            };
+            check(-dmu_dt[i], ds_dni);
+            for j in 0..n.len() {
+                check(dmu_dni[[i, j]], dmu_dni_fd[j]);
+            }
+        }
+        violation
+    }
+
     /// Residual molar isochoric heat capacity: $c_v^\text{res}=\left(\frac{\partial u^\text{res}}{\partial T}\right)_{V,N_i}$
     pub fn residual_molar_isochoric_heat_capacity(&self) -> MolarEntropy {
         self.temperature * self.ds_res_dt() / self.total_moles
@@ -1,15 +1,42 @@
 use super::{Contributions, State};
 use crate::equation_of_state::{IdealGas, Molarweight, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::parameter::{component_labels, Identifier};
 use ndarray::{Array1, Array2};
 use quantity::{
     Density, MassDensity, MolarEnergy, MolarEntropy, Moles, Pressure, SpecificEnergy,
-    SpecificEntropy, Temperature,
+    SpecificEntropy, Temperature, JOULE, KELVIN, KILO, KILOGRAM, METER, MOL, PASCAL,
 };
+use std::collections::HashMap;
 use std::iter::FromIterator;
 use std::ops::Deref;
+use typenum::P3;
 
 /// A list of states for a simple access to properties
 /// of multiple states.
+///
+/// # Examples
+/// The properties of all states can be evaluated at once or by
+/// iterating over the states (in Python: `[s.temperature for s in states]`).
+/// ```
+/// # use feos_core::{DensityInitialization, EosResult, State, StateVec};
+/// # use feos_core::cubic::{PengRobinson, PengRobinsonParameters};
+/// # use quantity::*;
+/// # use std::sync::Arc;
+/// # use ndarray::arr1;
+/// # fn main() -> EosResult<()> {
+/// let eos = Arc::new(PengRobinson::new(Arc::new(PengRobinsonParameters::new_simple(&[369.8], &[41.9 * 1e5], &[0.15], &[15.0])?)));
+/// let moles = arr1(&[1.0]) * MOL;
+/// let states = [250.0, 300.0, 350.0]
+///     .map(|t| State::new_npt(&eos, t * KELVIN, BAR, &moles, DensityInitialization::None));
+/// let states = states.into_iter().collect::<EosResult<Vec<_>>>()?;
+/// let states: StateVec<_> = states.iter().collect();
+/// for (state, t) in states.iter().zip(states.temperature().into_iter()) {
+///     assert_eq!(state.temperature, t);
+/// }
+/// # Ok(())
+/// # }
+/// ```
 pub struct StateVec<'a, E>(pub Vec<&'a State<E>>);
 
 impl<'a, E> FromIterator<&'a State<E>> for StateVec<'a, E> {
@@ -65,6 +92,16 @@ impl<'a, E: Residual> StateVec<'a, E> {
             self.0[i].molefracs[j]
         })
     }
+
+    /// Logarithm of the fugacity coefficients of all states (rows) and components (columns).
+    pub fn ln_phi(&self) -> Array2<f64> {
+        let components = self.0.first().map_or(0, |s| s.eos.components());
+        let mut ln_phi = Array2::zeros((self.0.len(), components));
+        for (mut row, state) in ln_phi.outer_iter_mut().zip(self.0.iter()) {
+            row.assign(&state.ln_phi());
+        }
+        ln_phi
+    }
 }
 
 impl<'a, E: Residual + Molarweight> StateVec<'a, E> {
@@ -97,4 +134,97 @@ impl<'a, E: Residual + Molarweight + IdealGas> StateVec<'a, E> {
     pub fn specific_entropy(&self, contributions: Contributions) -> SpecificEntropy<Array1<f64>> {
         SpecificEntropy::from_shape_fn(self.0.len(), |i| self.0[i].specific_entropy(contributions))
     }
+
+    pub fn specific_gibbs_energy(
+        &self,
+        contributions: Contributions,
+    ) -> SpecificEnergy<Array1<f64>> {
+        SpecificEnergy::from_shape_fn(self.0.len(), |i| {
+            self.0[i].specific_gibbs_energy(contributions)
+        })
+    }
+
+    /// Return the properties of all states as a map from property names
+    /// to values.
+    ///
+    /// The units are K for the temperature, Pa for the pressure, mol/m³ for
+    /// the density, kg/m³ for the mass density and kJ/mol or kJ/kg (divided
+    /// by K for entropies) for the molar and specific properties. For mixtures,
+    /// the mole fractions are keyed by the component labels obtained from the
+    /// `identifiers` (see [component_labels]).
+    ///
+    /// With `mass_basis`, the density, molar enthalpy and molar entropy are
+    /// replaced by the specific volume (m³/kg) and specific Gibbs energy, which
+    /// requires molar weights. Without molar weights, the mass-based properties
+    /// are omitted.
+    pub fn to_dict(
+        &self,
+        contributions: Contributions,
+        identifiers: Option<&[Identifier]>,
+        mass_basis: bool,
+    ) -> EosResult<HashMap<String, Vec<f64>>> {
+        let Some(state) = self.0.first() else {
+            return Ok(HashMap::new());
+        };
+        let n = state.eos.components();
+        let has_molar_weight = state.eos.has_molar_weight();
+        if mass_basis && !has_molar_weight {
+            return Err(EosError::Error(
+                "Properties on a mass basis require molar weights.".into(),
+            ));
+        }
+        let mut dict = HashMap::with_capacity(8 + n);
+        if n != 1 {
+            let xs = self.molefracs();
+            for (i, label) in component_labels(identifiers, n).into_iter().enumerate() {
+                dict.insert(label, xs.column(i).to_vec());
+            }
+        }
+        let mut insert = |key: &str, values: Array1<f64>| {
+            dict.insert(String::from(key), values.into_raw_vec_and_offset().0)
+        };
+        insert("temperature", self.temperature().convert_to(KELVIN));
+        insert("pressure", self.pressure().convert_to(PASCAL));
+        if !mass_basis {
+            insert(
+                "density",
+                self.density().convert_to(MOL / METER.powi::<P3>()),
+            );
+            insert(
+                "molar enthalpy",
+                self.molar_enthalpy(contributions)
+                    .convert_to(KILO * JOULE / MOL),
+            );
+            insert(
+                "molar entropy",
+                self.molar_entropy(contributions)
+                    .convert_to(KILO * JOULE / KELVIN / MOL),
+            );
+        }
+        if has_molar_weight {
+            let mass_density = self
+                .mass_density()
+                .convert_to(KILOGRAM / METER.powi::<P3>());
+            if mass_basis {
+                insert("specific volume", mass_density.mapv(f64::recip));
+                insert(
+                    "specific gibbs energy",
+                    self.specific_gibbs_energy(contributions)
+                        .convert_to(KILO * JOULE / KILOGRAM),
+                );
+            }
+            insert("mass density", mass_density);
+            insert(
+                "specific enthalpy",
+                self.specific_enthalpy(contributions)
+                    .convert_to(KILO * JOULE / KILOGRAM),
+            );
+            insert(
+                "specific entropy",
+                self.specific_entropy(contributions)
+                    .convert_to(KILO * JOULE / KELVIN / KILOGRAM),
+            );
+        }
+        Ok(dict)
+    }
 }
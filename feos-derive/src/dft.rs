@@ -108,7 +108,7 @@ fn impl_helmholtz_energy_functional(
                 Self::#name(functional) => functional.molar_weight()
             });
             has_molar_weight.push(quote! {
-                Self::#name(functional) => true
+                Self::#name(functional) => functional.has_molar_weight()
             });
         }
     }
@@ -30,6 +30,12 @@ fn impl_residual(
             Self::#name(residual) => residual.compute_max_density(moles)
         }
     });
+    let packing_fraction = variants.iter().map(|v| {
+        let name = &v.ident;
+        quote! {
+            Self::#name(residual) => residual.packing_fraction(temperature, partial_density)
+        }
+    });
     let residual_helmholtz_energy_contributions = variants.iter().map(|v| {
         let name = &v.ident;
         quote! {
@@ -44,6 +50,11 @@ fn impl_residual(
                     #(#compute_max_density,)*
                 }
             }
+            fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+                match self {
+                    #(#packing_fraction,)*
+                }
+            }
             fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(&self, state: &StateHD<D>) -> Vec<(String, D)> {
                 match self {
                     #(#residual_helmholtz_energy_contributions,)*
@@ -66,7 +77,7 @@ fn impl_molar_weight(
                 Self::#name(eos) => eos.molar_weight()
             });
             has_molar_weight.push(quote! {
-                Self::#name(_) => true
+                Self::#name(eos) => eos.has_molar_weight()
             });
         }
     }
@@ -115,6 +115,10 @@ impl<F: Molarweight> Molarweight for DFT<F> {
     fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
         self.0.molar_weight()
     }
+
+    fn has_molar_weight(&self) -> bool {
+        self.0.has_molar_weight()
+    }
 }
 
 impl<F: HelmholtzEnergyFunctional + IdealGas> IdealGas for DFT<F> {
@@ -229,6 +229,11 @@ impl<P: AssociationStrength> Association<P> {
     ) -> Array1<N> {
         let a = &self.association_parameters;
 
+        // a single site of a generic type that does not bond with itself
+        if !a.bonds_cc[(0, 0)] {
+            return Array::zeros(n3i.len());
+        }
+
         // site densities
         let i = a.sites_c[0].assoc_comp;
         let rhoc = &rho0.index_axis(Axis(0), i) * a.sites_c[0].n;
@@ -1,13 +1,14 @@
 //! Generic implementation of the SAFT association contribution
 //! that can be used across models.
 use crate::hard_sphere::HardSphereProperties;
+use feos_core::parameter::ParameterError;
 use feos_core::{EosError, EosResult, StateHD};
 use ndarray::*;
 use num_dual::linalg::{norm, LU};
 use num_dual::*;
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::sync::Arc;
 
@@ -20,6 +21,7 @@ struct AssociationSite<A> {
     site_index: usize,
     n: f64,
     parameters: A,
+    site_type: Option<usize>,
 }
 
 impl<A> AssociationSite<A> {
@@ -29,12 +31,49 @@ impl<A> AssociationSite<A> {
             site_index,
             n,
             parameters,
+            site_type: None,
         }
     }
+
+    fn new_generic(
+        assoc_comp: usize,
+        site_index: usize,
+        n: f64,
+        parameters: A,
+        site_type: usize,
+    ) -> Self {
+        Self {
+            site_type: Some(site_type),
+            ..Self::new(assoc_comp, site_index, n, parameters)
+        }
+    }
+}
+
+/// Association sites of a generic site type.
+///
+/// Sites of generic types do not bond with sites of type A, B or C.
+/// Two generic site types bond with each other, if either of them
+/// lists the other one in `bonds_with`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AssociationSiteType {
+    /// Identifier of the site type
+    pub id: usize,
+    /// \# of association sites of this type
+    pub n: f64,
+    /// Identifiers of the site types that sites of this type bond with
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub bonds_with: Vec<usize>,
+}
+
+impl AssociationSiteType {
+    pub fn new(id: usize, n: f64, bonds_with: Vec<usize>) -> Self {
+        Self { id, n, bonds_with }
+    }
 }
 
 /// Pure component association parameters.
-#[derive(Serialize, Deserialize, Clone, Copy)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct AssociationRecord<A> {
     #[serde(flatten)]
     pub parameters: A,
@@ -50,6 +89,10 @@ pub struct AssociationRecord<A> {
     #[serde(skip_serializing_if = "f64::is_zero")]
     #[serde(default)]
     pub nc: f64,
+    /// Association sites of additional, generic site types
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default)]
+    pub sites: Vec<AssociationSiteType>,
 }
 
 impl<A> AssociationRecord<A> {
@@ -59,6 +102,33 @@ impl<A> AssociationRecord<A> {
             na,
             nb,
             nc,
+            sites: Vec::new(),
+        }
+    }
+
+    /// Create a record with association sites of generic site types only.
+    pub fn new_generic(parameters: A, sites: Vec<AssociationSiteType>) -> Self {
+        Self {
+            sites,
+            ..Self::new(parameters, 0.0, 0.0, 0.0)
+        }
+    }
+
+    /// Return a record in which the numbers of all sites are multiplied by `factor`.
+    pub fn scale(&self, factor: f64) -> Self
+    where
+        A: Copy,
+    {
+        Self {
+            parameters: self.parameters,
+            na: self.na * factor,
+            nb: self.nb * factor,
+            nc: self.nc * factor,
+            sites: self
+                .sites
+                .iter()
+                .map(|s| AssociationSiteType::new(s.id, s.n * factor, s.bonds_with.clone()))
+                .collect(),
         }
     }
 }
@@ -75,6 +145,9 @@ impl<A: fmt::Display> fmt::Display for AssociationRecord<A> {
         if self.nc > 0.0 {
             write!(f, ", nc={}", self.nc)?;
         }
+        for site in &self.sites {
+            write!(f, ", n{}={}", site.id, site.n)?;
+        }
         write!(f, ")")
     }
 }
@@ -91,6 +164,20 @@ pub struct BinaryAssociationRecord<B> {
     pub site_indices: [usize; 2],
 }
 
+/// Site types connected by a binary association parameter.
+///
+/// The first site always belongs to the first component of the
+/// binary record and the second site to the second component.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SitePair {
+    /// Site A of the first and site B of the second component.
+    AB,
+    /// Site B of the first and site A of the second component.
+    BA,
+    /// Sites C of both components.
+    CC,
+}
+
 fn is_default_site_indices([i, j]: &[usize; 2]) -> bool {
     *i == 0 && *j == 0
 }
@@ -114,6 +201,7 @@ pub struct AssociationParameters<P: AssociationStrength> {
     sites_c: Array1<AssociationSite<P::Record>>,
     parameters_ab: Array2<P::Record>,
     parameters_cc: Array2<P::Record>,
+    bonds_cc: Array2<bool>,
 }
 
 impl<P: AssociationStrength> AssociationParameters<P> {
@@ -137,9 +225,34 @@ impl<P: AssociationStrength> AssociationParameters<P> {
                 if site.nc > 0.0 {
                     sites_c.push(AssociationSite::new(i, s, site.nc, site.parameters));
                 }
+                // sites of generic types are treated like sites of type C that
+                // only bond with the site types given by the association pattern
+                for site_type in site.sites.iter().filter(|t| t.n > 0.0) {
+                    sites_c.push(AssociationSite::new_generic(
+                        i,
+                        s,
+                        site_type.n,
+                        site.parameters,
+                        site_type.id,
+                    ));
+                }
             }
         }
 
+        let pattern: HashSet<_> = records
+            .iter()
+            .flatten()
+            .flat_map(|record| &record.sites)
+            .flat_map(|t| t.bonds_with.iter().flat_map(|&u| [(t.id, u), (u, t.id)]))
+            .collect();
+        let bonds_cc = Array2::from_shape_fn([sites_c.len(); 2], |(i, j)| {
+            match (sites_c[i].site_type, sites_c[j].site_type) {
+                (None, None) => true,
+                (Some(ti), Some(tj)) => pattern.contains(&(ti, tj)),
+                _ => false,
+            }
+        });
+
         let indices_a: HashMap<_, _> = sites_a
             .iter()
             .enumerate()
@@ -155,6 +268,7 @@ impl<P: AssociationStrength> AssociationParameters<P> {
         let indices_c: HashMap<_, _> = sites_c
             .iter()
             .enumerate()
+            .filter(|(_, site)| site.site_type.is_none())
             .map(|(i, site)| ((site.assoc_comp, site.site_index), i))
             .collect();
 
@@ -168,14 +282,30 @@ impl<P: AssociationStrength> AssociationParameters<P> {
         for &([i, j], record) in binary_records.iter() {
             let [a, b] = record.site_indices;
             if let (Some(x), Some(y)) = (indices_a.get(&(i, a)), indices_b.get(&(j, b))) {
-                P::update_binary(&mut parameters_ab[[*x, *y]], record.parameters);
+                P::update_binary(
+                    &mut parameters_ab[[*x, *y]],
+                    record.parameters,
+                    SitePair::AB,
+                );
             }
             if let (Some(y), Some(x)) = (indices_b.get(&(i, a)), indices_a.get(&(j, b))) {
-                P::update_binary(&mut parameters_ab[[*x, *y]], record.parameters);
+                P::update_binary(
+                    &mut parameters_ab[[*x, *y]],
+                    record.parameters,
+                    SitePair::BA,
+                );
             }
             if let (Some(x), Some(y)) = (indices_c.get(&(i, a)), indices_c.get(&(j, b))) {
-                P::update_binary(&mut parameters_cc[[*x, *y]], record.parameters);
-                P::update_binary(&mut parameters_cc[[*y, *x]], record.parameters);
+                P::update_binary(
+                    &mut parameters_cc[[*x, *y]],
+                    record.parameters,
+                    SitePair::CC,
+                );
+                P::update_binary(
+                    &mut parameters_cc[[*y, *x]],
+                    record.parameters,
+                    SitePair::CC,
+                );
             }
         }
 
@@ -188,12 +318,76 @@ impl<P: AssociationStrength> AssociationParameters<P> {
             sites_c: Array1::from_vec(sites_c),
             parameters_ab,
             parameters_cc,
+            bonds_cc,
         }
     }
 
+    /// Check that no binary record refers to an association record with
+    /// generic site types, for which binary parameters are not supported.
+    pub fn check_binary_records(
+        records: &[Vec<AssociationRecord<P::Record>>],
+        binary_records: &[([usize; 2], BinaryAssociationRecord<P::BinaryRecord>)],
+    ) -> Result<(), ParameterError> {
+        for ([i, j], record) in binary_records {
+            let [a, b] = record.site_indices;
+            for (c, s) in [(*i, a), (*j, b)] {
+                if records[c].get(s).is_some_and(|r| !r.sites.is_empty()) {
+                    return Err(ParameterError::IncompatibleParameters(format!(
+                        "binary association parameters of component {c} refer to generic site types, which is not supported."
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn is_empty(&self) -> bool {
         (self.sites_a.is_empty() | self.sites_b.is_empty()) & self.sites_c.is_empty()
     }
+
+    /// All association sites as (component index, site type, number of sites).
+    ///
+    /// Sites are ordered by type (A, B, then C). Sites of generic types are
+    /// labeled by their identifier and listed together with the sites of type C.
+    pub fn sites(&self) -> Vec<(usize, String, f64)> {
+        let sites = |sites: &Array1<AssociationSite<P::Record>>, site_type: &str| {
+            sites
+                .iter()
+                .map(|s| {
+                    let site_type = s.site_type.map_or(site_type.into(), |t| t.to_string());
+                    (self.component_index[s.assoc_comp], site_type, s.n)
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut res = sites(&self.sites_a, "A");
+        res.extend(sites(&self.sites_b, "B"));
+        res.extend(sites(&self.sites_c, "C"));
+        res
+    }
+
+    /// Pairs of sites that can bond with each other, given as indices into [Self::sites].
+    ///
+    /// Sites of type A bond with sites of type B, sites of type C bond with each other.
+    /// Sites of generic types bond according to the pattern given in the records.
+    pub fn site_pairs(&self) -> Vec<(usize, usize)> {
+        let a = self.sites_a.len();
+        let b = self.sites_b.len();
+        let c = self.sites_c.len();
+        let mut res = Vec::with_capacity(a * b + c * (c + 1) / 2);
+        for i in 0..a {
+            for j in 0..b {
+                res.push((i, a + j));
+            }
+        }
+        for i in 0..c {
+            for j in i..c {
+                if self.bonds_cc[(i, j)] {
+                    res.push((a + b + i, a + b + j));
+                }
+            }
+        }
+        res
+    }
 }
 
 /// Implementation of the SAFT association Helmholtz energy
@@ -249,7 +443,12 @@ pub trait AssociationStrength: HardSphereProperties {
 
     fn combining_rule(parameters_i: Self::Record, parameters_j: Self::Record) -> Self::Record;
 
-    fn update_binary(_parameters_ij: &mut Self::Record, _binary_parameters: Self::BinaryRecord) {}
+    fn update_binary(
+        _parameters_ij: &mut Self::Record,
+        _binary_parameters: Self::BinaryRecord,
+        _site_pair: SitePair,
+    ) {
+    }
 }
 
 impl<P: AssociationStrength> Association<P> {
@@ -309,6 +508,51 @@ impl<P: AssociationStrength> Association<P> {
         }
     }
 
+    /// Fractions of non-bonded association sites calculated with the
+    /// iterative cross-association solver.
+    ///
+    /// The sites are ordered as A sites, B sites, and C sites. Returns
+    /// [EosError::NotConverged] with the last residual if the solver does
+    /// not converge within the maximum number of iterations.
+    pub fn monomer_fractions(
+        &self,
+        state: &StateHD<f64>,
+        diameter: &Array1<f64>,
+    ) -> EosResult<Array1<f64>> {
+        let a = &self.association_parameters;
+
+        // auxiliary variables
+        let [zeta2, n3] = self
+            .parameters
+            .zeta(state.temperature, &state.partial_density, [2, 3]);
+        let n2 = zeta2 * 6.0;
+        let n3i = (-n3 + 1.0).recip();
+
+        // association strength
+        let [delta_ab, delta_cc] =
+            self.association_strength(state.temperature, diameter, n2, n3i, 1.0);
+
+        // extract site densities of associating segments
+        let rho: Array1<_> = a
+            .sites_a
+            .iter()
+            .chain(a.sites_b.iter())
+            .chain(a.sites_c.iter())
+            .map(|s| state.partial_density[a.component_index[s.assoc_comp]] * s.n)
+            .collect();
+
+        let mut x = Array::from_elem(rho.len(), 0.2);
+        Self::helmholtz_energy_density_cross_association(
+            &rho,
+            &delta_ab,
+            &delta_cc,
+            self.max_iter,
+            self.tol,
+            Some(&mut x),
+        )?;
+        Ok(x)
+    }
+
     fn association_strength<D: DualNum<f64> + Copy>(
         &self,
         temperature: D,
@@ -332,6 +576,9 @@ impl<P: AssociationStrength> Association<P> {
                 )
         });
         let delta_cc = Array2::from_shape_fn([p.sites_c.len(); 2], |(i, j)| {
+            if !p.bonds_cc[(i, j)] {
+                return D::zero();
+            }
             let di = diameter[p.sites_c[i].assoc_comp];
             let dj = diameter[p.sites_c[j].assoc_comp];
             let k = di * dj / (di + dj) * (n2 * n3i);
@@ -418,25 +665,24 @@ impl<P: AssociationStrength> Association<P> {
         let delta_ab_re = delta_ab.map(D::re);
         let delta_cc_re = delta_cc.map(D::re);
         let rho_re = rho.map(D::re);
-        for k in 0..max_iter {
-            if Self::newton_step_cross_association(
-                &mut x,
-                &delta_ab_re,
-                &delta_cc_re,
-                &rho_re,
-                tol,
-            )? {
+        let mut residual = f64::INFINITY;
+        for _ in 0..max_iter {
+            residual =
+                Self::newton_step_cross_association(&mut x, &delta_ab_re, &delta_cc_re, &rho_re)?;
+            if residual < tol {
                 break;
             }
-            if k == max_iter - 1 {
-                return Err(EosError::NotConverged("Cross association".into()));
-            }
+        }
+        if residual >= tol {
+            return Err(EosError::NotConverged(format!(
+                "Cross association (residual = {residual:e})"
+            )));
         }
 
         // calculate derivatives
         let mut x_dual = x.mapv(D::from);
         for _ in 0..D::NDERIV {
-            Self::newton_step_cross_association(&mut x_dual, delta_ab, delta_cc, rho, tol)?;
+            Self::newton_step_cross_association(&mut x_dual, delta_ab, delta_cc, rho)?;
         }
 
         // save monomer fraction
@@ -454,8 +700,7 @@ impl<P: AssociationStrength> Association<P> {
         delta_ab: &Array2<D>,
         delta_cc: &Array2<D>,
         rho: &ArrayBase<S, Ix1>,
-        tol: f64,
-    ) -> EosResult<bool> {
+    ) -> EosResult<f64> {
         let nassoc = x.len();
         // gradient
         let mut g = x.map(D::recip);
@@ -514,8 +759,8 @@ impl<P: AssociationStrength> Association<P> {
             }
         });
 
-        // check convergence
-        Ok(norm(&g.map(D::re)) < tol)
+        // residual of the Newton step
+        Ok(norm(&g.map(D::re)))
     }
 }
 
@@ -525,10 +770,14 @@ mod tests_pcsaft {
     use super::*;
     use crate::hard_sphere::HardSphereProperties;
     use crate::pcsaft::parameters::utils::water_parameters;
-    use crate::pcsaft::parameters::{PcSaftAssociationRecord, PcSaftBinaryAssociationRecord};
+    use crate::pcsaft::parameters::{
+        PcSaftAssociationRecord, PcSaftBinaryAssociationRecord, PcSaftBinaryRecord,
+    };
     use crate::pcsaft::PcSaftParameters;
     use approx::assert_relative_eq;
-    use feos_core::parameter::{Parameter, ParameterError};
+    use feos_core::parameter::{
+        BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError,
+    };
 
     fn record(
         kappa_ab: f64,
@@ -571,6 +820,97 @@ mod tests_pcsaft {
         assert_eq!(assoc.parameters_ab.mapv(|p| p.epsilon_k_ab), epsilon_k_ab);
     }
 
+    #[test]
+    fn test_asymmetric_binary_parameters() -> Result<(), ParameterError> {
+        let water = water_parameters().pure_records.pop().unwrap();
+        let mut other = water.clone();
+        other.model_record = other.model_record.with_sigma(3.5);
+        let records = vec![water, other];
+        let symmetric = PcSaftBinaryRecord::new(None, Some(0.02), Some(2000.));
+        let asymmetric =
+            PcSaftBinaryRecord::new_asymmetric(None, Some(0.02), Some(2000.), None, Some(1000.));
+        let symmetric = PcSaftParameters::new_binary(records.clone(), Some(symmetric))?;
+        let asymmetric = PcSaftParameters::new_binary(records, Some(asymmetric))?;
+
+        // site A of the first component with site B of the second component
+        let ab = symmetric.association.parameters_ab[[0, 1]];
+        assert_eq!(
+            asymmetric.association.parameters_ab[[0, 1]].epsilon_k_ab,
+            2000.
+        );
+        assert_eq!(ab.epsilon_k_ab, 2000.);
+
+        // site B of the first component with site A of the second component
+        let ba_sym = symmetric.association.parameters_ab[[1, 0]];
+        let ba_asym = asymmetric.association.parameters_ab[[1, 0]];
+        assert_eq!(ba_sym.epsilon_k_ab, 2000.);
+        assert_eq!(ba_asym.epsilon_k_ab, 1000.);
+        assert_eq!(ba_asym.kappa_ab, 0.02);
+
+        let t = 350.0;
+        let delta_sym = symmetric.association_strength(t, 1, 0, ba_sym);
+        let delta_asym = asymmetric.association_strength(t, 1, 0, ba_asym);
+        assert!(delta_asym < delta_sym);
+        assert_relative_eq!(
+            symmetric.association_strength(t, 1, 0, ab),
+            delta_sym,
+            max_relative = 1e-14
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_asymmetric_binary_parameters_record_order() -> Result<(), ParameterError> {
+        let water = water_parameters().pure_records.pop().unwrap();
+        let mut other = water.clone();
+        other.identifier.name = Some("other".into());
+        other.model_record = other.model_record.with_sigma(3.5);
+        let binary_json = r#"[
+            {
+                "id1": {"name": "water_np"},
+                "id2": {"name": "other"},
+                "model_record": {
+                    "kappa_ab": 0.02,
+                    "epsilon_k_ab": 2000.0,
+                    "epsilon_k_ba": 1000.0
+                }
+            }
+        ]"#;
+        let binary_records: Vec<BinaryRecord<Identifier, PcSaftBinaryRecord>> =
+            serde_json::from_str(binary_json).expect("Unable to parse json.");
+
+        // water is the first component
+        let records = vec![water.clone(), other.clone()];
+        let binary = PcSaftParameters::binary_matrix_from_records(
+            &records,
+            &binary_records,
+            IdentifierOption::Name,
+        );
+        let parameters = PcSaftParameters::from_records(records, binary)?;
+        let ab = parameters
+            .association
+            .parameters_ab
+            .mapv(|p| p.epsilon_k_ab);
+        assert_eq!(ab[[0, 1]], 2000.);
+        assert_eq!(ab[[1, 0]], 1000.);
+
+        // water is the second component
+        let records = vec![other, water];
+        let binary = PcSaftParameters::binary_matrix_from_records(
+            &records,
+            &binary_records,
+            IdentifierOption::Name,
+        );
+        let parameters = PcSaftParameters::from_records(records, binary)?;
+        let ab = parameters
+            .association
+            .parameters_ab
+            .mapv(|p| p.epsilon_k_ab);
+        assert_eq!(ab[[1, 0]], 2000.);
+        assert_eq!(ab[[0, 1]], 1000.);
+        Ok(())
+    }
+
     #[test]
     fn test_induced_association() {
         let comp1 = vec![record(0.1, 2500., 1.0, 1.0)];
@@ -592,6 +932,16 @@ mod tests_pcsaft {
         );
     }
 
+    #[test]
+    fn test_sites() {
+        let params = water_parameters();
+        assert_eq!(
+            params.association.sites(),
+            vec![(0, "A".into(), 1.0), (0, "B".into(), 1.0)]
+        );
+        assert_eq!(params.association.site_pairs(), vec![(0, 1)]);
+    }
+
     #[test]
     fn helmholtz_energy() {
         let params = Arc::new(water_parameters());
@@ -622,9 +972,7 @@ mod tests_pcsaft {
     fn helmholtz_energy_cross_3b() -> Result<(), ParameterError> {
         let mut params = water_parameters();
         let mut record = params.pure_records.pop().unwrap();
-        let mut association_record = record.model_record.association_record.unwrap();
-        association_record.na = 2.0;
-        record.model_record.association_record = Some(association_record);
+        record.model_record.association_records[0].na = 2.0;
         let params = Arc::new(PcSaftParameters::new_pure(record)?);
         let assoc = Association::new(&params, &params.association, 50, 1e-10);
         let cross_assoc =
@@ -639,6 +987,85 @@ mod tests_pcsaft {
         assert_relative_eq!(a_assoc, a_cross_assoc, epsilon = 1e-10);
         Ok(())
     }
+
+    fn generic_water(sites: Vec<AssociationSiteType>) -> Result<PcSaftParameters, ParameterError> {
+        let mut record = water_parameters().pure_records.pop().unwrap();
+        let parameters = record.model_record.association_records[0].parameters;
+        record.model_record.association_records =
+            vec![AssociationRecord::new_generic(parameters, sites)];
+        PcSaftParameters::new_pure(record)
+    }
+
+    fn helmholtz_energy_water(params: PcSaftParameters) -> f64 {
+        let params = Arc::new(params);
+        let assoc = Association::new(&params, &params.association, 50, 1e-10);
+        let t = 350.0;
+        let v = 41.248289328513216;
+        let n = 1.23;
+        let s = StateHD::new(t, v, arr1(&[n]));
+        let d = params.hs_diameter(t);
+        assoc.helmholtz_energy(&s, &d) / n
+    }
+
+    #[test]
+    fn helmholtz_energy_generic_site_types() -> Result<(), ParameterError> {
+        // two generic site types that bond with each other are equivalent to sites A and B
+        let params = generic_water(vec![
+            AssociationSiteType::new(1, 1.0, vec![2]),
+            AssociationSiteType::new(2, 1.0, vec![]),
+        ])?;
+        assert_eq!(
+            params.association.sites(),
+            vec![(0, "1".into(), 1.0), (0, "2".into(), 1.0)]
+        );
+        assert_eq!(params.association.site_pairs(), vec![(0, 1)]);
+        let a = helmholtz_energy_water(params);
+        assert_relative_eq!(a, -4.229878997054543, epsilon = 1e-10);
+
+        // a generic site type that bonds with itself is equivalent to a site C
+        let params = generic_water(vec![AssociationSiteType::new(7, 2.0, vec![7])])?;
+        let mut record = water_parameters().pure_records.pop().unwrap();
+        let association = &mut record.model_record.association_records[0];
+        (association.na, association.nb, association.nc) = (0.0, 0.0, 2.0);
+        let params_c = PcSaftParameters::new_pure(record)?;
+        assert_relative_eq!(
+            helmholtz_energy_water(params),
+            helmholtz_energy_water(params_c),
+            epsilon = 1e-10
+        );
+
+        // four site types in two independent pairs (1-2 and 3-4)
+        let params = generic_water(vec![
+            AssociationSiteType::new(1, 1.0, vec![2]),
+            AssociationSiteType::new(2, 1.0, vec![1]),
+            AssociationSiteType::new(3, 1.0, vec![4]),
+            AssociationSiteType::new(4, 1.0, vec![]),
+        ])?;
+        assert_eq!(params.association.site_pairs(), vec![(0, 1), (2, 3)]);
+        let a = helmholtz_energy_water(params);
+        assert!(a.is_finite());
+        assert_relative_eq!(a, 2.0 * -4.229878997054543, epsilon = 1e-9);
+
+        // sites that do not bond do not contribute
+        let params = generic_water(vec![AssociationSiteType::new(1, 1.0, vec![])])?;
+        assert!(params.association.site_pairs().is_empty());
+        assert_eq!(helmholtz_energy_water(params), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_parameters_generic_site_types() -> Result<(), ParameterError> {
+        let water = generic_water(vec![AssociationSiteType::new(1, 1.0, vec![1])])?
+            .pure_records
+            .pop()
+            .unwrap();
+        let mut other = water.clone();
+        other.model_record = other.model_record.with_sigma(3.5);
+        let binary = PcSaftBinaryRecord::new(None, Some(0.02), Some(2000.));
+        assert!(PcSaftParameters::new_binary(vec![water.clone(), other.clone()], None).is_ok());
+        assert!(PcSaftParameters::new_binary(vec![water, other], Some(binary)).is_err());
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -13,6 +13,7 @@ use crate::saftvrqmie::SaftVRQMie;
 #[cfg(feature = "uvtheory")]
 use crate::uvtheory::UVTheory;
 use feos_core::cubic::PengRobinson;
+use feos_core::parameter::Identifier;
 #[cfg(feature = "python")]
 use feos_core::python::user_defined::PyResidual;
 use feos_core::*;
@@ -55,3 +56,31 @@ pub enum ResidualModel {
     #[implement(molar_weight)]
     UVTheory(UVTheory),
 }
+
+impl ResidualModel {
+    /// Identifiers of all components in the order used by the model.
+    ///
+    /// Returns `None` for models that are not constructed from
+    /// pure-component records.
+    pub fn component_identifiers(&self) -> Option<Vec<Identifier>> {
+        match self {
+            #[cfg(feature = "pcsaft")]
+            Self::PcSaft(eos) => Some(
+                eos.parameters()
+                    .pure_records
+                    .iter()
+                    .map(|r| r.identifier.clone())
+                    .collect(),
+            ),
+            #[cfg(feature = "epcsaft")]
+            Self::ElectrolytePcSaft(eos) => Some(
+                eos.parameters
+                    .pure_records
+                    .iter()
+                    .map(|r| r.identifier.clone())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
@@ -155,6 +155,10 @@ impl Residual for ElectrolytePcSaft {
                 .sum()
     }
 
+    fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+        Some(self.parameters.zeta(temperature, partial_density, [3])[0])
+    }
+
     fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy>(
         &self,
         state: &StateHD<D>,
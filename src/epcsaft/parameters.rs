@@ -1,5 +1,6 @@
 use crate::association::{
     AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
+    SitePair,
 };
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use feos_core::parameter::{FromSegments, Parameter, ParameterError, PureRecord};
@@ -357,11 +358,11 @@ impl Parameter for ElectrolytePcSaftParameters {
             sigma[i] = r.sigma;
             epsilon_k[i] = r.epsilon_k;
             z[i] = r.z.unwrap_or(0.0);
-            association_records.push(r.association_record.into_iter().collect());
+            association_records.push(r.association_record.iter().cloned().collect());
             molarweight[i] = record.molarweight;
             // check if component i is water with temperature-dependent sigma
             if (m[i] * 1000.0).round() / 1000.0 == 1.205 && epsilon_k[i].round() == 354.0 {
-                if let Some(record) = r.association_record {
+                if let Some(record) = &r.association_record {
                     if (record.parameters.kappa_ab * 1000.0).round() / 1000.0 == 0.045
                         && record.parameters.epsilon_k_ab.round() == 2426.0
                     {
@@ -378,6 +379,10 @@ impl Parameter for ElectrolytePcSaftParameters {
                     .filter_map(|((i, j), record)| record.association.map(|r| ([i, j], r)))
             })
             .collect();
+        AssociationParameters::<Self>::check_binary_records(
+            &association_records,
+            &binary_association,
+        )?;
         let association =
             AssociationParameters::new(&association_records, &binary_association, None);
 
@@ -589,7 +594,11 @@ impl AssociationStrength for ElectrolytePcSaftParameters {
         }
     }
 
-    fn update_binary(parameters_ij: &mut Self::Record, binary_parameters: Self::BinaryRecord) {
+    fn update_binary(
+        parameters_ij: &mut Self::Record,
+        binary_parameters: Self::BinaryRecord,
+        _site_pair: SitePair,
+    ) {
         if let Some(kappa_ab) = binary_parameters.kappa_ab {
             parameters_ij.kappa_ab = kappa_ab
         }
@@ -611,14 +620,18 @@ impl ElectrolytePcSaftParameters {
         for (i, record) in self.pure_records.iter().enumerate() {
             let component = record.identifier.name.clone();
             let component = component.unwrap_or(format!("Component {}", i + 1));
-            let association = record.model_record.association_record.unwrap_or_else(|| {
-                AssociationRecord::new(
-                    ElectrolytePcSaftAssociationRecord::new(0.0, 0.0),
-                    0.0,
-                    0.0,
-                    0.0,
-                )
-            });
+            let association = record
+                .model_record
+                .association_record
+                .clone()
+                .unwrap_or_else(|| {
+                    AssociationRecord::new(
+                        ElectrolytePcSaftAssociationRecord::new(0.0, 0.0),
+                        0.0,
+                        0.0,
+                        0.0,
+                    )
+                });
             write!(
                 o,
                 "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
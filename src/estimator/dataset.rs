@@ -3,11 +3,80 @@
 //! a `target` which can be values from experimental data or
 //! other models.
 use super::{EstimatorError, Loss};
-use feos_core::Residual;
+use feos_core::{EosResult, Residual, SolverOptions, State};
 use ndarray::Array1;
+use quantity::Temperature;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::sync::Arc;
 
+/// Inputs of a critical point calculation stored in a [`DataSetCache`].
+type CriticalPointKey = (Option<Temperature>, SolverOptions);
+
+/// Results that are shared between [`DataSet`]s during a single
+/// evaluation of an [`Estimator`](super::Estimator).
+///
+/// The cache is only valid for a single equation of state and
+/// has to be discarded once the parameters change.
+pub struct DataSetCache<E> {
+    critical_points: RefCell<Vec<(CriticalPointKey, State<E>)>>,
+    critical_point_solves: Cell<usize>,
+}
+
+impl<E> Default for DataSetCache<E> {
+    fn default() -> Self {
+        Self {
+            critical_points: RefCell::new(Vec::new()),
+            critical_point_solves: Cell::new(0),
+        }
+    }
+}
+
+impl<E: Residual> DataSetCache<E> {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the critical point of the pure substance.
+    ///
+    /// The critical point is only calculated if it is not already stored
+    /// in the cache for the same `initial_temperature` and `options`.
+    /// If the calculation starting from `initial_temperature` fails, it is
+    /// repeated with the default initial temperature.
+    pub fn critical_point(
+        &self,
+        eos: &Arc<E>,
+        initial_temperature: Option<Temperature>,
+        options: SolverOptions,
+    ) -> EosResult<State<E>> {
+        let key = (initial_temperature, options);
+        if let Some((_, critical_point)) = self
+            .critical_points
+            .borrow()
+            .iter()
+            .find(|(k, _)| *k == key)
+        {
+            return Ok(critical_point.clone());
+        }
+        let solve = |initial_temperature| {
+            self.critical_point_solves
+                .set(self.critical_point_solves.get() + 1);
+            State::critical_point(eos, None, initial_temperature, options)
+        };
+        let critical_point = solve(initial_temperature).or_else(|_| solve(None))?;
+        self.critical_points
+            .borrow_mut()
+            .push((key, critical_point.clone()));
+        Ok(critical_point)
+    }
+
+    /// Return the number of critical point calculations performed using this cache.
+    pub fn critical_point_solves(&self) -> usize {
+        self.critical_point_solves.get()
+    }
+}
+
 /// Utilities for working with experimental data.
 ///
 /// Functionalities in the context of optimizations of
@@ -25,14 +94,46 @@ pub trait DataSet<E: Residual>: Send + Sync {
     /// Evaluation of the equation of state for the target quantity.
     fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError>;
 
+    /// Evaluation of the equation of state for the target quantity
+    /// reusing results stored in `cache`.
+    ///
+    /// Defaults to [`predict`](Self::predict) for data sets that do
+    /// not benefit from caching.
+    fn predict_with_cache(
+        &self,
+        eos: &Arc<E>,
+        _cache: &DataSetCache<E>,
+    ) -> Result<Array1<f64>, EstimatorError> {
+        self.predict(eos)
+    }
+
     /// Evaluate the cost function.
     fn cost(&self, eos: &Arc<E>, loss: Loss) -> Result<Array1<f64>, EstimatorError> {
-        let mut cost = self.relative_difference(eos)?;
+        self.cost_with_cache(eos, loss, &DataSetCache::new())
+    }
+
+    /// Evaluate the cost function reusing results stored in `cache`.
+    fn cost_with_cache(
+        &self,
+        eos: &Arc<E>,
+        loss: Loss,
+        cache: &DataSetCache<E>,
+    ) -> Result<Array1<f64>, EstimatorError> {
+        let mut cost = self.relative_difference_with_cache(eos, cache)?;
         loss.apply(&mut cost);
         let datapoints = cost.len();
         Ok(cost / datapoints as f64)
     }
 
+    /// Returns the prediction of the equation of state and the target
+    /// for each data point, e.g., for parity plots.
+    fn predict_vs_target(
+        &self,
+        eos: &Arc<E>,
+    ) -> Result<(Array1<f64>, Array1<f64>), EstimatorError> {
+        Ok((self.predict(eos)?, self.target().clone()))
+    }
+
     /// Returns the number of experimental data points.
     fn datapoints(&self) -> usize {
         self.target().len()
@@ -40,7 +141,17 @@ pub trait DataSet<E: Residual>: Send + Sync {
 
     /// Returns the relative difference between the equation of state and the experimental values.
     fn relative_difference(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
-        let prediction = &self.predict(eos)?;
+        self.relative_difference_with_cache(eos, &DataSetCache::new())
+    }
+
+    /// Returns the relative difference between the equation of state and the experimental values
+    /// reusing results stored in `cache`.
+    fn relative_difference_with_cache(
+        &self,
+        eos: &Arc<E>,
+        cache: &DataSetCache<E>,
+    ) -> Result<Array1<f64>, EstimatorError> {
+        let prediction = &self.predict_with_cache(eos, cache)?;
         let target = self.target();
         Ok((prediction - target) / target)
     }
@@ -54,6 +165,21 @@ pub trait DataSet<E: Residual>: Send + Sync {
             .enumerate()
             .fold(0.0, |mean, (i, x)| mean + (x.abs() - mean) / (i + 1) as f64))
     }
+
+    /// Returns the mean of the absolute difference between the equation of state and the experimental values.
+    ///
+    /// The result is given in the same units as the [`target`](Self::target).
+    /// In contrast to the [`mean_absolute_relative_difference`](Self::mean_absolute_relative_difference),
+    /// it is well-behaved for target values close to zero.
+    fn mean_absolute_difference(&self, eos: &Arc<E>) -> Result<f64, EstimatorError> {
+        let prediction = &self.predict(eos)?;
+        let target = self.target();
+        Ok((prediction - target)
+            .into_iter()
+            .filter(|&x| x.is_finite())
+            .enumerate()
+            .fold(0.0, |mean, (i, x)| mean + (x.abs() - mean) / (i + 1) as f64))
+    }
 }
 
 impl<E: Residual> fmt::Display for dyn DataSet<E> {
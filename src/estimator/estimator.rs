@@ -1,6 +1,6 @@
 //! The [`Estimator`] struct can be used to store multiple [`DataSet`]s for convenient parameter
 //! optimization.
-use super::{DataSet, EstimatorError, Loss};
+use super::{DataSet, DataSetCache, EstimatorError, Loss};
 use feos_core::Residual;
 use ndarray::{arr1, concatenate, Array1, ArrayView1, Axis};
 // use quantity::si::SIArray1;
@@ -15,6 +15,7 @@ pub struct Estimator<E: Residual> {
     data: Vec<Arc<dyn DataSet<E>>>,
     weights: Vec<f64>,
     losses: Vec<Loss>,
+    non_finite_penalty: Option<f64>,
 }
 
 impl<E: Residual> Estimator<E> {
@@ -27,9 +28,26 @@ impl<E: Residual> Estimator<E> {
             data,
             weights,
             losses,
+            non_finite_penalty: None,
         }
     }
 
+    /// Replace non-finite costs by `penalty`.
+    ///
+    /// By default, a `NaN` or infinite cost of a single data point (e.g.,
+    /// if a phase equilibrium can not be calculated for a trial parameter
+    /// set) is passed on to the optimizer. With a finite penalty, these
+    /// costs are replaced by `penalty` (after weighting) instead.
+    ///
+    /// Returns an error if `penalty` itself is not finite.
+    pub fn with_non_finite_penalty(mut self, penalty: f64) -> Result<Self, EstimatorError> {
+        if !penalty.is_finite() {
+            return Err(EstimatorError::NonFinitePenalty(penalty));
+        }
+        self.non_finite_penalty = Some(penalty);
+        Ok(self)
+    }
+
     /// Add a `DataSet` and its weight.
     pub fn add_data(&mut self, data: &Arc<dyn DataSet<E>>, weight: f64, loss: Loss) {
         self.data.push(data.clone());
@@ -41,20 +59,62 @@ impl<E: Residual> Estimator<E> {
     ///
     /// Each cost contains the inverse weight.
     pub fn cost(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
-        let w = arr1(&self.weights) / self.weights.iter().sum::<f64>();
-        let predictions = self
+        self.cost_with_cache(eos, &DataSetCache::new())
+    }
+
+    /// Returns the cost of each `DataSet` reusing results stored in `cache`.
+    ///
+    /// Results that are shared between `DataSet`s, like the critical
+    /// point of a pure substance, are only calculated once.
+    pub fn cost_with_cache(
+        &self,
+        eos: &Arc<E>,
+        cache: &DataSetCache<E>,
+    ) -> Result<Array1<f64>, EstimatorError> {
+        let predictions = self.weighted_costs(eos, cache)?;
+        let aview: Vec<ArrayView1<f64>> = predictions.iter().map(|pi| pi.view()).collect();
+        Ok(concatenate(Axis(0), &aview)?)
+    }
+
+    /// Returns the target and the squared norm of the cost of each `DataSet`.
+    ///
+    /// The sum over all `DataSet`s equals the squared norm of the
+    /// cost vector returned by [`cost`](Self::cost).
+    pub fn cost_per_dataset(&self, eos: &Arc<E>) -> Result<Vec<(String, f64)>, EstimatorError> {
+        Ok(self
             .data
+            .iter()
+            .zip(self.weighted_costs(eos, &DataSetCache::new())?)
+            .map(|(d, c)| (d.target_str().to_string(), c.dot(&c)))
+            .collect())
+    }
+
+    fn weighted_costs(
+        &self,
+        eos: &Arc<E>,
+        cache: &DataSetCache<E>,
+    ) -> Result<Vec<Array1<f64>>, EstimatorError> {
+        let w = arr1(&self.weights) / self.weights.iter().sum::<f64>();
+        self.data
             .iter()
             .enumerate()
-            .map(|(i, d)| Ok(d.cost(eos, self.losses[i])? * w[i]))
-            .collect::<Result<Vec<_>, EstimatorError>>()?;
-        let aview: Vec<ArrayView1<f64>> = predictions.iter().map(|pi| pi.view()).collect();
-        Ok(concatenate(Axis(0), &aview)?)
+            .map(|(i, d)| {
+                let mut cost = d.cost_with_cache(eos, self.losses[i], cache)? * w[i];
+                if let Some(penalty) = self.non_finite_penalty {
+                    cost.mapv_inplace(|c| if c.is_finite() { c } else { penalty });
+                }
+                Ok(cost)
+            })
+            .collect()
     }
 
     /// Returns the properties as computed by the equation of state for each `DataSet`.
     pub fn predict(&self, eos: &Arc<E>) -> Result<Vec<Array1<f64>>, EstimatorError> {
-        self.data.iter().map(|d| d.predict(eos)).collect()
+        let cache = DataSetCache::new();
+        self.data
+            .iter()
+            .map(|d| d.predict_with_cache(eos, &cache))
+            .collect()
     }
 
     /// Returns the relative difference for each `DataSet`.
@@ -76,6 +136,14 @@ impl<E: Residual> Estimator<E> {
             .collect()
     }
 
+    /// Returns the mean absolute difference for each `DataSet`.
+    pub fn mean_absolute_difference(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        self.data
+            .iter()
+            .map(|d| d.mean_absolute_difference(eos))
+            .collect()
+    }
+
     /// Returns the stored `DataSet`s.
     pub fn datasets(&self) -> Vec<Arc<dyn DataSet<E>>> {
         self.data.to_vec()
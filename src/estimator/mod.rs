@@ -5,7 +5,7 @@ use std::num::ParseFloatError;
 use thiserror::Error;
 
 mod dataset;
-pub use dataset::DataSet;
+pub use dataset::{DataSet, DataSetCache};
 #[expect(clippy::module_inception)]
 mod estimator;
 pub use estimator::Estimator;
@@ -50,6 +50,8 @@ impl From<Phase> for DensityInitialization {
 pub enum EstimatorError {
     #[error("Input has not the same amount of data as the target.")]
     IncompatibleInput,
+    #[error("The penalty for non-finite costs has to be finite, got {0}.")]
+    NonFinitePenalty(f64),
     #[error(transparent)]
     ShapeError(#[from] ndarray::ShapeError),
     #[error(transparent)]
@@ -168,6 +168,32 @@ macro_rules! impl_estimator {
                 Ok(self.0.predict(&eos.0)?.view().to_pyarray_bound(py))
             }
 
+            /// Return the property of interest for each data point
+            /// as computed by the equation of state together with
+            /// the target, e.g., for parity plots.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state that is used.
+            ///
+            /// Returns
+            /// -------
+            /// (numpy.ndarray[Float], numpy.ndarray[Float])
+            ///     The prediction and the target.
+            #[pyo3(text_signature = "($self, eos)")]
+            fn predict_vs_target<'py>(
+                &self,
+                eos: &$py_eos,
+                py: Python<'py>,
+            ) -> PyResult<(Bound<'py, PyArray1<f64>>, Bound<'py, PyArray1<f64>>)> {
+                let (prediction, target) = self.0.predict_vs_target(&eos.0)?;
+                Ok((
+                    prediction.view().to_pyarray_bound(py),
+                    target.view().to_pyarray_bound(py),
+                ))
+            }
+
             /// Return the relative difference between experimental data
             /// and prediction of the equation of state.
             ///
@@ -211,6 +237,29 @@ macro_rules! impl_estimator {
                 Ok(self.0.mean_absolute_relative_difference(&eos.0)?)
             }
 
+            /// Return the mean absolute difference.
+            ///
+            /// The mean absolute difference is computed as:
+            ///
+            /// .. math:: \text{MAD} = \frac{1}{N}\sum_{i=1}^{N} \left|x_i^\text{prediction} - x_i^\text{experiment}\right|
+            ///
+            /// In contrast to the mean absolute relative difference, it is
+            /// well-behaved for experimental values close to zero.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state that is used.
+            ///
+            /// Returns
+            /// -------
+            /// Float
+            ///     The mean absolute difference in the units of the target.
+            #[pyo3(text_signature = "($self, eos)")]
+            fn mean_absolute_difference(&self, eos: &$py_eos) -> PyResult<f64> {
+                Ok(self.0.mean_absolute_difference(&eos.0)?)
+            }
+
             /// Create a DataSet with experimental data for vapor pressure.
             ///
             /// Parameters
@@ -484,6 +533,10 @@ macro_rules! impl_estimator {
         ///     the weights are normalized (sum of weights equals unity).
         /// losses : List[Loss]
         ///     The loss functions for each property.
+        /// non_finite_penalty : float, optional
+        ///     If provided, non-finite costs of single data points
+        ///     are replaced by this value. Has to be finite.
+        ///     Defaults to None.
         ///
         /// Returns
         /// -------
@@ -494,13 +547,23 @@ macro_rules! impl_estimator {
         #[pymethods]
         impl PyEstimator {
             #[new]
-            #[pyo3(text_signature = "(data, weights, losses)")]
-            fn new(data: Vec<PyDataSet>, weights: Vec<f64>, losses: Vec<PyLoss>) -> Self {
-                Self(Estimator::new(
+            #[pyo3(text_signature = "(data, weights, losses, non_finite_penalty=None)")]
+            #[pyo3(signature = (data, weights, losses, non_finite_penalty=None))]
+            fn new(
+                data: Vec<PyDataSet>,
+                weights: Vec<f64>,
+                losses: Vec<PyLoss>,
+                non_finite_penalty: Option<f64>,
+            ) -> PyResult<Self> {
+                let estimator = Estimator::new(
                     data.iter().map(|d| d.0.clone()).collect(),
                     weights,
                     losses.iter().map(|l| l.0.clone()).collect(),
-                ))
+                );
+                Ok(Self(match non_finite_penalty {
+                    Some(penalty) => estimator.with_non_finite_penalty(penalty)?,
+                    None => estimator,
+                }))
             }
 
             /// Compute the cost function for each ``DataSet``.
@@ -529,6 +592,29 @@ macro_rules! impl_estimator {
                 Ok(self.0.cost(&eos.0)?.view().to_pyarray_bound(py))
             }
 
+            /// Compute the cost function of each ``DataSet``.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state that is used.
+            ///
+            /// Returns
+            /// -------
+            /// Dict[str, float]
+            ///     The squared norm of the cost of each ``DataSet`` with the
+            ///     target as key. The costs of ``DataSet``s with the same
+            ///     target are added up. The sum of all values equals the
+            ///     squared norm of the result of ``cost``.
+            #[pyo3(text_signature = "($self, eos)")]
+            fn cost_per_dataset(&self, eos: &$py_eos) -> PyResult<HashMap<String, f64>> {
+                let mut costs = HashMap::new();
+                for (target, cost) in self.0.cost_per_dataset(&eos.0)? {
+                    *costs.entry(target).or_insert(0.0) += cost;
+                }
+                Ok(costs)
+            }
+
             /// Return the properties as computed by the
             /// equation of state for each `DataSet`.
             ///
@@ -606,6 +692,34 @@ macro_rules! impl_estimator {
                     .to_pyarray_bound(py))
             }
 
+            /// Return the mean absolute difference for each ``DataSet``.
+            ///
+            /// The mean absolute difference is computed as:
+            ///
+            /// .. math:: \text{MAD} = \frac{1}{N}\sum_{i=1}^{N} \left|x_i^\text{prediction} - x_i^\text{experiment}\right|
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state that is used.
+            ///
+            /// Returns
+            /// -------
+            /// numpy.ndarray[Float]
+            ///     The mean absolute differences in the units of the targets.
+            #[pyo3(text_signature = "($self, eos)")]
+            fn mean_absolute_difference<'py>(
+                &self,
+                eos: &$py_eos,
+                py: Python<'py>,
+            ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+                Ok(self
+                    .0
+                    .mean_absolute_difference(&eos.0)?
+                    .view()
+                    .to_pyarray_bound(py))
+            }
+
             /// Return the stored ``DataSet``s.
             ///
             /// Returns
@@ -672,6 +786,35 @@ macro_rules! impl_estimator_entropy_scaling {
                 )))
             }
 
+            /// Create a DataSet with experimental data for viscosity
+            /// given at specified temperatures and densities.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for viscosity.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// density : SIArray1
+            ///     Molar density for experimental data points.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(target, temperature, density)")]
+            fn viscosity_density(
+                target: quantity::Viscosity<Array1<f64>>,
+                temperature: Temperature<Array1<f64>>,
+                density: Density<Array1<f64>>,
+            ) -> Self {
+                Self(Arc::new($crate::estimator::Viscosity::new_density(
+                    target,
+                    temperature,
+                    density,
+                )))
+            }
+
             /// Create a DataSet with experimental data for thermal conductivity.
             ///
             /// Parameters
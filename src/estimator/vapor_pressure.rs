@@ -1,5 +1,5 @@
-use super::{DataSet, EstimatorError};
-use feos_core::{Contributions, PhaseEquilibrium, ReferenceSystem, Residual, SolverOptions, State};
+use super::{DataSet, DataSetCache, EstimatorError};
+use feos_core::{Contributions, PhaseEquilibrium, ReferenceSystem, Residual, SolverOptions};
 use ndarray::{arr1, Array1};
 use quantity::{Pressure, Temperature, PASCAL};
 use std::sync::Arc;
@@ -67,13 +67,20 @@ impl<E: Residual> DataSet<E> for VaporPressure {
     }
 
     fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        self.predict_with_cache(eos, &DataSetCache::new())
+    }
+
+    fn predict_with_cache(
+        &self,
+        eos: &Arc<E>,
+        cache: &DataSetCache<E>,
+    ) -> Result<Array1<f64>, EstimatorError> {
         if self.datapoints == 0 {
             return Ok(arr1(&[]));
         }
 
         let critical_point =
-            State::critical_point(eos, None, Some(self.max_temperature), self.solver_options)
-                .or_else(|_| State::critical_point(eos, None, None, self.solver_options))?;
+            cache.critical_point(eos, Some(self.max_temperature), self.solver_options)?;
         let tc = critical_point.temperature;
         let pc = critical_point.pressure(Contributions::Total);
 
@@ -1,8 +1,10 @@
 use super::{DataSet, EstimatorError, Phase};
-use feos_core::{DensityInitialization, EntropyScaling, ReferenceSystem, Residual, State};
+use feos_core::{
+    DensityInitialization, EntropyScaling, EosError, EosResult, ReferenceSystem, Residual, State,
+};
 use itertools::izip;
 use ndarray::{arr1, Array1};
-use quantity::{Moles, Pressure, Temperature, MILLI, PASCAL, SECOND};
+use quantity::{Density, Moles, Pressure, Temperature, MILLI, PASCAL, SECOND};
 use std::sync::Arc;
 
 /// Store experimental viscosity data.
@@ -11,8 +13,14 @@ pub struct Viscosity {
     pub target: Array1<f64>,
     unit: quantity::Viscosity,
     temperature: Temperature<Array1<f64>>,
-    pressure: Pressure<Array1<f64>>,
-    initial_density: Vec<DensityInitialization>,
+    specification: Specification,
+}
+
+/// Second state variable (besides temperature) of the data points.
+#[derive(Clone)]
+enum Specification {
+    Pressure(Pressure<Array1<f64>>, Vec<DensityInitialization>),
+    Density(Density<Array1<f64>>),
 }
 
 impl Viscosity {
@@ -24,15 +32,39 @@ impl Viscosity {
         phase: Option<&Vec<Phase>>,
     ) -> Self {
         let n = temperature.len();
+        let initial_density = phase.map_or(vec![DensityInitialization::None; n], |phase| {
+            phase.iter().map(|&p| p.into()).collect()
+        });
+        Self::new_with_specification(
+            target,
+            temperature,
+            Specification::Pressure(pressure, initial_density),
+        )
+    }
+
+    /// Create a new data set for experimental viscosity data given at
+    /// specified temperatures and (molar) densities.
+    ///
+    /// No density iteration is required to evaluate the data points.
+    pub fn new_density(
+        target: quantity::Viscosity<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        density: Density<Array1<f64>>,
+    ) -> Self {
+        Self::new_with_specification(target, temperature, Specification::Density(density))
+    }
+
+    fn new_with_specification(
+        target: quantity::Viscosity<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        specification: Specification,
+    ) -> Self {
         let unit = MILLI * PASCAL * SECOND;
         Self {
             target: (target / unit).into_value(),
             unit,
             temperature,
-            pressure,
-            initial_density: phase.map_or(vec![DensityInitialization::None; n], |phase| {
-                phase.iter().map(|&p| p.into()).collect()
-            }),
+            specification,
         }
     }
 
@@ -41,9 +73,20 @@ impl Viscosity {
         &self.temperature
     }
 
-    /// Return pressure.
-    pub fn pressure(&self) -> &Pressure<Array1<f64>> {
-        &self.pressure
+    /// Return pressure, if the data set is specified via pressures.
+    pub fn pressure(&self) -> Option<&Pressure<Array1<f64>>> {
+        match &self.specification {
+            Specification::Pressure(pressure, _) => Some(pressure),
+            Specification::Density(_) => None,
+        }
+    }
+
+    /// Return density, if the data set is specified via densities.
+    pub fn density(&self) -> Option<&Density<Array1<f64>>> {
+        match &self.specification {
+            Specification::Pressure(..) => None,
+            Specification::Density(density) => Some(density),
+        }
     }
 }
 
@@ -57,19 +100,37 @@ impl<E: Residual + EntropyScaling> DataSet<E> for Viscosity {
     }
 
     fn input_str(&self) -> Vec<&str> {
-        vec!["temperature", "pressure"]
+        match self.specification {
+            Specification::Pressure(..) => vec!["temperature", "pressure"],
+            Specification::Density(_) => vec!["temperature", "density"],
+        }
     }
 
+    /// Data points for which the state or the viscosity can not
+    /// be calculated are set to `NAN`, which can be replaced by a penalty
+    /// using [`Estimator::with_non_finite_penalty`](super::Estimator::with_non_finite_penalty).
+    ///
+    /// Returns an error if the viscosity correlation is not available for
+    /// the equation of state, e.g., if the parameters are missing.
     fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
         let moles = Moles::from_reduced(arr1(&[1.0]));
-        izip!(&self.temperature, &self.pressure, &self.initial_density)
-            .map(|(t, p, &initial_density)| {
-                State::new_npt(eos, t, p, &moles, initial_density)?
-                    .viscosity()
-                    .map(|viscosity| viscosity.convert_to(self.unit))
-                    .map_err(EstimatorError::from)
-            })
-            .collect()
+        let viscosity = |state: EosResult<State<E>>| match state.and_then(|s| s.viscosity()) {
+            Ok(viscosity) => Ok(viscosity.convert_to(self.unit)),
+            Err(EosError::ParameterError(e)) => Err(EstimatorError::from(EosError::from(e))),
+            Err(_) => Ok(f64::NAN),
+        };
+        match &self.specification {
+            Specification::Pressure(pressure, initial_density) => {
+                izip!(&self.temperature, pressure, initial_density)
+                    .map(|(t, p, &initial_density)| {
+                        viscosity(State::new_npt(eos, t, p, &moles, initial_density))
+                    })
+                    .collect()
+            }
+            Specification::Density(density) => izip!(&self.temperature, density)
+                .map(|(t, rho)| viscosity(State::new_nvt(eos, t, moles.sum() / rho, &moles)))
+                .collect(),
+        }
     }
 
     // fn get_input(&self) -> HashMap<String, SIArray1> {
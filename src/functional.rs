@@ -41,6 +41,27 @@ pub enum FunctionalVariant {
     SaftVRQMie(SaftVRQMieFunctional),
 }
 
+impl FunctionalVariant {
+    /// Identifiers of all components in the order used by the functional.
+    ///
+    /// Returns `None` for functionals that are not constructed from
+    /// pure-component records.
+    pub fn component_identifiers(&self) -> Option<Vec<parameter::Identifier>> {
+        match self {
+            #[cfg(feature = "pcsaft")]
+            Self::PcSaft(functional) => Some(
+                functional
+                    .parameters
+                    .pure_records
+                    .iter()
+                    .map(|r| r.identifier.clone())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+}
+
 #[derive(FunctionalContribution)]
 pub enum FunctionalContributionVariant {
     #[cfg(feature = "pcsaft")]
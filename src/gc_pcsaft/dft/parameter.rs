@@ -83,7 +83,8 @@ impl ParameterHetero for GcPcSaftFunctionalParameters {
                     segment
                         .model_record
                         .association_record
-                        .into_iter()
+                        .iter()
+                        .cloned()
                         .collect(),
                 );
 
@@ -109,6 +109,10 @@ impl Residual for GcPcSaft {
             / (FRAC_PI_6 * &p.m * p.sigma.mapv(|v| v.powi(3)) * moles_segments).sum()
     }
 
+    fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+        Some(self.parameters.zeta(temperature, partial_density, [3])[0])
+    }
+
     fn residual_helmholtz_energy_contributions<D: num_dual::DualNum<f64> + Copy>(
         &self,
         state: &feos_core::StateHD<D>,
@@ -5,10 +5,10 @@ use feos_core::parameter::{
     BinaryRecord, ChemicalRecord, Identifier, ParameterError, ParameterHetero, SegmentCount,
     SegmentRecord,
 };
-use quantity::{JOULE, KB, KELVIN};
 use indexmap::IndexMap;
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
+use quantity::{JOULE, KB, KELVIN};
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Write;
@@ -146,12 +146,8 @@ impl ParameterHetero for GcPcSaftEosParameters {
                 sigma.push(segment.model_record.sigma);
                 epsilon_k.push(segment.model_record.epsilon_k);
 
-                let mut assoc = segment.model_record.association_record;
-                if let Some(assoc) = assoc.as_mut() {
-                    assoc.na *= count;
-                    assoc.nb *= count;
-                };
-                association_records.push(assoc.into_iter().collect());
+                let assoc = segment.model_record.association_record.as_ref();
+                association_records.push(assoc.map(|a| a.scale(count)).into_iter().collect());
 
                 m_i += segment.model_record.m * count;
                 sigma_i += segment.model_record.m * segment.model_record.sigma.powi(3) * count;
@@ -353,7 +349,7 @@ impl GcPcSaftEosParameters {
                 )
             };
             let record = gorup_dict[&self.identifiers[i]];
-            let association = if let Some(a) = record.association_record {
+            let association = if let Some(a) = &record.association_record {
                 format!(
                     "{}|{}|{}|{}|{}",
                     a.parameters.kappa_ab, a.parameters.epsilon_k_ab, a.na, a.nb, a.nc
@@ -435,6 +431,7 @@ impl std::fmt::Display for GcPcSaftEosParameters {
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::association::AssociationSiteType;
     use feos_core::parameter::{ChemicalRecord, Identifier};
 
     fn ch3() -> SegmentRecord<GcPcSaftRecord> {
@@ -546,4 +543,29 @@ pub mod test {
             (181.49f64 * 334.29).sqrt() * 1.0087
         );
     }
+
+    #[test]
+    fn test_generic_sites_count() {
+        let mut oh = oh();
+        let association = oh.model_record.association_record.as_mut().unwrap();
+        (association.na, association.nb) = (0.0, 0.0);
+        association.sites = vec![
+            AssociationSiteType::new(1, 1.0, vec![2]),
+            AssociationSiteType::new(2, 1.0, vec![]),
+        ];
+        let diol = ChemicalRecord::new(
+            Identifier::new(None, Some("ethane-1,2-diol"), None, None, None, None),
+            vec!["OH".into(), "CH2".into(), "CH2".into(), "OH".into()],
+            None,
+        );
+        let params =
+            GcPcSaftEosParameters::from_segments(vec![diol], vec![ch2(), oh], None).unwrap();
+        let counts: Vec<_> = params
+            .association
+            .sites()
+            .into_iter()
+            .map(|s| s.2)
+            .collect();
+        assert_eq!(counts, vec![2.0, 2.0]);
+    }
 }
@@ -24,3 +24,54 @@ pub enum IdealGasModel {
     #[cfg(feature = "python")]
     Python(PyIdealGas),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feos_core::cubic::{PengRobinson, PengRobinsonParameters};
+    use feos_core::parameter::{Identifier, Parameter, PureRecord};
+    use feos_core::{Contributions, EosResult, EquationOfState, State};
+    use ndarray::arr1;
+    use quantity::{KELVIN, LITER, MOL};
+
+    #[test]
+    fn swap_ideal_gas_model() -> EosResult<()> {
+        let parameters =
+            PengRobinsonParameters::new_simple(&[369.96], &[4.25e6], &[0.153], &[44.0962])?;
+        let residual = Arc::new(PengRobinson::new(Arc::new(parameters)));
+
+        let joback = PureRecord::new(
+            Identifier::default(),
+            0.0,
+            JobackRecord::new(-5.24, 0.3059, -1.587e-4, 3.215e-8, 0.0),
+        );
+        let dippr = PureRecord::new(
+            Identifier::default(),
+            0.0,
+            DipprRecord::eq100(&[276370., -2090.1, 8.125, -0.014116, 0.0000093701]),
+        );
+        let joback = IdealGasModel::Joback(Arc::new(Joback::new_pure(joback)?));
+        let dippr = IdealGasModel::Dippr(Arc::new(Dippr::new_pure(dippr)?));
+        let eos_joback = Arc::new(EquationOfState::new(Arc::new(joback), residual.clone()));
+        let eos_dippr = Arc::new(EquationOfState::new(Arc::new(dippr), residual));
+
+        let temperature = 300.0 * KELVIN;
+        let volume = 10.0 * LITER;
+        let moles = arr1(&[1.0]) * MOL;
+        let state_joback = State::new_nvt(&eos_joback, temperature, volume, &moles)?;
+        let state_dippr = State::new_nvt(&eos_dippr, temperature, volume, &moles)?;
+        assert_eq!(
+            state_joback.pressure(Contributions::Total),
+            state_dippr.pressure(Contributions::Total)
+        );
+        assert_eq!(
+            state_joback.molar_enthalpy(Contributions::Residual),
+            state_dippr.molar_enthalpy(Contributions::Residual)
+        );
+        assert!(
+            state_joback.molar_enthalpy(Contributions::Total)
+                != state_dippr.molar_enthalpy(Contributions::Total)
+        );
+        Ok(())
+    }
+}
@@ -132,6 +132,10 @@ impl Molarweight for PcSaftFunctional {
     fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
         self.parameters.molarweight.clone() * GRAM / MOL
     }
+
+    fn has_molar_weight(&self) -> bool {
+        self.parameters.has_molar_weight()
+    }
 }
 
 impl FluidParameters for PcSaftFunctional {
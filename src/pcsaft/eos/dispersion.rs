@@ -177,4 +177,15 @@ mod tests {
         assert_relative_eq!(a1, a1m, epsilon = 1e-14);
         assert_relative_eq!(a2, a2m, epsilon = 1e-14);
     }
+
+    #[test]
+    fn verify_derivatives() -> feos_core::EosResult<()> {
+        let eos = crate::pcsaft::PcSaft::new(propane_butane_parameters());
+        let s = StateHD::new(250.0, 1000.0, arr1(&[1.5, 2.5]));
+        assert!(matches!(
+            feos_core::dev::verify_derivatives(&eos, "Polar", &s),
+            Err(feos_core::EosError::UnknownContribution(_))
+        ));
+        feos_core::dev::verify_derivatives(&eos, "Dispersion", &s)
+    }
 }
@@ -1,12 +1,12 @@
 use super::parameters::PcSaftParameters;
 use crate::association::Association;
 use crate::hard_sphere::{HardSphere, HardSphereProperties};
-use feos_core::parameter::Parameter;
+use feos_core::parameter::{Parameter, ParameterError};
 use feos_core::{
-    Components, EntropyScaling, EosError, EosResult, Molarweight, ReferenceSystem, Residual, State,
-    StateHD,
+    validate_residual_entropy, Components, EntropyScaling, EosError, EosResult, Molarweight,
+    ReferenceSystem, Residual, State, StateHD,
 };
-use ndarray::Array1;
+use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use quantity::*;
 use std::f64::consts::{FRAC_PI_6, PI};
@@ -17,6 +17,7 @@ use typenum::P2;
 pub(crate) mod dispersion;
 pub(crate) mod hard_chain;
 pub(crate) mod polar;
+mod simplified;
 use dispersion::Dispersion;
 use hard_chain::HardChain;
 pub use polar::DQVariants;
@@ -29,6 +30,12 @@ pub struct PcSaftOptions {
     pub max_iter_cross_assoc: usize,
     pub tol_cross_assoc: f64,
     pub dq_variant: DQVariants,
+    /// Use the averaged segment diameter of the simplified PC-SAFT
+    /// ([von Solms et al., 2003](https://doi.org/10.1021/ie020753p))
+    /// in the hard-sphere and hard-chain contributions.
+    ///
+    /// Only affects the equation of state, not the Helmholtz energy functional.
+    pub simplified: bool,
 }
 
 impl Default for PcSaftOptions {
@@ -38,11 +45,52 @@ impl Default for PcSaftOptions {
             max_iter_cross_assoc: 50,
             tol_cross_assoc: 1e-10,
             dq_variant: DQVariants::DQ35,
+            simplified: false,
         }
     }
 }
 
 /// PC-SAFT equation of state.
+///
+/// # Example: evaluating a single Helmholtz energy contribution
+///
+/// Contributions can be evaluated directly for a [StateHD] in reduced units
+/// (temperature in K, volume in Å³ and number of molecules), using any dual
+/// number type to obtain derivatives.
+/// ```
+/// # use feos_core::EosError;
+/// use feos::pcsaft::{PcSaft, PcSaftParameters};
+/// use feos_core::parameter::{IdentifierOption, Parameter};
+/// use feos_core::{Residual, StateHD};
+/// use ndarray::arr1;
+/// use num_dual::{Dual64, DualNum};
+/// use std::sync::Arc;
+///
+/// let parameters = PcSaftParameters::from_json(
+///     vec!["propane"],
+///     "tests/pcsaft/test_parameters.json",
+///     None,
+///     IdentifierOption::Name,
+/// )?;
+/// let saft = PcSaft::new(Arc::new(parameters));
+///
+/// // Derivative with respect to the volume.
+/// let temperature = Dual64::from(300.0);
+/// let volume = Dual64::from(1000.0).derivative();
+/// let moles = arr1(&[Dual64::from(1.0)]);
+/// let state = StateHD::new(temperature, volume, moles);
+///
+/// let (_, a_disp) = saft
+///     .residual_helmholtz_energy_contributions(&state)
+///     .into_iter()
+///     .find(|(name, _)| name == "Dispersion")
+///     .unwrap();
+/// assert!(a_disp.re() < 0.0);
+/// // The dispersion contribution to the reduced pressure.
+/// let p_disp = -a_disp.eps;
+/// assert!(p_disp < 0.0);
+/// # Ok::<(), EosError>(())
+/// ```
 pub struct PcSaft {
     parameters: Arc<PcSaftParameters>,
     options: PcSaftOptions,
@@ -118,6 +166,39 @@ impl PcSaft {
             association,
         }
     }
+
+    /// The parameters of the equation of state.
+    pub fn parameters(&self) -> &Arc<PcSaftParameters> {
+        &self.parameters
+    }
+
+    /// Fractions of non-bonded association sites in the given state.
+    ///
+    /// The iterative solver is controlled by `max_iter_cross_assoc` and
+    /// `tol_cross_assoc` in [PcSaftOptions]. Returns an empty array if
+    /// none of the components is associating.
+    pub fn association_monomer_fractions(&self, state: &StateHD<f64>) -> EosResult<Array1<f64>> {
+        match self.association.as_ref() {
+            Some(association) => association
+                .monomer_fractions(state, &self.parameters.hs_diameter(state.temperature)),
+            None => Ok(Array1::zeros(0)),
+        }
+    }
+
+    /// The options used to construct the equation of state.
+    pub fn options(&self) -> PcSaftOptions {
+        self.options
+    }
+
+    /// Return a copy of the equation of state that uses the given
+    /// combination rule in the dipole-quadrupole contribution.
+    pub fn with_dq_variant(&self, dq_variant: DQVariants) -> Self {
+        let options = PcSaftOptions {
+            dq_variant,
+            ..self.options
+        };
+        Self::with_options(self.parameters.clone(), options)
+    }
 }
 
 impl Components for PcSaft {
@@ -140,6 +221,10 @@ impl Residual for PcSaft {
                 .sum()
     }
 
+    fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+        Some(self.parameters.zeta(temperature, partial_density, [3])[0])
+    }
+
     fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy>(
         &self,
         state: &StateHD<D>,
@@ -147,12 +232,25 @@ impl Residual for PcSaft {
         let mut v = Vec::with_capacity(7);
         let d = self.parameters.hs_diameter(state.temperature);
 
-        v.push((
-            self.hard_sphere.to_string(),
-            self.hard_sphere.helmholtz_energy(state),
-        ));
-        if let Some(hc) = self.hard_chain.as_ref() {
-            v.push((hc.to_string(), hc.helmholtz_energy(state)))
+        if self.options.simplified {
+            v.push((
+                self.hard_sphere.to_string(),
+                simplified::hard_sphere(&self.parameters, state),
+            ));
+            if let Some(hc) = self.hard_chain.as_ref() {
+                v.push((
+                    hc.to_string(),
+                    simplified::hard_chain(&self.parameters, state),
+                ))
+            }
+        } else {
+            v.push((
+                self.hard_sphere.to_string(),
+                self.hard_sphere.helmholtz_energy(state),
+            ));
+            if let Some(hc) = self.hard_chain.as_ref() {
+                v.push((hc.to_string(), hc.helmholtz_energy(state)))
+            }
         }
         v.push((
             self.dispersion.to_string(),
@@ -187,6 +285,10 @@ impl Molarweight for PcSaft {
     fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
         self.parameters.molarweight.clone() * GRAM / MOL
     }
+
+    fn has_molar_weight(&self) -> bool {
+        self.parameters.has_molar_weight()
+    }
 }
 
 impl fmt::Display for PcSaft {
@@ -224,6 +326,31 @@ fn chapman_enskog_thermal_conductivity(
         / KELVIN
 }
 
+impl PcSaft {
+    /// Chapman-Enskog viscosities of the pure components.
+    fn chapman_enskog_viscosity(&self, temperature: Temperature) -> Viscosity<Array1<f64>> {
+        let p = &self.parameters;
+        Viscosity::from_shape_fn(self.components(), |i| {
+            let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
+            5.0 / 16.0 * (p.molarweight[i] * GRAM / MOL * KB / NAV * temperature / PI).sqrt()
+                / omega22(tr)
+                / (p.sigma[i] * ANGSTROM).powi::<P2>()
+        })
+    }
+
+    /// Interaction factors of Mason and Saxena used to mix dilute gas
+    /// transport properties.
+    fn mason_saxena_factors(&self, viscosity: &Viscosity<Array1<f64>>) -> Array2<f64> {
+        let mw = &self.parameters.molarweight;
+        Array2::from_shape_fn([self.components(); 2], |(i, j)| {
+            (1.0 + (viscosity.get(i) / viscosity.get(j)).into_value().sqrt()
+                * (mw[j] / mw[i]).powf(1.0 / 4.0))
+            .powi(2)
+                / (8.0 * (1.0 + mw[i] / mw[j])).sqrt()
+        })
+    }
+}
+
 impl EntropyScaling for PcSaft {
     fn viscosity_reference(
         &self,
@@ -231,38 +358,22 @@ impl EntropyScaling for PcSaft {
         _: Volume,
         moles: &Moles<Array1<f64>>,
     ) -> EosResult<Viscosity> {
-        let p = &self.parameters;
-        let mw = &p.molarweight;
         let x = (moles / moles.sum()).into_value();
-        let ce: Array1<_> = (0..self.components())
-            .map(|i| {
-                let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
-                5.0 / 16.0 * (mw[i] * GRAM / MOL * KB / NAV * temperature / PI).sqrt()
-                    / omega22(tr)
-                    / (p.sigma[i] * ANGSTROM).powi::<P2>()
-            })
-            .collect();
+        let ce = self.chapman_enskog_viscosity(temperature);
+        let phi = self.mason_saxena_factors(&ce);
         let mut ce_mix = 0.0 * MILLI * PASCAL * SECOND;
         for i in 0..self.components() {
-            let denom: f64 = (0..self.components())
-                .map(|j| {
-                    x[j] * (1.0
-                        + (ce[i] / ce[j]).into_value().sqrt() * (mw[j] / mw[i]).powf(1.0 / 4.0))
-                    .powi(2)
-                        / (8.0 * (1.0 + mw[i] / mw[j])).sqrt()
-                })
-                .sum();
-            ce_mix += ce[i] * x[i] / denom
+            ce_mix += ce.get(i) * x[i] / phi.row(i).dot(&x);
         }
         Ok(ce_mix)
     }
 
     fn viscosity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
-        let coefficients = self
-            .parameters
-            .viscosity
-            .as_ref()
-            .expect("Missing viscosity coefficients.");
+        validate_residual_entropy("viscosity", s_res)?;
+        let coefficients =
+            self.parameters.viscosity.as_ref().ok_or_else(|| {
+                ParameterError::MissingParameters("viscosity coefficients".into())
+            })?;
         let m = (x * &self.parameters.m).sum();
         let s = s_res / m;
         let pref = (x * &self.parameters.m) / m;
@@ -295,14 +406,14 @@ impl EntropyScaling for PcSaft {
     }
 
     fn diffusion_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        validate_residual_entropy("diffusion", s_res)?;
         if self.components() != 1 {
             return Err(EosError::IncompatibleComponents(self.components(), 1));
         }
-        let coefficients = self
-            .parameters
-            .diffusion
-            .as_ref()
-            .expect("Missing diffusion coefficients.");
+        let coefficients =
+            self.parameters.diffusion.as_ref().ok_or_else(|| {
+                ParameterError::MissingParameters("diffusion coefficients".into())
+            })?;
         let m = (x * &self.parameters.m).sum();
         let s = s_res / m;
         let pref = (x * &self.parameters.m).mapv(|v| v / m);
@@ -315,51 +426,53 @@ impl EntropyScaling for PcSaft {
     }
 
     // Equation 4 of DOI: 10.1021/acs.iecr.9b04289
+    // For mixtures, the Chapman-Enskog contributions are combined using the
+    // Wassiljewa equation with Mason-Saxena factors and the residual entropy
+    // is reduced with the mean segment number.
     fn thermal_conductivity_reference(
         &self,
         temperature: Temperature,
         volume: Volume,
         moles: &Moles<Array1<f64>>,
     ) -> EosResult<ThermalConductivity> {
-        if self.components() != 1 {
-            return Err(EosError::IncompatibleComponents(self.components(), 1));
-        }
         let p = &self.parameters;
         let mws = self.molar_weight();
+        let x = (moles / moles.sum()).into_value();
         let state = State::new_nvt(&Arc::new(Self::new(p.clone())), temperature, volume, moles)?;
-        let res: Array1<_> = (0..self.components())
-            .map(|i| {
-                let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
-                let s_res_reduced = state.residual_molar_entropy().to_reduced() / p.m[i];
-                let ref_ce = chapman_enskog_thermal_conductivity(
-                    temperature,
-                    mws.get(i),
-                    p.m[i],
-                    p.sigma[i],
-                    p.epsilon_k[i],
-                );
-                let alpha_visc = (-s_res_reduced / -0.5).exp();
-                let ref_ts = (-0.0167141 * tr / p.m[i] + 0.0470581 * (tr / p.m[i]).powi(2))
-                    * (p.m[i] * p.m[i] * p.sigma[i].powi(3) * p.epsilon_k[i])
-                    * 1e-5
-                    * WATT
-                    / METER
-                    / KELVIN;
-                ref_ce + ref_ts * alpha_visc
-            })
-            .collect();
-        Ok(res[0])
+        let m = (&x * &p.m).sum();
+        let s_res_reduced = state.residual_molar_entropy().to_reduced() / m;
+        let alpha_visc = (-s_res_reduced / -0.5).exp();
+        let phi = self.mason_saxena_factors(&self.chapman_enskog_viscosity(temperature));
+        let mut res = 0.0 * WATT / METER / KELVIN;
+        for i in 0..self.components() {
+            let tr = (temperature / p.epsilon_k[i] / KELVIN).into_value();
+            let ref_ce = chapman_enskog_thermal_conductivity(
+                temperature,
+                mws.get(i),
+                p.m[i],
+                p.sigma[i],
+                p.epsilon_k[i],
+            );
+            let ref_ts = (-0.0167141 * tr / p.m[i] + 0.0470581 * (tr / p.m[i]).powi(2))
+                * (p.m[i] * p.m[i] * p.sigma[i].powi(3) * p.epsilon_k[i])
+                * 1e-5
+                * WATT
+                / METER
+                / KELVIN;
+            res += ref_ce * x[i] / phi.row(i).dot(&x) + ref_ts * x[i] * alpha_visc;
+        }
+        Ok(res)
     }
 
     fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
-        if self.components() != 1 {
-            return Err(EosError::IncompatibleComponents(self.components(), 1));
-        }
+        validate_residual_entropy("thermal conductivity", s_res)?;
         let coefficients = self
             .parameters
             .thermal_conductivity
             .as_ref()
-            .expect("Missing thermal conductivity coefficients");
+            .ok_or_else(|| {
+                ParameterError::MissingParameters("thermal conductivity coefficients".into())
+            })?;
         let m = (x * &self.parameters.m).sum();
         let s = s_res / m;
         let pref = (x * &self.parameters.m).mapv(|v| v / m);
@@ -374,8 +487,10 @@ impl EntropyScaling for PcSaft {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ideal_gas::{Joback, JobackRecord};
     use crate::pcsaft::parameters::utils::{
-        butane_parameters, propane_butane_parameters, propane_parameters, water_parameters,
+        butane_parameters, dme_co2_parameters, propane_butane_parameters, propane_parameters,
+        water_parameters,
     };
     use approx::assert_relative_eq;
     use feos_core::*;
@@ -383,6 +498,75 @@ mod tests {
     use quantity::{BAR, KELVIN, METER, MILLI, PASCAL, RGAS, SECOND};
     use typenum::P3;
 
+    #[test]
+    fn simplified() -> EosResult<()> {
+        let simplified = PcSaftOptions {
+            simplified: true,
+            ..Default::default()
+        };
+
+        // for pure components, the simplified PC-SAFT is identical to PC-SAFT
+        let state = StateHD::new(300.0, 1e3, arr1(&[1.0]));
+        let a = PcSaft::new(propane_parameters()).residual_helmholtz_energy(&state);
+        let a_simplified = PcSaft::with_options(propane_parameters(), simplified)
+            .residual_helmholtz_energy(&state);
+        assert_relative_eq!(a, a_simplified, max_relative = 1e-14);
+
+        // ... also if shape factors are used in the hard-sphere contribution
+        let shaped = Arc::new(propane_parameters().with_shape_factor(arr1(&[1.5]))?);
+        let a = PcSaft::new(shaped.clone()).residual_helmholtz_energy(&state);
+        let a_simplified =
+            PcSaft::with_options(shaped, simplified).residual_helmholtz_energy(&state);
+        assert_relative_eq!(a, a_simplified, max_relative = 1e-14);
+
+        let parameters = Arc::new(dme_co2_parameters());
+        let density = |options| {
+            let eos = Arc::new(PcSaft::with_options(parameters.clone(), options));
+            State::new_npt(
+                &eos,
+                250.0 * KELVIN,
+                50.0 * BAR,
+                &(arr1(&[0.5, 0.5]) * MOL),
+                DensityInitialization::Liquid,
+            )
+            .map(|s| s.density)
+        };
+        // for mixtures, the liquid densities differ slightly
+        let rho = density(PcSaftOptions::default())?;
+        let rho_simplified = density(simplified)?;
+        let deviation = ((rho_simplified - rho) / rho).into_value().abs();
+        assert!(deviation > 1e-4 && deviation < 1e-2);
+        Ok(())
+    }
+
+    #[test]
+    fn shape_factor() {
+        let params = propane_parameters();
+        let contributions = |params: Arc<PcSaftParameters>| {
+            let saft = PcSaft::new(params);
+            let state = StateHD::new(300.0, 1e4, arr1(&[1.0]));
+            saft.residual_helmholtz_energy_contributions(&state)
+        };
+        let reference = contributions(params.clone());
+        let modified = contributions(Arc::new(params.with_shape_factor(arr1(&[1.5])).unwrap()));
+        let find = |contributions: &[(String, f64)], name: &str| {
+            contributions
+                .iter()
+                .find_map(|(n, a)| (n == name).then_some(*a))
+                .unwrap()
+        };
+        let hs = find(&reference, "Hard Sphere");
+        assert!((find(&modified, "Hard Sphere") - hs).abs() > 1e-3 * hs.abs());
+        assert_eq!(
+            find(&reference, "Dispersion"),
+            find(&modified, "Dispersion")
+        );
+        assert!(params.with_shape_factor(arr1(&[1.0, 2.0])).is_err());
+        for s in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(params.with_shape_factor(arr1(&[s])).is_err());
+        }
+    }
+
     #[test]
     fn ideal_gas_pressure() {
         let e = Arc::new(PcSaft::new(propane_parameters()));
@@ -472,6 +656,77 @@ mod tests {
         assert_relative_eq!(a_rust, -4.229878997054543, epsilon = 1e-10);
     }
 
+    #[test]
+    fn dq_variants() {
+        let dq35 = Arc::new(PcSaft::new(Arc::new(dme_co2_parameters())));
+        let dq44 = Arc::new(dq35.with_dq_variant(DQVariants::DQ44));
+        assert_eq!(dq35.options().dq_variant, DQVariants::DQ35);
+        assert_eq!(dq44.options().dq_variant, DQVariants::DQ44);
+        let t = 300.0 * KELVIN;
+        let v = 1e-3 * METER.powi::<P3>();
+        let n = arr1(&[1.0, 1.0]) * MOL;
+        let s35 = State::new_nvt(&dq35, t, v, &n).unwrap();
+        let s44 = State::new_nvt(&dq44, t, v, &n).unwrap();
+        let p35 = s35.pressure(Contributions::Total);
+        let p44 = s44.pressure(Contributions::Total);
+        assert!((p35 - p44).abs() > 1e-6 * p35.abs());
+        let contributions = s35.pressure_contributions();
+        for ((name, p35), (_, p44)) in contributions.iter().zip(s44.pressure_contributions()) {
+            if name != "DipoleQuadrupole" {
+                assert_relative_eq!(*p35, p44, max_relative = 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn gibbs_duhem_residual() {
+        let eos = Arc::new(PcSaft::new(Arc::new(dme_co2_parameters())));
+        let t = 300.0 * KELVIN;
+        let p = 50.0 * BAR;
+        let n = arr1(&[0.3, 0.7]) * MOL;
+        let s = State::new_npt(&eos, t, p, &n, DensityInitialization::Liquid).unwrap();
+        assert!(s.gibbs_duhem_residual() < 1e-12);
+    }
+
+    #[test]
+    fn packing_fraction() {
+        let eos = Arc::new(PcSaft::new(propane_parameters()));
+        let t = 250.0 * KELVIN;
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_npt(&eos, t, BAR, &n, DensityInitialization::Liquid).unwrap();
+        let eta = s.packing_fraction().unwrap();
+        assert!(eta > 0.3 && eta < 0.5);
+
+        let p = &eos.parameters;
+        let d = p.hs_diameter(t.to_reduced());
+        let eta_ref = FRAC_PI_6 * p.m[0] * d[0].powi(3) * s.density.to_reduced();
+        assert_relative_eq!(eta, eta_ref, max_relative = 1e-12);
+
+        let s = State::new_nvt(&eos, t, 0.5 * s.volume, &n).unwrap();
+        assert!(s.packing_fraction().unwrap() > 0.5);
+    }
+
+    #[test]
+    fn self_consistency_check() {
+        let eos = Arc::new(PcSaft::new(propane_parameters()));
+        let t = 250.0 * KELVIN;
+        let p = 50.0 * BAR;
+        let n = arr1(&[1.0]) * MOL;
+        let s = State::new_npt(&eos, t, p, &n, DensityInitialization::Liquid).unwrap();
+        assert!(s.is_liquid().unwrap());
+        assert!(s.self_consistency_check() < 1e-6);
+
+        let eos = Arc::new(PcSaft::new(Arc::new(dme_co2_parameters())));
+        let n = arr1(&[0.3, 0.7]) * MOL;
+        let s = State::new_npt(&eos, t, p, &n, DensityInitialization::Liquid).unwrap();
+        assert!(s.self_consistency_check() < 1e-6);
+
+        // mole numbers below the finite difference step
+        let n = arr1(&[1e-8, 1.0]) * MOL;
+        let s = State::new_npt(&eos, t, p, &n, DensityInitialization::Liquid).unwrap();
+        assert!(s.self_consistency_check() < 1e-6);
+    }
+
     #[test]
     fn new_tpn() {
         let e = Arc::new(PcSaft::new(propane_parameters()));
@@ -580,4 +835,107 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn association_solver_options() -> EosResult<()> {
+        let parameters = Arc::new(water_parameters());
+        let options = |max_iter_cross_assoc, tol_cross_assoc| PcSaftOptions {
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+            ..Default::default()
+        };
+        let state = StateHD::new(350.0, 41.248289328513216, arr1(&[1.23]));
+
+        let loose = PcSaft::with_options(parameters.clone(), options(50, 1e-3));
+        let tight = PcSaft::with_options(parameters.clone(), options(50, 1e-12));
+        let x_loose = loose.association_monomer_fractions(&state)?;
+        let x_tight = tight.association_monomer_fractions(&state)?;
+        assert_ne!(x_loose, x_tight);
+        assert_relative_eq!(x_loose, x_tight, max_relative = 1e-3);
+
+        let capped = PcSaft::with_options(parameters, options(1, 1e-12));
+        match capped.association_monomer_fractions(&state) {
+            Err(EosError::NotConverged(msg)) => assert!(msg.contains("residual")),
+            r => panic!("expected NotConverged, got {r:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn thermal_conductivity_mixture() -> EosResult<()> {
+        // use the coefficients of propane for butane
+        let mut records = propane_butane_parameters().pure_records.clone();
+        records[1].model_record.thermal_conductivity = records[0].model_record.thermal_conductivity;
+        let parameters = Arc::new(PcSaftParameters::new_binary(records, None)?);
+        let t = 300.0 * KELVIN;
+        let p = 20.0 * BAR;
+        let lambda = |component_list: &[usize], x: Array1<f64>| -> EosResult<_> {
+            let e = Arc::new(PcSaft::new(Arc::new(parameters.subset(component_list))));
+            let s = State::new_npt(&e, t, p, &(x * MOL), DensityInitialization::Liquid)?;
+            s.thermal_conductivity()
+        };
+        let lambda_propane = lambda(&[0], arr1(&[1.0]))?;
+        let lambda_butane = lambda(&[1], arr1(&[1.0]))?;
+        let lambda_mix = lambda(&[0, 1], arr1(&[0.5, 0.5]))?;
+        assert_relative_eq!(
+            lambda(&[0, 1], arr1(&[1.0, 0.0]))?,
+            lambda_propane,
+            max_relative = 1e-10
+        );
+        assert!(lambda_propane < lambda_mix && lambda_mix < lambda_butane);
+        Ok(())
+    }
+
+    #[test]
+    fn entropy_scaling_missing_coefficients() {
+        let e = PcSaft::new(butane_parameters());
+        let x = arr1(&[1.0]);
+        for result in [
+            e.viscosity_correlation(-1.0, &x),
+            e.diffusion_correlation(-1.0, &x),
+            e.thermal_conductivity_correlation(-1.0, &x),
+        ] {
+            assert!(matches!(
+                result,
+                Err(EosError::ParameterError(ParameterError::MissingParameters(
+                    _
+                )))
+            ));
+        }
+    }
+
+    #[test]
+    fn entropy_scaling_invalid_residual_entropy() -> EosResult<()> {
+        let e = PcSaft::new(propane_parameters());
+        let x = arr1(&[1.0]);
+        for s_res in [0.1, f64::NAN] {
+            assert!(matches!(
+                e.viscosity_correlation(s_res, &x),
+                Err(EosError::InvalidState(_, _, _))
+            ));
+            assert!(e.diffusion_correlation(s_res, &x).is_err());
+            assert!(e.thermal_conductivity_correlation(s_res, &x).is_err());
+        }
+        assert_eq!(
+            e.viscosity_correlation(0.1, &x).unwrap_err().to_string(),
+            "Invalid state in viscosity correlation: s_res = 0.1."
+        );
+
+        // with only the hard-chain contribution, the residual entropy
+        // of a very dilute gas is positive
+        let t = 300.0 * KELVIN;
+        let n = arr1(&[1.0]) * MOL;
+        let joback = Joback::from_model_records(vec![JobackRecord::new(1.0, 0.0, 0.0, 0.0, 0.0)])?;
+        let eos = EquationOfState::new(Arc::new(joback), Arc::new(e));
+        let eos = Arc::new(
+            eos.without_contribution("Hard Sphere")?
+                .without_contribution("Dispersion")?,
+        );
+        let s = State::new_npt(&eos, t, 1e-6 * BAR, &n, DensityInitialization::Vapor)?;
+        assert!(matches!(
+            s.viscosity(),
+            Err(EosError::InvalidState(_, _, _))
+        ));
+        Ok(())
+    }
 }
@@ -326,10 +326,20 @@ impl fmt::Display for Quadrupole {
 }
 
 /// Different combination rules used in the dipole-quadrupole contribution.
-#[derive(Clone, Copy, PartialEq)]
+///
+/// The variants differ in the powers of the segment diameters that
+/// multiply the reduced squared dipole moment $\mu_i^{*2}$ of component $i$
+/// and the reduced squared quadrupole moment $Q_j^{*2}$ of component $j$
+/// in the second-order term of
+/// [Vrabec and Gross (2008)](https://doi.org/10.1021/jp072619u).
+#[derive(Clone, Copy, PartialEq, Debug)]
 #[cfg_attr(feature = "python", pyo3::pyclass(eq))]
 pub enum DQVariants {
+    /// $\sigma_i^3\sigma_j^5$, so that the term only depends on the
+    /// unreduced moments $\mu_i^2Q_j^2$.
     DQ35,
+    /// $\sigma_i^4\sigma_j^4$, which results in an additional factor
+    /// $\sigma_i/\sigma_j$ compared to `DQ35`.
     DQ44,
 }
 
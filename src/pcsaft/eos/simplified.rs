@@ -0,0 +1,55 @@
+//! Hard-sphere and hard-chain contributions of the simplified PC-SAFT
+//! ([von Solms et al., 2003](https://doi.org/10.1021/ie020753p)).
+//!
+//! All segments are replaced by segments with the averaged diameter
+//! $\bar d^3=\frac{\sum_ix_im_id_i^3}{\sum_ix_im_i}$, which leaves the
+//! packing fraction $\eta$ unchanged.
+use super::PcSaftParameters;
+use crate::hard_sphere::HardSphereProperties;
+use feos_core::StateHD;
+use num_dual::DualNum;
+use std::f64::consts::FRAC_PI_6;
+
+/// Total number of segments and packing fraction.
+///
+/// Like in the full hard-sphere contribution, the shape factors
+/// are used instead of the segment numbers if they are provided.
+fn segments_and_packing_fraction<D: DualNum<f64> + Copy>(
+    parameters: &PcSaftParameters,
+    state: &StateHD<D>,
+) -> (D, D) {
+    let d = parameters.hs_diameter(state.temperature);
+    let shape_factor = parameters.shape_factor.as_ref().unwrap_or(&parameters.m);
+    let mut segments = D::zero();
+    let mut volume = D::zero();
+    for (i, &m) in shape_factor.iter().enumerate() {
+        segments += state.moles[i] * m;
+        volume += state.moles[i] * d[i].powi(3) * m;
+    }
+    (segments, volume * FRAC_PI_6 / state.volume)
+}
+
+/// Carnahan-Starling Helmholtz energy of the averaged hard-sphere fluid.
+pub(super) fn hard_sphere<D: DualNum<f64> + Copy>(
+    parameters: &PcSaftParameters,
+    state: &StateHD<D>,
+) -> D {
+    let (segments, eta) = segments_and_packing_fraction(parameters, state);
+    segments * eta * (eta * -3.0 + 4.0) / (-eta + 1.0).powi(2)
+}
+
+/// Hard-chain Helmholtz energy with the contact value of the averaged hard-sphere fluid.
+pub(super) fn hard_chain<D: DualNum<f64> + Copy>(
+    parameters: &PcSaftParameters,
+    state: &StateHD<D>,
+) -> D {
+    let (_, eta) = segments_and_packing_fraction(parameters, state);
+    let ln_g_hs = ((eta * -0.5 + 1.0) / (-eta + 1.0).powi(3)).ln();
+    parameters
+        .m
+        .iter()
+        .enumerate()
+        .fold(D::zero(), |acc, (i, &m)| {
+            acc + state.moles[i] * (1.0 - m) * ln_g_hs
+        })
+}
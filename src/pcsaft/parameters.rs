@@ -1,22 +1,27 @@
 use crate::association::{
     AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
+    SitePair,
 };
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use conv::ValueInto;
 use feos_core::parameter::{
-    FromSegments, FromSegmentsBinary, Parameter, ParameterError, PureRecord,
+    FromSegments, FromSegmentsBinary, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord,
 };
 use quantity::{JOULE, KB, KELVIN};
-use ndarray::{Array, Array1, Array2};
+use ndarray::{Array, Array1, Array2, Axis};
 use num_dual::DualNum;
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Write;
+use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 /// PC-SAFT pure-component parameters.
-#[derive(Serialize, Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(try_from = "PcSaftRecordSerde", into = "PcSaftRecordSerde")]
 pub struct PcSaftRecord {
     /// Segment number
     pub m: f64,
@@ -25,26 +30,121 @@ pub struct PcSaftRecord {
     /// Energetic parameter in units of Kelvin
     pub epsilon_k: f64,
     /// Dipole moment in units of Debye
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub mu: Option<f64>,
     /// Quadrupole moment in units of Debye * Angstrom
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub q: Option<f64>,
-    /// Association parameters
-    #[serde(flatten)]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub association_record: Option<AssociationRecord<PcSaftAssociationRecord>>,
+    /// Association parameters for every type of association site
+    pub association_records: Vec<AssociationRecord<PcSaftAssociationRecord>>,
     /// Entropy scaling coefficients for the viscosity
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// The correlation is a cubic polynomial in the residual entropy
+    /// and therefore requires four coefficients.
     pub viscosity: Option<[f64; 4]>,
     /// Entropy scaling coefficients for the diffusion coefficient
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// In contrast to viscosity and thermal conductivity, the correlation
+    /// contains an additional term of eighth order in the residual entropy
+    /// and therefore requires five coefficients.
     pub diffusion: Option<[f64; 5]>,
     /// Entropy scaling coefficients for the thermal conductivity
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// The correlation requires four coefficients.
     pub thermal_conductivity: Option<[f64; 4]>,
 }
 
+/// Serialized form of [PcSaftRecord].
+///
+/// A single association site is written directly into the record,
+/// multiple sites are written as a list `association_records`.
+/// The entropy scaling coefficients are read as lists of arbitrary
+/// length and validated during the conversion to [PcSaftRecord].
+#[derive(Serialize, Deserialize, Clone)]
+struct PcSaftRecordSerde {
+    m: f64,
+    sigma: f64,
+    epsilon_k: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mu: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<f64>,
+    #[serde(flatten)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    association_record: Option<AssociationRecord<PcSaftAssociationRecord>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    association_records: Vec<AssociationRecord<PcSaftAssociationRecord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    viscosity: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diffusion: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thermal_conductivity: Option<Vec<f64>>,
+}
+
+/// Convert a list of entropy scaling coefficients into an array of fixed length.
+fn entropy_scaling_coefficients<const N: usize>(
+    property: &str,
+    coefficients: Option<Vec<f64>>,
+) -> Result<Option<[f64; N]>, ParameterError> {
+    coefficients
+        .map(|c| {
+            let len = c.len();
+            c.try_into().map_err(|_| {
+                ParameterError::IncompatibleParameters(format!(
+                    "Entropy scaling for the {property} requires {N} coefficients, but {len} were provided."
+                ))
+            })
+        })
+        .transpose()
+}
+
+impl TryFrom<PcSaftRecordSerde> for PcSaftRecord {
+    type Error = ParameterError;
+
+    fn try_from(record: PcSaftRecordSerde) -> Result<Self, ParameterError> {
+        let association_records = record
+            .association_record
+            .into_iter()
+            .chain(record.association_records)
+            .collect();
+        Ok(Self {
+            m: record.m,
+            sigma: record.sigma,
+            epsilon_k: record.epsilon_k,
+            mu: record.mu,
+            q: record.q,
+            association_records,
+            viscosity: entropy_scaling_coefficients("viscosity", record.viscosity)?,
+            diffusion: entropy_scaling_coefficients("diffusion coefficient", record.diffusion)?,
+            thermal_conductivity: entropy_scaling_coefficients(
+                "thermal conductivity",
+                record.thermal_conductivity,
+            )?,
+        })
+    }
+}
+
+impl From<PcSaftRecord> for PcSaftRecordSerde {
+    fn from(record: PcSaftRecord) -> Self {
+        let (association_record, association_records) = match &record.association_records[..] {
+            [single] => (Some(single.clone()), vec![]),
+            _ => (None, record.association_records),
+        };
+        Self {
+            m: record.m,
+            sigma: record.sigma,
+            epsilon_k: record.epsilon_k,
+            mu: record.mu,
+            q: record.q,
+            association_record,
+            association_records,
+            viscosity: record.viscosity.map(Vec::from),
+            diffusion: record.diffusion.map(Vec::from),
+            thermal_conductivity: record.thermal_conductivity.map(Vec::from),
+        }
+    }
+}
+
 impl FromSegments<f64> for PcSaftRecord {
     fn from_segments(segments: &[(Self, f64)]) -> Result<Self, ParameterError> {
         let mut m = 0.0;
@@ -65,38 +165,18 @@ impl FromSegments<f64> for PcSaftRecord {
             .iter()
             .filter_map(|(s, n)| s.mu.map(|mu| mu * n))
             .reduce(|a, b| a + b);
-        let association_record = segments
+        let association_records = segments
             .iter()
-            .filter_map(|(s, n)| {
-                s.association_record.as_ref().map(|record| {
-                    [
-                        record.parameters.kappa_ab * n,
-                        record.parameters.epsilon_k_ab * n,
-                        record.na * n,
-                        record.nb * n,
-                        record.nc * n,
-                    ]
-                })
+            .flat_map(|(s, n)| {
+                s.association_records
+                    .iter()
+                    .map(move |record| record.scale(*n))
             })
-            .reduce(|a, b| {
-                [
-                    a[0] + b[0],
-                    a[1] + b[1],
-                    a[2] + b[2],
-                    a[3] + b[3],
-                    a[4] + b[4],
-                ]
-            })
-            .map(|[kappa_ab, epsilon_k_ab, na, nb, nc]| {
-                AssociationRecord::new(
-                    PcSaftAssociationRecord::new(kappa_ab, epsilon_k_ab),
-                    na,
-                    nb,
-                    nc,
-                )
-            });
+            .collect();
 
-        // entropy scaling
+        // entropy scaling: the first two coefficients of viscosity and diffusion
+        // are weighted with the segment volumes, all other coefficients with
+        // the number of segments
         let mut viscosity = if segments
             .iter()
             .all(|(record, _)| record.viscosity.is_some())
@@ -113,7 +193,7 @@ impl FromSegments<f64> for PcSaftRecord {
         } else {
             None
         };
-        let diffusion = if segments
+        let mut diffusion = if segments
             .iter()
             .all(|(record, _)| record.diffusion.is_some())
         {
@@ -139,16 +219,20 @@ impl FromSegments<f64> for PcSaftRecord {
                 p[2] += n * c;
                 p[3] += n_t * d;
             }
-            // if let Some(p) = diffusion.as_mut() {
-            //     let [a, b, c, d, e] = s.diffusion.unwrap();
-            //     p[0] += s3 * a;
-            //     p[1] += s3 * b / sigma3.powf(0.45);
-            //     p[2] += *n * c;
-            //     p[3] += *n * d;
-            // }
+            if let Some(p) = diffusion.as_mut() {
+                let [a, b, c, d, e] = s.diffusion.unwrap();
+                p[0] += s3 * a;
+                p[1] += s3 * b / sigma3.powf(0.45);
+                p[2] += n * c;
+                p[3] += n * d;
+                p[4] += n * e;
+            }
         });
         // correction due to difference in Chapman-Enskog reference between GC and regular formulation.
         viscosity = viscosity.map(|v| [v[0] - 0.5 * m.ln(), v[1], v[2], v[3]]);
+        // as for the viscosity, the GC reference is evaluated with the segment molar weight M/m,
+        // i.e. D_GC / D_CE = m, because the regular diffusion reference scales with (M m)^(-1/2).
+        diffusion = diffusion.map(|d| [d[0] + m.ln(), d[1], d[2], d[3], d[4]]);
 
         Ok(Self {
             m,
@@ -156,7 +240,7 @@ impl FromSegments<f64> for PcSaftRecord {
             epsilon_k: epsilon_k / m,
             mu,
             q,
-            association_record,
+            association_records,
             viscosity,
             diffusion,
             thermal_conductivity,
@@ -172,8 +256,9 @@ impl FromSegments<usize> for PcSaftRecord {
             .filter_map(|(s, n)| {
                 if s.q.is_some()
                     || s.mu.is_some()
-                    || s.association_record
-                        .is_some_and(|r| r.na + r.nb + r.nc > 0.0)
+                    || s.association_records
+                        .iter()
+                        .any(|r| r.na + r.nb + r.nc > 0.0)
                 {
                     Some(n)
                 } else {
@@ -185,9 +270,10 @@ impl FromSegments<usize> for PcSaftRecord {
         let dipole_segments: usize = segments.iter().filter_map(|(s, n)| s.mu.map(|_| n)).sum();
         let assoc_segments: usize = segments
             .iter()
-            .filter_map(|(s, n)| {
-                s.association_record
-                    .map(|r| (r.na * r.nb + r.nc) as usize * n)
+            .flat_map(|(s, n)| {
+                s.association_records
+                    .iter()
+                    .map(move |r| (r.na * r.nb + r.nc) as usize * n)
             })
             .sum();
         if polar_segments > 1 {
@@ -204,6 +290,16 @@ impl FromSegments<usize> for PcSaftRecord {
     }
 }
 
+/// Consistent with [PartialEq]: only the segment number, diameter and
+/// energy parameter are hashed, with `-0.0` and `0.0` treated as equal.
+impl std::hash::Hash for PcSaftRecord {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for p in [self.m, self.sigma, self.epsilon_k] {
+            (p + 0.0).to_bits().hash(state);
+        }
+    }
+}
+
 impl std::fmt::Display for PcSaftRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "PcSaftRecord(m={}", self.m)?;
@@ -215,8 +311,19 @@ impl std::fmt::Display for PcSaftRecord {
         if let Some(n) = &self.q {
             write!(f, ", q={}", n)?;
         }
-        if let Some(n) = &self.association_record {
-            write!(f, ", association_record={}", n)?;
+        match &self.association_records[..] {
+            [] => (),
+            [record] => write!(f, ", association_record={}", record)?,
+            records => {
+                write!(f, ", association_records=[")?;
+                for (i, record) in records.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", record)?;
+                }
+                write!(f, "]")?;
+            }
         }
         if let Some(n) = &self.viscosity {
             write!(f, ", viscosity={:?}", n)?;
@@ -248,16 +355,16 @@ impl PcSaftRecord {
         diffusion: Option<[f64; 5]>,
         thermal_conductivity: Option<[f64; 4]>,
     ) -> PcSaftRecord {
-        let association_record =
+        let association_records =
             if let (Some(kappa_ab), Some(epsilon_k_ab)) = (kappa_ab, epsilon_k_ab) {
-                Some(AssociationRecord::new(
+                vec![AssociationRecord::new(
                     PcSaftAssociationRecord::new(kappa_ab, epsilon_k_ab),
                     na.unwrap_or_default(),
                     nb.unwrap_or_default(),
                     nc.unwrap_or_default(),
-                ))
+                )]
             } else {
-                None
+                vec![]
             };
         Self {
             m,
@@ -265,15 +372,79 @@ impl PcSaftRecord {
             epsilon_k,
             mu,
             q,
-            association_record,
+            association_records,
             viscosity,
             diffusion,
             thermal_conductivity,
         }
     }
+
+    /// Return a copy of the record with the given segment number.
+    pub fn with_m(&self, m: f64) -> Self {
+        Self { m, ..self.clone() }
+    }
+
+    /// Return a copy of the record with the given segment diameter.
+    pub fn with_sigma(&self, sigma: f64) -> Self {
+        Self {
+            sigma,
+            ..self.clone()
+        }
+    }
+
+    /// Return a copy of the record with the given energetic parameter.
+    pub fn with_epsilon_k(&self, epsilon_k: f64) -> Self {
+        Self {
+            epsilon_k,
+            ..self.clone()
+        }
+    }
+
+    /// Return a copy of the record with the given dipole moment.
+    pub fn with_mu(&self, mu: Option<f64>) -> Self {
+        Self { mu, ..self.clone() }
+    }
+
+    /// Return a copy of the record with the given quadrupole moment.
+    pub fn with_q(&self, q: Option<f64>) -> Self {
+        Self { q, ..self.clone() }
+    }
+
+    /// Check that the parameters of the record are physically plausible.
+    pub fn validate(&self) -> Result<(), ParameterError> {
+        for (name, value) in [
+            ("m", self.m),
+            ("sigma", self.sigma),
+            ("epsilon_k", self.epsilon_k),
+        ] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "{name} has to be positive, got {name} = {value}."
+                )));
+            }
+        }
+        for association_record in &self.association_records {
+            let kappa_ab = association_record.parameters.kappa_ab;
+            if kappa_ab.is_nan() || kappa_ab < 0.0 {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "kappa_ab has to be non-negative, got kappa_ab = {kappa_ab}."
+                )));
+            }
+            let AssociationRecord {
+                na, nb, nc, sites, ..
+            } = association_record;
+            if *na == 0.0 && *nb == 0.0 && *nc == 0.0 && sites.iter().all(|s| s.n == 0.0) {
+                return Err(ParameterError::IncompatibleParameters(
+                    "association parameters require at least one site (na, nb, nc or sites)."
+                        .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+#[derive(Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
 pub struct PcSaftAssociationRecord {
     /// Association volume parameter
     pub kappa_ab: f64,
@@ -326,17 +497,65 @@ impl From<PcSaftBinaryRecord> for f64 {
 
 impl PcSaftBinaryRecord {
     pub fn new(k_ij: Option<f64>, kappa_ab: Option<f64>, epsilon_k_ab: Option<f64>) -> Self {
+        Self::new_asymmetric(k_ij, kappa_ab, epsilon_k_ab, None, None)
+    }
+
+    pub fn new_asymmetric(
+        k_ij: Option<f64>,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        kappa_ba: Option<f64>,
+        epsilon_k_ba: Option<f64>,
+    ) -> Self {
         let k_ij = k_ij.unwrap_or_default();
-        let association = if kappa_ab.is_none() && epsilon_k_ab.is_none() {
+        let association = if [kappa_ab, epsilon_k_ab, kappa_ba, epsilon_k_ba]
+            .iter()
+            .all(Option::is_none)
+        {
             None
         } else {
             Some(BinaryAssociationRecord::new(
-                PcSaftBinaryAssociationRecord::new(kappa_ab, epsilon_k_ab),
+                PcSaftBinaryAssociationRecord::new_asymmetric(
+                    kappa_ab,
+                    epsilon_k_ab,
+                    kappa_ba,
+                    epsilon_k_ba,
+                ),
                 None,
             ))
         };
         Self { k_ij, association }
     }
+
+    /// Return the record for the two components in reversed order.
+    ///
+    /// The parameters between site A of the first and site B of the second
+    /// component are exchanged with those between site B of the first and
+    /// site A of the second component.
+    pub fn reversed(self) -> Self {
+        let association = self.association.map(|a| {
+            let PcSaftBinaryAssociationRecord {
+                kappa_ab,
+                epsilon_k_ab,
+                kappa_ba,
+                epsilon_k_ba,
+            } = a.parameters;
+            let [i, j] = a.site_indices;
+            BinaryAssociationRecord::new(
+                PcSaftBinaryAssociationRecord::new_asymmetric(
+                    kappa_ba.or(kappa_ab),
+                    epsilon_k_ba.or(epsilon_k_ab),
+                    kappa_ba.and(kappa_ab),
+                    epsilon_k_ba.and(epsilon_k_ab),
+                ),
+                Some([j, i]),
+            )
+        });
+        Self {
+            k_ij: self.k_ij,
+            association,
+        }
+    }
 }
 
 impl<T: Copy + ValueInto<f64>> FromSegmentsBinary<T> for PcSaftBinaryRecord {
@@ -345,8 +564,9 @@ impl<T: Copy + ValueInto<f64>> FromSegmentsBinary<T> for PcSaftBinaryRecord {
             let nab = (*n1).value_into().unwrap() * (*n2).value_into().unwrap();
             (k_ij + br * nab, n + nab)
         });
+        let k_ij = if n > 0.0 { k_ij / n } else { 0.0 };
         Ok(Self {
-            k_ij: k_ij / n,
+            k_ij,
             association: None,
         })
     }
@@ -365,6 +585,12 @@ impl std::fmt::Display for PcSaftBinaryRecord {
             if let Some(epsilon_k_ab) = association.parameters.epsilon_k_ab {
                 tokens.push(format!("epsilon_k_ab={}", epsilon_k_ab));
             }
+            if let Some(kappa_ba) = association.parameters.kappa_ba {
+                tokens.push(format!("kappa_ba={}", kappa_ba));
+            }
+            if let Some(epsilon_k_ba) = association.parameters.epsilon_k_ba {
+                tokens.push(format!("epsilon_k_ba={}", epsilon_k_ba));
+            }
         }
         write!(f, "PcSaftBinaryRecord({})", tokens.join(", "))
     }
@@ -378,18 +604,51 @@ pub struct PcSaftBinaryAssociationRecord {
     /// Cross-association energy parameter.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub epsilon_k_ab: Option<f64>,
+    /// Cross-association association volume parameter between site B
+    /// of the first and site A of the second component (defaults to `kappa_ab`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kappa_ba: Option<f64>,
+    /// Cross-association energy parameter between site B of the first
+    /// and site A of the second component (defaults to `epsilon_k_ab`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epsilon_k_ba: Option<f64>,
 }
 
 impl PcSaftBinaryAssociationRecord {
     pub fn new(kappa_ab: Option<f64>, epsilon_k_ab: Option<f64>) -> Self {
+        Self::new_asymmetric(kappa_ab, epsilon_k_ab, None, None)
+    }
+
+    pub fn new_asymmetric(
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        kappa_ba: Option<f64>,
+        epsilon_k_ba: Option<f64>,
+    ) -> Self {
         Self {
             kappa_ab,
             epsilon_k_ab,
+            kappa_ba,
+            epsilon_k_ba,
         }
     }
 }
 
+/// Definition of the hard-sphere diameter of the segments.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq))]
+pub enum HardSphereDiameter {
+    /// Temperature-dependent diameter
+    /// $d_i=\sigma_i\left(1-0.12e^{-3\varepsilon_i/kT}\right)$
+    /// according to Barker and Henderson.
+    #[default]
+    BarkerHenderson,
+    /// Temperature-independent diameter $d_i=\sigma_i$.
+    Sigma,
+}
+
 /// Parameter set required for the PC-SAFT equation of state and Helmholtz energy functional.
+#[derive(Clone)]
 pub struct PcSaftParameters {
     pub molarweight: Array1<f64>,
     pub m: Array1<f64>,
@@ -412,6 +671,8 @@ pub struct PcSaftParameters {
     pub thermal_conductivity: Option<Array2<f64>>,
     pub pure_records: Vec<PureRecord<PcSaftRecord>>,
     pub binary_records: Option<Array2<PcSaftBinaryRecord>>,
+    pub hard_sphere_diameter: HardSphereDiameter,
+    pub shape_factor: Option<Array1<f64>>,
 }
 
 impl Parameter for PcSaftParameters {
@@ -437,15 +698,26 @@ impl Parameter for PcSaftParameters {
 
         let mut component_index = HashMap::with_capacity(n);
 
+        // molar weights have to be provided either for all or for none of the components
+        let has_molar_weight = pure_records.iter().any(|r| r.molarweight != 0.0);
+
         for (i, record) in pure_records.iter().enumerate() {
             component_index.insert(record.identifier.clone(), i);
+            let mw = record.molarweight;
+            if mw.is_nan() || mw < 0.0 || (has_molar_weight && mw == 0.0) {
+                return Err(ParameterError::IncompatibleParameters(format!(
+                    "molarweight of {} has to be positive, got molarweight = {mw}.",
+                    record.identifier
+                )));
+            }
             let r = &record.model_record;
+            r.validate()?;
             m[i] = r.m;
             sigma[i] = r.sigma;
             epsilon_k[i] = r.epsilon_k;
             mu[i] = r.mu.unwrap_or(0.0);
             q[i] = r.q.unwrap_or(0.0);
-            association_records.push(r.association_record.into_iter().collect());
+            association_records.push(r.association_records.clone());
             viscosity.push(r.viscosity);
             diffusion.push(r.diffusion);
             thermal_conductivity.push(r.thermal_conductivity);
@@ -471,13 +743,20 @@ impl Parameter for PcSaftParameters {
             .collect();
         let nquadpole = quadpole_comp.len();
 
+        // the lower triangle contains the reversed binary records, so only
+        // the upper triangle is used for the association parameters
         let binary_association: Vec<_> = binary_records
             .iter()
             .flat_map(|r| {
                 r.indexed_iter()
+                    .filter(|((i, j), _)| i <= j)
                     .filter_map(|((i, j), record)| record.association.map(|r| ([i, j], r)))
             })
             .collect();
+        AssociationParameters::<Self>::check_binary_records(
+            &association_records,
+            &binary_association,
+        )?;
         let association =
             AssociationParameters::new(&association_records, &binary_association, None);
 
@@ -548,6 +827,8 @@ impl Parameter for PcSaftParameters {
             thermal_conductivity: thermal_conductivity_coefficients,
             pure_records,
             binary_records,
+            hard_sphere_diameter: HardSphereDiameter::default(),
+            shape_factor: None,
         })
     }
 
@@ -559,14 +840,43 @@ impl Parameter for PcSaftParameters {
     ) {
         (&self.pure_records, self.binary_records.as_ref())
     }
+
+    fn reverse_binary_record(binary_record: Self::Binary) -> Self::Binary {
+        binary_record.reversed()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let pure_records = component_list
+            .iter()
+            .map(|&i| self.pure_records[i].clone())
+            .collect();
+        let binary_records = self.binary_records.as_ref().map(|br| {
+            br.select(Axis(0), component_list)
+                .select(Axis(1), component_list)
+        });
+        let parameters = Self::from_records(pure_records, binary_records)
+            .expect("failed to create subset from parameters.");
+        Self {
+            hard_sphere_diameter: self.hard_sphere_diameter,
+            shape_factor: self
+                .shape_factor
+                .as_ref()
+                .map(|s| s.select(Axis(0), component_list)),
+            ..parameters
+        }
+    }
 }
 
 impl HardSphereProperties for PcSaftParameters {
     fn monomer_shape<N: DualNum<f64>>(&self, _: N) -> MonomerShape<N> {
-        MonomerShape::NonSpherical(self.m.mapv(N::from))
+        let shape_factor = self.shape_factor.as_ref().unwrap_or(&self.m);
+        MonomerShape::NonSpherical(shape_factor.mapv(N::from))
     }
 
     fn hs_diameter<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array1<D> {
+        if self.hard_sphere_diameter == HardSphereDiameter::Sigma {
+            return self.sigma.mapv(D::from);
+        }
         let ti = temperature.recip() * -3.0;
         Array::from_shape_fn(self.sigma.len(), |i| {
             -((ti * self.epsilon_k[i]).exp() * 0.12 - 1.0) * self.sigma[i]
@@ -599,34 +909,303 @@ impl AssociationStrength for PcSaftParameters {
         }
     }
 
-    fn update_binary(parameters_ij: &mut Self::Record, binary_parameters: Self::BinaryRecord) {
-        if let Some(kappa_ab) = binary_parameters.kappa_ab {
+    fn update_binary(
+        parameters_ij: &mut Self::Record,
+        binary_parameters: Self::BinaryRecord,
+        site_pair: SitePair,
+    ) {
+        let PcSaftBinaryAssociationRecord {
+            kappa_ab,
+            epsilon_k_ab,
+            kappa_ba,
+            epsilon_k_ba,
+        } = binary_parameters;
+        let (kappa, epsilon_k) = match site_pair {
+            SitePair::AB | SitePair::CC => (kappa_ab, epsilon_k_ab),
+            SitePair::BA => (kappa_ba.or(kappa_ab), epsilon_k_ba.or(epsilon_k_ab)),
+        };
+        if let Some(kappa_ab) = kappa {
             parameters_ij.kappa_ab = kappa_ab
         }
-        if let Some(epsilon_k_ab) = binary_parameters.epsilon_k_ab {
+        if let Some(epsilon_k_ab) = epsilon_k {
             parameters_ij.epsilon_k_ab = epsilon_k_ab
         }
     }
 }
 
+/// Columns of a parameter CSV file that identify a substance.
+const CSV_IDENTIFIER_COLUMNS: [&str; 6] =
+    ["cas", "name", "iupac_name", "smiles", "inchi", "formula"];
+
+/// Columns of a parameter CSV file that have to be present.
+const CSV_REQUIRED_COLUMNS: [&str; 4] = ["molarweight", "m", "sigma", "epsilon_k"];
+
+/// Split a single line of a CSV file into its fields.
+///
+/// Fields can be enclosed in double quotes, which is required
+/// if they contain commas (e.g., InChI or IUPAC names). Quoted
+/// fields that span multiple lines are not supported and result
+/// in an error.
+fn split_csv_line(line: &str) -> Result<Vec<String>, ParameterError> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(ParameterError::InvalidCsv(format!(
+            "unterminated quoted field in line '{line}' (fields spanning multiple lines are not supported)"
+        )));
+    }
+    fields.push(field);
+    Ok(fields.into_iter().map(|f| f.trim().to_string()).collect())
+}
+
 impl PcSaftParameters {
+    /// Creates parameters for all substances in a CSV file.
+    ///
+    /// The first line of the file contains the column names. The columns
+    /// `molarweight`, `m`, `sigma`, `epsilon_k` and the identifier column
+    /// corresponding to `identifier_option` are required. The columns
+    /// `mu`, `q`, `kappa_ab`, `epsilon_k_ab`, `na`, `nb`, `nc` and the
+    /// remaining identifiers are optional; missing columns or empty
+    /// fields are interpreted as `None`. Other columns are ignored.
+    ///
+    /// Numerical values have to be finite. Fields can be enclosed in
+    /// double quotes, but quoted fields must not contain line breaks.
+    pub fn from_csv<P: AsRef<Path>>(
+        path: P,
+        identifier_option: IdentifierOption,
+    ) -> Result<Self, ParameterError> {
+        let content = fs::read_to_string(path)?;
+        let mut lines = content.lines().filter(|l| !l.trim().is_empty());
+        let header = split_csv_line(
+            lines
+                .next()
+                .ok_or_else(|| ParameterError::InvalidCsv("file is empty".into()))?,
+        )?;
+        let column = |name: &str| header.iter().position(|h| h == name);
+
+        let identifier_column = match identifier_option {
+            IdentifierOption::Cas => "cas",
+            IdentifierOption::Name => "name",
+            IdentifierOption::IupacName => "iupac_name",
+            IdentifierOption::Smiles => "smiles",
+            IdentifierOption::Inchi => "inchi",
+            IdentifierOption::Formula => "formula",
+        };
+        let missing: Vec<_> = CSV_REQUIRED_COLUMNS
+            .iter()
+            .chain([&identifier_column])
+            .filter(|&&c| column(c).is_none())
+            .copied()
+            .collect();
+        if !missing.is_empty() {
+            return Err(ParameterError::InvalidCsv(format!(
+                "missing required column(s): {}",
+                missing.join(", ")
+            )));
+        }
+
+        let mut records = Vec::new();
+        for (i, line) in lines.enumerate() {
+            let row = i + 1;
+            let fields = split_csv_line(line)?;
+            if fields.len() != header.len() {
+                return Err(ParameterError::InvalidCsv(format!(
+                    "row {row} has {} fields, but the header has {}",
+                    fields.len(),
+                    header.len()
+                )));
+            }
+            let text = |name: &str| {
+                column(name)
+                    .map(|c| fields[c].as_str())
+                    .filter(|f| !f.is_empty())
+            };
+            let value = |name: &str| {
+                text(name)
+                    .map(|f| {
+                        f.parse::<f64>()
+                            .ok()
+                            .filter(|v| v.is_finite())
+                            .ok_or_else(|| {
+                                ParameterError::InvalidCsv(format!(
+                                    "invalid value '{f}' in column '{name}' of row {row}"
+                                ))
+                            })
+                    })
+                    .transpose()
+            };
+            let required = |name: &str| {
+                value(name)?.ok_or_else(|| {
+                    ParameterError::InvalidCsv(format!(
+                        "missing value in column '{name}' of row {row}"
+                    ))
+                })
+            };
+
+            let [cas, name, iupac_name, smiles, inchi, formula] = CSV_IDENTIFIER_COLUMNS.map(text);
+            if text(identifier_column).is_none() {
+                return Err(ParameterError::InvalidCsv(format!(
+                    "missing value in column '{identifier_column}' of row {row}"
+                )));
+            }
+            let identifier = Identifier::new(cas, name, iupac_name, smiles, inchi, formula);
+            let model_record = PcSaftRecord::new(
+                required("m")?,
+                required("sigma")?,
+                required("epsilon_k")?,
+                value("mu")?,
+                value("q")?,
+                value("kappa_ab")?,
+                value("epsilon_k_ab")?,
+                value("na")?,
+                value("nb")?,
+                value("nc")?,
+                None,
+                None,
+                None,
+            );
+            records.push(PureRecord::new(
+                identifier,
+                required("molarweight")?,
+                model_record,
+            ));
+        }
+        Self::from_records(records, None)
+    }
+
+    /// Creates parameters for the substances identified by their SMILES
+    /// strings in a JSON file.
+    ///
+    /// Returns [ParameterError::ComponentsNotFound] listing all SMILES
+    /// that are not contained in the file.
+    pub fn from_smiles_database<P: AsRef<Path>>(
+        smiles: &[&str],
+        path: P,
+    ) -> Result<Self, ParameterError> {
+        Self::from_json(smiles.to_vec(), path, None, IdentifierOption::Smiles)
+    }
+
+    /// Return a copy of the parameters that uses the given
+    /// definition of the hard-sphere diameter.
+    pub fn with_hard_sphere_diameter(&self, hard_sphere_diameter: HardSphereDiameter) -> Self {
+        Self {
+            hard_sphere_diameter,
+            ..self.clone()
+        }
+    }
+
+    /// Return a copy of the parameters that uses the given shape factors
+    /// of the monomers in the hard-sphere contribution instead of the
+    /// segment numbers.
+    ///
+    /// All other contributions still use the segment numbers `m`.
+    pub fn with_shape_factor(&self, shape_factor: Array1<f64>) -> Result<Self, ParameterError> {
+        if shape_factor.len() != self.m.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "expected {} shape factors, got {}.",
+                self.m.len(),
+                shape_factor.len()
+            )));
+        }
+        if let Some(s) = shape_factor.iter().find(|s| !s.is_finite() || **s <= 0.0) {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "shape factors have to be positive, got {s}."
+            )));
+        }
+        Ok(Self {
+            shape_factor: Some(shape_factor),
+            ..self.clone()
+        })
+    }
+
+    /// Return `true` if the molar weights of all components are available.
+    ///
+    /// Parameters created without molar weights (e.g. from model records)
+    /// cannot be used to calculate mass specific properties.
+    pub fn has_molar_weight(&self) -> bool {
+        self.molarweight.iter().all(|&mw| mw > 0.0)
+    }
+
+    /// Return `true` if entropy scaling parameters for the viscosity are available.
+    pub fn has_viscosity_parameters(&self) -> bool {
+        self.viscosity.is_some()
+    }
+
+    /// Return `true` if entropy scaling parameters for the diffusion coefficient are available.
+    pub fn has_diffusion_parameters(&self) -> bool {
+        self.diffusion.is_some()
+    }
+
+    /// Return `true` if entropy scaling parameters for the thermal conductivity are available.
+    pub fn has_thermal_conductivity_parameters(&self) -> bool {
+        self.thermal_conductivity.is_some()
+    }
+
+    /// Convert mass fractions into mole fractions using the molar weights
+    /// of the components: $x_i=\frac{w_i/MW_i}{\sum_jw_j/MW_j}$
+    pub fn massfracs_to_molefracs(
+        &self,
+        massfracs: &Array1<f64>,
+    ) -> Result<Array1<f64>, ParameterError> {
+        self.validate_composition(massfracs)?;
+        let moles = massfracs / &self.molarweight;
+        Ok(&moles / moles.sum())
+    }
+
+    /// Convert mole fractions into mass fractions using the molar weights
+    /// of the components: $w_i=\frac{x_iMW_i}{\sum_jx_jMW_j}$
+    pub fn molefracs_to_massfracs(
+        &self,
+        molefracs: &Array1<f64>,
+    ) -> Result<Array1<f64>, ParameterError> {
+        self.validate_composition(molefracs)?;
+        let mass = molefracs * &self.molarweight;
+        Ok(&mass / mass.sum())
+    }
+
+    fn validate_composition(&self, fractions: &Array1<f64>) -> Result<(), ParameterError> {
+        if !self.has_molar_weight() {
+            return Err(ParameterError::IncompatibleParameters(
+                "converting between mass and mole fractions requires the molar weights of all components.".into(),
+            ));
+        }
+        if fractions.len() != self.molarweight.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "expected {} fractions, got {}.",
+                self.molarweight.len(),
+                fractions.len()
+            )));
+        }
+        Ok(())
+    }
+
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
         let o = &mut output;
         write!(
             o,
-            "|component|molarweight|$m$|$\\sigma$|$\\varepsilon$|$\\mu$|$Q$|$\\kappa_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|$N_C$|\n|-|-|-|-|-|-|-|-|-|-|-|-|"
+            "|component|molarweight|$m$|$\\sigma$|$\\varepsilon$|$\\mu$|$Q$|sites|$\\kappa_{{AB}}$|$\\varepsilon_{{AB}}$|$N_A$|$N_B$|$N_C$|\n|-|-|-|-|-|-|-|-|-|-|-|-|-|"
         )
         .unwrap();
         for (i, record) in self.pure_records.iter().enumerate() {
             let component = record.identifier.name.clone();
             let component = component.unwrap_or(format!("Component {}", i + 1));
-            let association = record.model_record.association_record.unwrap_or_else(|| {
-                AssociationRecord::new(PcSaftAssociationRecord::new(0.0, 0.0), 0.0, 0.0, 0.0)
-            });
             write!(
                 o,
-                "\n|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|",
+                "\n|{}|{}|{}|{}|{}|{}|{}|",
                 component,
                 record.molarweight,
                 record.model_record.m,
@@ -634,13 +1213,37 @@ impl PcSaftParameters {
                 record.model_record.epsilon_k,
                 record.model_record.mu.unwrap_or(0.0),
                 record.model_record.q.unwrap_or(0.0),
-                association.parameters.kappa_ab,
-                association.parameters.epsilon_k_ab,
-                association.na,
-                association.nb,
-                association.nc
             )
             .unwrap();
+            let association_records = &record.model_record.association_records;
+            if association_records.is_empty() {
+                write!(o, "||||||").unwrap();
+            }
+            // additional association records are listed in separate rows below the component
+            for (j, association) in association_records.iter().enumerate() {
+                if j > 0 {
+                    write!(o, "\n||||||||").unwrap();
+                }
+                let sites: String = [
+                    ("A", association.na),
+                    ("B", association.nb),
+                    ("C", association.nc),
+                ]
+                .into_iter()
+                .filter_map(|(site, n)| (n > 0.0).then_some(site))
+                .collect();
+                write!(
+                    o,
+                    "{}|{}|{}|{}|{}|{}|",
+                    sites,
+                    association.parameters.kappa_ab,
+                    association.parameters.epsilon_k_ab,
+                    association.na,
+                    association.nb,
+                    association.nc
+                )
+                .unwrap();
+            }
         }
 
         output
@@ -650,7 +1253,11 @@ impl PcSaftParameters {
 #[cfg(test)]
 pub mod utils {
     use super::*;
+    use crate::pcsaft::PcSaft;
+    use approx::assert_relative_eq;
     use feos_core::parameter::{BinaryRecord, ChemicalRecord, SegmentRecord};
+    use feos_core::Molarweight;
+    use ndarray::arr1;
     use std::sync::Arc;
 
     pub fn propane_parameters() -> Arc<PcSaftParameters> {
@@ -864,6 +1471,376 @@ pub mod utils {
         Arc::new(PcSaftParameters::new_binary(binary_record, None).unwrap())
     }
 
+    #[test]
+    pub fn test_association_record() {
+        let record = PcSaftRecord::new(
+            1.0,
+            3.0,
+            200.0,
+            None,
+            None,
+            Some(0.03),
+            Some(2500.0),
+            Some(2.0),
+            Some(1.0),
+            Some(1.0),
+            None,
+            None,
+            None,
+        );
+        let [association_record] = &record.association_records[..] else {
+            panic!("expected a single association record");
+        };
+        assert_eq!(association_record.na, 2.0);
+        assert_eq!(association_record.nb, 1.0);
+        assert_eq!(association_record.nc, 1.0);
+        assert_eq!(association_record.parameters.kappa_ab, 0.03);
+        assert_eq!(association_record.parameters.epsilon_k_ab, 2500.0);
+
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: PcSaftRecord = serde_json::from_str(&json).unwrap();
+        let [parsed_record] = &parsed.association_records[..] else {
+            panic!("expected a single association record");
+        };
+        assert_eq!(
+            (parsed_record.na, parsed_record.nb, parsed_record.nc),
+            (2.0, 1.0, 1.0)
+        );
+        assert_eq!(parsed_record.parameters.kappa_ab, 0.03);
+        assert_eq!(parsed_record.parameters.epsilon_k_ab, 2500.0);
+    }
+
+    #[test]
+    pub fn test_multiple_association_records() {
+        let segment_json = r#"
+            {
+                "m": 0.5,
+                "sigma": 3.0,
+                "epsilon_k": 250.0,
+                "association_records": [
+                    {"kappa_ab": 0.02, "epsilon_k_ab": 2000.0, "na": 1.0, "nb": 1.0},
+                    {"kappa_ab": 0.04, "epsilon_k_ab": 1500.0, "nc": 1.0}
+                ]
+            }"#;
+        let segment: PcSaftRecord =
+            serde_json::from_str(segment_json).expect("Unable to parse json.");
+        assert_eq!(segment.association_records.len(), 2);
+
+        let record = PcSaftRecord::from_segments(&[(segment.clone(), 2.0)]).unwrap();
+        let [first, second] = &record.association_records[..] else {
+            panic!("expected two association records");
+        };
+        assert_eq!(first.parameters.kappa_ab, 0.02);
+        assert_eq!(first.parameters.epsilon_k_ab, 2000.0);
+        assert_eq!((first.na, first.nb, first.nc), (2.0, 2.0, 0.0));
+        assert_eq!(second.parameters.kappa_ab, 0.04);
+        assert_eq!(second.parameters.epsilon_k_ab, 1500.0);
+        assert_eq!((second.na, second.nb, second.nc), (0.0, 0.0, 2.0));
+
+        let json = serde_json::to_string(&record).unwrap();
+        let parsed: PcSaftRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.association_records.len(), 2);
+        assert_eq!(parsed.to_string(), record.to_string());
+    }
+
+    #[test]
+    pub fn test_markdown() {
+        let water = water_parameters().to_markdown();
+        assert!(water.contains("|water_np|18.0152|"));
+        assert!(water.contains("|AB|0.034867983|2500.6706|1|1|0|"));
+
+        let propane = propane_parameters().to_markdown();
+        assert!(propane.ends_with("|0|0|||||||"));
+
+        let segment_json = r#"
+            {
+                "m": 1.0,
+                "sigma": 3.0,
+                "epsilon_k": 250.0,
+                "association_records": [
+                    {"kappa_ab": 0.02, "epsilon_k_ab": 2000.0, "na": 1.0, "nb": 1.0},
+                    {"kappa_ab": 0.04, "epsilon_k_ab": 1500.0, "nc": 1.0}
+                ]
+            }"#;
+        let record: PcSaftRecord =
+            serde_json::from_str(segment_json).expect("Unable to parse json.");
+        let pure_record = PureRecord::new(Default::default(), 0.0, record);
+        let markdown = PcSaftParameters::new_pure(pure_record)
+            .unwrap()
+            .to_markdown();
+        let rows: Vec<_> = markdown.lines().skip(2).collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].starts_with("|Component 1|"));
+        assert!(rows[0].ends_with("|AB|0.02|2000|1|1|0|"));
+        assert_eq!(rows[1], "||||||||C|0.04|1500|0|0|1|");
+    }
+
+    #[test]
+    pub fn test_combined_parameters() {
+        let p = dme_co2_parameters();
+        let k_ij = p.binary_records.as_ref().map_or(0.0, |br| br[[0, 1]].k_ij);
+        assert_eq!(p.sigma_ij[[0, 1]], 0.5 * (p.sigma[0] + p.sigma[1]));
+        assert_eq!(p.sigma_ij[[0, 1]], p.sigma_ij[[1, 0]]);
+        assert_eq!(p.sigma_ij[[0, 0]], p.sigma[0]);
+        assert_eq!(p.e_k_ij[[0, 1]], (p.epsilon_k[0] * p.epsilon_k[1]).sqrt());
+        assert_eq!(p.epsilon_k_ij[[0, 1]], p.e_k_ij[[0, 1]] * (1.0 - k_ij));
+    }
+
+    #[test]
+    pub fn test_record_equality() {
+        let json = r#"
+            {
+                "m": 1.065587,
+                "sigma": 3.000683,
+                "epsilon_k": 366.5121,
+                "kappa_ab": 0.034867983,
+                "epsilon_k_ab": 2500.6706,
+                "na": 1.0,
+                "nb": 1.0,
+                "viscosity": [-0.8013, -1.9972, -0.2907, -0.0467]
+            }"#;
+        let record: PcSaftRecord = serde_json::from_str(json).unwrap();
+        let identical: PcSaftRecord = serde_json::from_str(json).unwrap();
+        assert!(record == identical);
+        assert!(record != record.with_sigma(3.0));
+
+        let mut perturbed = record.clone();
+        perturbed.association_records[0].parameters.kappa_ab = 0.035;
+        assert!(record != perturbed);
+
+        let hash = |r: &PcSaftRecord| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            std::hash::Hash::hash(r, &mut hasher);
+            std::hash::Hasher::finish(&hasher)
+        };
+        assert_eq!(hash(&record), hash(&identical));
+        assert_eq!(hash(&record.with_m(0.0)), hash(&record.with_m(-0.0)));
+    }
+
+    #[test]
+    pub fn test_with_parameters() {
+        let record = water_parameters().pure_records[0].model_record.clone();
+        let modified = record.with_epsilon_k(210.0);
+        assert_eq!(modified.epsilon_k, 210.0);
+        assert_eq!(modified.m, record.m);
+        assert_eq!(modified.sigma, record.sigma);
+        assert_eq!(modified.mu, record.mu);
+        assert_eq!(modified.q, record.q);
+        let association = &modified.association_records[0];
+        assert_eq!(association.na, 1.0);
+        assert_eq!(association.nb, 1.0);
+        assert_eq!(association.parameters.kappa_ab, 0.034867983);
+
+        let modified = record.with_m(2.0).with_sigma(3.5).with_mu(Some(1.8));
+        assert_eq!(modified.m, 2.0);
+        assert_eq!(modified.sigma, 3.5);
+        assert_eq!(modified.epsilon_k, record.epsilon_k);
+        assert_eq!(modified.mu, Some(1.8));
+        assert_eq!(modified.with_q(Some(2.0)).q, Some(2.0));
+    }
+
+    #[test]
+    pub fn test_hard_sphere_diameter() {
+        let params = dme_co2_parameters();
+        let temperature = 300.0;
+        let diameter = params.hs_diameter(temperature);
+        assert!(diameter.iter().zip(&params.sigma).all(|(d, s)| d < s));
+
+        let params = params.with_hard_sphere_diameter(HardSphereDiameter::Sigma);
+        assert_eq!(params.hs_diameter(temperature), params.sigma);
+        assert_eq!(params.hs_diameter(500.0), params.sigma);
+
+        let subset = params.subset(&[1]);
+        assert_eq!(subset.hard_sphere_diameter, HardSphereDiameter::Sigma);
+        assert_eq!(subset.hs_diameter(temperature), subset.sigma);
+    }
+
+    #[test]
+    pub fn test_shape_factor_subset() {
+        let params = dme_co2_parameters()
+            .with_shape_factor(arr1(&[1.5, 2.5]))
+            .unwrap();
+        let subset = params.subset(&[1]);
+        assert_eq!(subset.shape_factor, Some(arr1(&[2.5])));
+        let reversed = params.subset(&[1, 0]);
+        assert_eq!(reversed.shape_factor, Some(arr1(&[2.5, 1.5])));
+    }
+
+    #[test]
+    pub fn test_serde_round_trip() {
+        let water = water_parameters().pure_records[0].model_record.clone();
+        let propane = propane_parameters().pure_records[0].model_record.clone();
+        let record = PcSaftRecord::new(
+            1.5,
+            3.2,
+            250.0,
+            Some(1.2),
+            None,
+            Some(0.01),
+            Some(1500.0),
+            None,
+            None,
+            Some(1.0),
+            None,
+            None,
+            None,
+        );
+        for record in [water, propane, record] {
+            let json = serde_json::to_string(&record).unwrap();
+            let parsed: PcSaftRecord = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed.to_string(), record.to_string());
+            assert_eq!(
+                parsed.association_records.len(),
+                record.association_records.len()
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_validate() {
+        let record = water_parameters().pure_records[0].model_record.clone();
+        assert!(record.validate().is_ok());
+        assert!(record.with_m(0.0).validate().is_err());
+        assert!(record.with_sigma(0.0).validate().is_err());
+        assert!(record.with_sigma(f64::NAN).validate().is_err());
+        assert!(record.with_epsilon_k(-100.0).validate().is_err());
+
+        let mut negative_kappa = record.clone();
+        negative_kappa.association_records[0].parameters.kappa_ab = -0.01;
+        assert!(negative_kappa.validate().is_err());
+
+        let mut no_sites = record.clone();
+        let association_record = &mut no_sites.association_records[0];
+        association_record.na = 0.0;
+        association_record.nb = 0.0;
+        assert!(no_sites.validate().is_err());
+
+        let mut pure_record = water_parameters().pure_records.pop().unwrap();
+        pure_record.model_record = record.with_sigma(0.0);
+        assert!(PcSaftParameters::new_pure(pure_record).is_err());
+    }
+
+    #[test]
+    pub fn test_molar_weight() {
+        let propane = propane_parameters().pure_records[0].clone();
+        let water = water_parameters().pure_records[0].clone();
+        let params = PcSaftParameters::new_binary(vec![propane.clone(), water.clone()], None);
+        assert!(params.unwrap().has_molar_weight());
+
+        let mut no_molar_weight = water.clone();
+        no_molar_weight.molarweight = 0.0;
+        let params = PcSaftParameters::new_binary(vec![propane.clone(), no_molar_weight], None);
+        let err = params.err().unwrap().to_string();
+        assert!(err.contains("molarweight"));
+        assert!(err.contains("name=water"));
+        assert!(err.contains("got molarweight = 0"));
+
+        let mut negative = water;
+        negative.molarweight = -18.0;
+        assert!(PcSaftParameters::new_pure(negative).is_err());
+
+        let params =
+            PcSaftParameters::from_model_records(vec![propane.model_record.clone()]).unwrap();
+        assert!(!params.has_molar_weight());
+
+        // the equation of state reports missing molar weights
+        let mut pure = propane;
+        pure.molarweight = 0.0;
+        let params = Arc::new(PcSaftParameters::new_pure(pure).unwrap());
+        assert!(!params.has_molar_weight());
+        assert!(!PcSaft::new(params).has_molar_weight());
+        assert!(PcSaft::new(propane_parameters()).has_molar_weight());
+    }
+
+    #[test]
+    pub fn test_entropy_scaling_parameters() {
+        let propane = propane_parameters();
+        assert!(propane.has_viscosity_parameters());
+        assert!(propane.has_diffusion_parameters());
+        assert!(propane.has_thermal_conductivity_parameters());
+
+        let co2 = carbon_dioxide_parameters();
+        assert!(!co2.has_viscosity_parameters());
+        assert!(!co2.has_diffusion_parameters());
+        assert!(!co2.has_thermal_conductivity_parameters());
+    }
+
+    #[test]
+    pub fn test_entropy_scaling_coefficient_lengths() {
+        let json = |diffusion: &str| {
+            format!(
+                r#"{{
+                    "m": 2.0,
+                    "sigma": 3.6,
+                    "epsilon_k": 210.0,
+                    "viscosity": [-0.8, -0.5, -0.1, 0.0],
+                    "diffusion": {diffusion}
+                }}"#
+            )
+        };
+        let record: PcSaftRecord =
+            serde_json::from_str(&json("[-0.7, 0.3, 0.1, 0.01, 0.001]")).unwrap();
+        assert_eq!(record.diffusion, Some([-0.7, 0.3, 0.1, 0.01, 0.001]));
+        let serialized = serde_json::to_string(&record).unwrap();
+        let parsed: PcSaftRecord = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed.diffusion, record.diffusion);
+
+        let err = serde_json::from_str::<PcSaftRecord>(&json("[-0.7, 0.3, 0.1, 0.0]"))
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("diffusion coefficient requires 5 coefficients, but 4 were provided"));
+    }
+
+    #[test]
+    pub fn test_diffusion_from_segments() {
+        let segment = |sigma: f64, diffusion: Option<[f64; 5]>| PcSaftRecord {
+            m: 1.0,
+            sigma,
+            epsilon_k: 200.0,
+            diffusion,
+            ..Default::default()
+        };
+        let d1 = [-0.5, 0.2, 0.1, 0.01, 0.001];
+        let d2 = [-0.3, 0.4, 0.2, 0.02, 0.002];
+        let ch3 = segment(3.5, Some(d1));
+        let ch2 = segment(3.9, Some(d2));
+        let record = PcSaftRecord::from_segments(&[(ch3.clone(), 2.0), (ch2, 1.0)]).unwrap();
+        let diffusion = record.diffusion.unwrap();
+        let (s1, s2) = (2.0 * 3.5f64.powi(3), 3.9f64.powi(3));
+        let sigma3 = s1 + s2;
+        assert_relative_eq!(diffusion[0], s1 * d1[0] + s2 * d2[0] + 3.0f64.ln());
+        assert_relative_eq!(diffusion[1], (s1 * d1[1] + s2 * d2[1]) / sigma3.powf(0.45));
+        for k in 2..5 {
+            assert_relative_eq!(diffusion[k], 2.0 * d1[k] + d2[k]);
+        }
+        // reference values
+        assert_relative_eq!(diffusion[0], -59.57208771133189, max_relative = 1e-12);
+        assert_relative_eq!(diffusion[1], 4.35288117386585, max_relative = 1e-12);
+
+        // a single segment without coefficients disables the combination
+        let record = PcSaftRecord::from_segments(&[(ch3, 2.0), (segment(3.9, None), 1.0)]);
+        assert!(record.unwrap().diffusion.is_none());
+    }
+
+    #[test]
+    pub fn test_mass_mole_fraction_conversion() -> Result<(), ParameterError> {
+        let params = propane_butane_parameters();
+        let x = arr1(&[0.3, 0.7]);
+        let w = params.molefracs_to_massfracs(&x)?;
+        assert_relative_eq!(w.sum(), 1.0, max_relative = 1e-14);
+        let mw = &params.molarweight;
+        assert_relative_eq!(w[0], 0.3 * mw[0] / (0.3 * mw[0] + 0.7 * mw[1]));
+        assert_relative_eq!(params.massfracs_to_molefracs(&w)?, x, max_relative = 1e-14);
+
+        assert!(params.molefracs_to_massfracs(&arr1(&[1.0])).is_err());
+        let params = PcSaftParameters::from_model_records(vec![params.pure_records[0]
+            .model_record
+            .clone()])?;
+        assert!(params.massfracs_to_molefracs(&arr1(&[1.0])).is_err());
+        Ok(())
+    }
+
     #[test]
     pub fn test_kij() -> Result<(), ParameterError> {
         let ch3: String = "CH3".into();
@@ -895,4 +1872,17 @@ pub mod utils {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_kij_without_segments() -> Result<(), ParameterError> {
+        let empty: [(f64, f64, f64); 0] = [];
+        let record = PcSaftBinaryRecord::from_segments_binary(&empty)?;
+        assert_eq!(record.k_ij, 0.0);
+        assert!(record.association.is_none());
+
+        let record = PcSaftBinaryRecord::from_segments_binary(&[(-0.1, 0.0, 2.0)])?;
+        assert_eq!(record.k_ij, 0.0);
+        assert!(record.association.is_none());
+        Ok(())
+    }
 }
@@ -1,17 +1,86 @@
-use super::parameters::{PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
-use super::DQVariants;
+use super::parameters::{
+    PcSaftAssociationRecord, PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord,
+};
+use super::{DQVariants, HardSphereDiameter};
+use crate::association::AssociationRecord;
 use feos_core::parameter::{
     BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
     SegmentRecord,
 };
 use feos_core::python::parameter::*;
 use feos_core::*;
-use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
-use pyo3::exceptions::PyTypeError;
+use numpy::{IntoPyArray, PyArray1, PyArray2, PyReadonlyArray1, PyReadonlyArray2, ToPyArray};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use std::collections::hash_map::DefaultHasher;
 use std::convert::{TryFrom, TryInto};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+/// Pure component association parameters.
+///
+/// Parameters
+/// ----------
+/// kappa_ab : float
+///     Association volume parameter.
+/// epsilon_k_ab : float
+///     Association energy parameter in units of Kelvin.
+/// na : float, optional
+///     Number of association sites of type A.
+/// nb : float, optional
+///     Number of association sites of type B.
+/// nc : float, optional
+///     Number of association sites of type C.
+#[pyclass(name = "PcSaftAssociationRecord")]
+#[derive(Clone)]
+pub struct PyPcSaftAssociationRecord(pub AssociationRecord<PcSaftAssociationRecord>);
+
+#[pymethods]
+impl PyPcSaftAssociationRecord {
+    #[new]
+    #[pyo3(signature = (kappa_ab, epsilon_k_ab, na=0.0, nb=0.0, nc=0.0))]
+    fn new(kappa_ab: f64, epsilon_k_ab: f64, na: f64, nb: f64, nc: f64) -> Self {
+        Self(AssociationRecord::new(
+            PcSaftAssociationRecord::new(kappa_ab, epsilon_k_ab),
+            na,
+            nb,
+            nc,
+        ))
+    }
+
+    #[getter]
+    fn get_kappa_ab(&self) -> f64 {
+        self.0.parameters.kappa_ab
+    }
+
+    #[getter]
+    fn get_epsilon_k_ab(&self) -> f64 {
+        self.0.parameters.epsilon_k_ab
+    }
+
+    #[getter]
+    fn get_na(&self) -> f64 {
+        self.0.na
+    }
+
+    #[getter]
+    fn get_nb(&self) -> f64 {
+        self.0.nb
+    }
+
+    #[getter]
+    fn get_nc(&self) -> f64 {
+        self.0.nc
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+impl_json_handling!(PyPcSaftAssociationRecord);
+
 /// Pure-substance parameters for the PC-Saft equation of state.
 ///
 /// Parameters
@@ -113,27 +182,43 @@ impl PyPcSaftRecord {
 
     #[getter]
     fn get_kappa_ab(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.parameters.kappa_ab)
+        self.0
+            .association_records
+            .first()
+            .map(|a| a.parameters.kappa_ab)
     }
 
     #[getter]
     fn get_epsilon_k_ab(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.parameters.epsilon_k_ab)
+        self.0
+            .association_records
+            .first()
+            .map(|a| a.parameters.epsilon_k_ab)
     }
 
     #[getter]
     fn get_na(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.na)
+        self.0.association_records.first().map(|a| a.na)
     }
 
     #[getter]
     fn get_nb(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nb)
+        self.0.association_records.first().map(|a| a.nb)
     }
 
     #[getter]
     fn get_nc(&self) -> Option<f64> {
-        self.0.association_record.map(|a| a.nc)
+        self.0.association_records.first().map(|a| a.nc)
+    }
+
+    #[getter]
+    fn get_association_records(&self) -> Vec<PyPcSaftAssociationRecord> {
+        self.0
+            .association_records
+            .iter()
+            .cloned()
+            .map(PyPcSaftAssociationRecord)
+            .collect()
     }
 
     #[getter]
@@ -151,6 +236,92 @@ impl PyPcSaftRecord {
         self.0.thermal_conductivity
     }
 
+    /// Return a copy of the record with a new segment number.
+    ///
+    /// Parameters
+    /// ----------
+    /// m : float
+    ///     Segment number
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftRecord
+    fn with_m(&self, m: f64) -> Self {
+        Self(self.0.with_m(m))
+    }
+
+    /// Return a copy of the record with a new segment diameter.
+    ///
+    /// Parameters
+    /// ----------
+    /// sigma : float
+    ///     Segment diameter in units of Angstrom.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftRecord
+    fn with_sigma(&self, sigma: f64) -> Self {
+        Self(self.0.with_sigma(sigma))
+    }
+
+    /// Return a copy of the record with a new energetic parameter.
+    ///
+    /// Parameters
+    /// ----------
+    /// epsilon_k : float
+    ///     Energetic parameter in units of Kelvin.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftRecord
+    fn with_epsilon_k(&self, epsilon_k: f64) -> Self {
+        Self(self.0.with_epsilon_k(epsilon_k))
+    }
+
+    /// Return a copy of the record with a new dipole moment.
+    ///
+    /// Parameters
+    /// ----------
+    /// mu : float, optional
+    ///     Dipole moment in units of Debye.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftRecord
+    #[pyo3(signature = (mu=None))]
+    fn with_mu(&self, mu: Option<f64>) -> Self {
+        Self(self.0.with_mu(mu))
+    }
+
+    /// Return a copy of the record with a new quadrupole moment.
+    ///
+    /// Parameters
+    /// ----------
+    /// q : float, optional
+    ///     Quadrupole moment in units of Debye * Angstrom.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftRecord
+    #[pyo3(signature = (q=None))]
+    fn with_q(&self, q: Option<f64>) -> Self {
+        Self(self.0.with_q(q))
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
+        match op {
+            CompareOp::Eq => (self.0 == other.0).into_py(py),
+            CompareOp::Ne => (self.0 != other.0).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
@@ -169,10 +340,24 @@ pub struct PyPcSaftBinaryRecord(PcSaftBinaryRecord);
 #[pymethods]
 impl PyPcSaftBinaryRecord {
     #[new]
-    #[pyo3(text_signature = "(k_ij=None, kappa_ab=None, epsilon_k_ab=None)")]
-    #[pyo3(signature = (k_ij=None, kappa_ab=None, epsilon_k_ab=None))]
-    fn new(k_ij: Option<f64>, kappa_ab: Option<f64>, epsilon_k_ab: Option<f64>) -> Self {
-        Self(PcSaftBinaryRecord::new(k_ij, kappa_ab, epsilon_k_ab))
+    #[pyo3(
+        text_signature = "(k_ij=None, kappa_ab=None, epsilon_k_ab=None, kappa_ba=None, epsilon_k_ba=None)"
+    )]
+    #[pyo3(signature = (k_ij=None, kappa_ab=None, epsilon_k_ab=None, kappa_ba=None, epsilon_k_ba=None))]
+    fn new(
+        k_ij: Option<f64>,
+        kappa_ab: Option<f64>,
+        epsilon_k_ab: Option<f64>,
+        kappa_ba: Option<f64>,
+        epsilon_k_ba: Option<f64>,
+    ) -> Self {
+        Self(PcSaftBinaryRecord::new_asymmetric(
+            k_ij,
+            kappa_ab,
+            epsilon_k_ab,
+            kappa_ba,
+            epsilon_k_ba,
+        ))
     }
 
     fn __repr__(&self) -> PyResult<String> {
@@ -194,7 +379,7 @@ impl_parameter!(
     PyPcSaftRecord,
     PyPcSaftBinaryRecord
 );
-impl_parameter_from_segments!(PcSaftParameters, PyPcSaftParameters);
+impl_parameter_from_segments!(PcSaftParameters, PyPcSaftParameters, without_from_smiles);
 
 #[pymethods]
 impl PyPcSaftParameters {
@@ -206,6 +391,262 @@ impl PyPcSaftParameters {
             .map(|br| br.map(|br| br.k_ij).view().to_pyarray_bound(py))
     }
 
+    /// Molar weights of all components in g/mol.
+    #[getter]
+    fn get_molar_weights<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        self.0.molarweight.to_pyarray_bound(py)
+    }
+
+    /// Combined segment diameters of all pairs of components in Angstrom.
+    #[getter]
+    fn get_sigma_ij<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        self.0.sigma_ij.to_pyarray_bound(py)
+    }
+
+    /// Combined energy parameters of all pairs of components in Kelvin
+    /// including the binary interaction parameters.
+    #[getter]
+    fn get_epsilon_k_ij<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        self.0.epsilon_k_ij.to_pyarray_bound(py)
+    }
+
+    /// Combined energy parameters of all pairs of components in Kelvin
+    /// without the binary interaction parameters.
+    #[getter]
+    fn get_e_k_ij<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        self.0.e_k_ij.to_pyarray_bound(py)
+    }
+
+    /// List of association sites as (component index, site type, number of sites).
+    #[getter]
+    fn get_association_sites(&self) -> Vec<(usize, String, f64)> {
+        self.0.association.sites()
+    }
+
+    /// List of pairs of association sites that can bond,
+    /// given as indices into `association_sites`.
+    #[getter]
+    fn get_association_site_pairs(&self) -> Vec<(usize, usize)> {
+        self.0.association.site_pairs()
+    }
+
+    /// Return `True` if entropy scaling parameters for the
+    /// viscosity are available.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    fn has_viscosity_parameters(&self) -> bool {
+        self.0.has_viscosity_parameters()
+    }
+
+    /// Return `True` if entropy scaling parameters for the
+    /// diffusion coefficient are available.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    fn has_diffusion_parameters(&self) -> bool {
+        self.0.has_diffusion_parameters()
+    }
+
+    /// Return `True` if entropy scaling parameters for the
+    /// thermal conductivity are available.
+    ///
+    /// Returns
+    /// -------
+    /// bool
+    fn has_thermal_conductivity_parameters(&self) -> bool {
+        self.0.has_thermal_conductivity_parameters()
+    }
+
+    /// Convert mass fractions into mole fractions using the
+    /// molar weights of the components.
+    ///
+    /// Parameters
+    /// ----------
+    /// massfracs : numpy.ndarray[float]
+    ///     The mass fractions of all components.
+    ///
+    /// Returns
+    /// -------
+    /// numpy.ndarray[float]
+    #[pyo3(text_signature = "($self, massfracs)")]
+    fn massfracs_to_molefracs<'py>(
+        &self,
+        py: Python<'py>,
+        massfracs: PyReadonlyArray1<f64>,
+    ) -> Result<Bound<'py, PyArray1<f64>>, ParameterError> {
+        let molefracs = self
+            .0
+            .massfracs_to_molefracs(&massfracs.as_array().to_owned())?;
+        Ok(molefracs.into_pyarray_bound(py))
+    }
+
+    /// Convert mole fractions into mass fractions using the
+    /// molar weights of the components.
+    ///
+    /// Parameters
+    /// ----------
+    /// molefracs : numpy.ndarray[float]
+    ///     The mole fractions of all components.
+    ///
+    /// Returns
+    /// -------
+    /// numpy.ndarray[float]
+    #[pyo3(text_signature = "($self, molefracs)")]
+    fn molefracs_to_massfracs<'py>(
+        &self,
+        py: Python<'py>,
+        molefracs: PyReadonlyArray1<f64>,
+    ) -> Result<Bound<'py, PyArray1<f64>>, ParameterError> {
+        let massfracs = self
+            .0
+            .molefracs_to_massfracs(&molefracs.as_array().to_owned())?;
+        Ok(massfracs.into_pyarray_bound(py))
+    }
+
+    /// Return a copy of the parameters that uses the given
+    /// definition of the hard-sphere diameter.
+    ///
+    /// Parameters
+    /// ----------
+    /// hard_sphere_diameter : HardSphereDiameter
+    ///     `HardSphereDiameter.BarkerHenderson` (default) for the
+    ///     temperature-dependent diameter or `HardSphereDiameter.Sigma`
+    ///     to use the segment diameter sigma.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftParameters
+    #[pyo3(text_signature = "($self, hard_sphere_diameter)")]
+    fn with_hard_sphere_diameter(&self, hard_sphere_diameter: HardSphereDiameter) -> Self {
+        Self(Arc::new(
+            self.0.with_hard_sphere_diameter(hard_sphere_diameter),
+        ))
+    }
+
+    /// Return a copy of the parameters that uses the given shape factors
+    /// of the monomers in the hard-sphere contribution instead of the
+    /// segment numbers.
+    ///
+    /// Parameters
+    /// ----------
+    /// shape_factor : numpy.ndarray[float]
+    ///     The shape factor of every component.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftParameters
+    #[pyo3(text_signature = "($self, shape_factor)")]
+    fn with_shape_factor(
+        &self,
+        shape_factor: PyReadonlyArray1<f64>,
+    ) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(
+            self.0
+                .with_shape_factor(shape_factor.as_array().to_owned())?,
+        )))
+    }
+
+    /// Creates parameters for all substances in a CSV file.
+    ///
+    /// Parameters
+    /// ----------
+    /// path : str
+    ///     Path to the CSV file. Required columns are `molarweight`, `m`,
+    ///     `sigma`, `epsilon_k` and the identifier column. Missing optional
+    ///     columns (`mu`, `q`, `kappa_ab`, `epsilon_k_ab`, `na`, `nb`, `nc`)
+    ///     and empty fields are interpreted as `None`. Numerical values
+    ///     have to be finite and quoted fields must not contain line breaks.
+    /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier column that has to be present in the file.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftParameters
+    #[staticmethod]
+    #[pyo3(
+        signature = (path, identifier_option=IdentifierOption::Name),
+        text_signature = "(path, identifier_option)"
+    )]
+    fn from_csv(path: String, identifier_option: IdentifierOption) -> Result<Self, ParameterError> {
+        Ok(Self(Arc::new(PcSaftParameters::from_csv(
+            path,
+            identifier_option,
+        )?)))
+    }
+
+    /// Creates parameters for substances identified by their SMILES strings.
+    ///
+    /// If `smarts_records` is the path to a JSON file containing pure
+    /// substance parameters, the SMILES strings are looked up in that file
+    /// and neither a group contribution method nor rdkit is used. Otherwise,
+    /// the parameters are calculated from SMARTS and segment records, which
+    /// requires an installation of rdkit.
+    ///
+    /// Properties of the groups identified by the SMARTS codes, including
+    /// association sites, are taken from the corresponding segment records.
+    /// E.g., an OH group with one site of type A and one of type B leads to
+    /// one A and one B site for every hydroxyl group in the molecule.
+    ///
+    /// Parameters
+    /// ----------
+    /// identifier : [str | Identifier]
+    ///     A list of SMILES codes or [Identifier] objects. Only SMILES codes
+    ///     are supported for the lookup in a parameter database.
+    /// smarts_records : str | [SmartsRecord]
+    ///     Path to the JSON file containing the pure substance parameters,
+    ///     or a list of records containing the SMARTS codes used to
+    ///     fragment the molecule.
+    /// segment_records : [SegmentRecord], optional
+    ///     A list of records containing the parameters of all individual
+    ///     segments. Required if `smarts_records` is not a path.
+    /// binary_segment_records : [BinarySegmentRecord], optional
+    ///     A list of binary segment-segment parameters.
+    ///
+    /// Returns
+    /// -------
+    /// PcSaftParameters
+    ///
+    /// Raises
+    /// ------
+    /// RuntimeError
+    ///     If SMILES codes are not contained in the parameter database.
+    #[staticmethod]
+    #[pyo3(
+        signature = (identifier, smarts_records, segment_records=None, binary_segment_records=None),
+        text_signature = "(identifier, smarts_records, segment_records=None, binary_segment_records=None)"
+    )]
+    fn from_smiles(
+        identifier: Vec<Bound<'_, PyAny>>,
+        smarts_records: Bound<'_, PyAny>,
+        segment_records: Option<Vec<PySegmentRecord>>,
+        binary_segment_records: Option<Vec<PyBinarySegmentRecord>>,
+    ) -> PyResult<Self> {
+        if let Ok(path) = smarts_records.extract::<String>() {
+            let smiles_list = identifier
+                .iter()
+                .map(|i| i.extract::<String>())
+                .collect::<PyResult<Vec<_>>>()?;
+            let smiles: Vec<_> = smiles_list.iter().map(String::as_str).collect();
+            return Ok(Self(Arc::new(PcSaftParameters::from_smiles_database(
+                &smiles, path,
+            )?)));
+        }
+        let segment_records = segment_records.ok_or_else(|| {
+            PyValueError::new_err(
+                "`segment_records` are required to build parameters from SMARTS records.",
+            )
+        })?;
+        Self::from_smiles_segments(
+            identifier,
+            smarts_records.extract()?,
+            segment_records,
+            binary_segment_records,
+        )
+    }
+
     fn _repr_markdown_(&self) -> String {
         self.0.to_markdown()
     }
@@ -219,6 +660,8 @@ pub fn pcsaft(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySmartsRecord>()?;
 
     m.add_class::<DQVariants>()?;
+    m.add_class::<HardSphereDiameter>()?;
+    m.add_class::<PyPcSaftAssociationRecord>()?;
     m.add_class::<PyPcSaftRecord>()?;
     m.add_class::<PyPcSaftBinaryRecord>()?;
     m.add_class::<PyPureRecord>()?;
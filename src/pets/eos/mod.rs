@@ -1,5 +1,5 @@
 use super::parameters::PetsParameters;
-use crate::hard_sphere::HardSphere;
+use crate::hard_sphere::{HardSphere, HardSphereProperties};
 use feos_core::parameter::Parameter;
 use feos_core::{Components, Molarweight, Residual};
 use ndarray::Array1;
@@ -74,6 +74,10 @@ impl Residual for Pets {
             / (FRAC_PI_6 * self.parameters.sigma.mapv(|v| v.powi(3)) * moles).sum()
     }
 
+    fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+        Some(self.parameters.zeta(temperature, partial_density, [3])[0])
+    }
+
     fn residual_helmholtz_energy_contributions<D: num_dual::DualNum<f64> + Copy>(
         &self,
         state: &feos_core::StateHD<D>,
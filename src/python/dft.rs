@@ -100,6 +100,7 @@ impl PyFunctionalVariant {
             max_iter_cross_assoc,
             tol_cross_assoc,
             dq_variant,
+            ..Default::default()
         };
         let func = PcSaftFunctional::with_options(parameters.0, fmt_version, options);
         Self::new(func)
@@ -248,9 +249,12 @@ impl_estimator!(DFT<Functional>, PyFunctionalVariant);
 pub fn dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Contributions>()?;
     m.add_class::<Verbosity>()?;
+    m.add_class::<PhaseIdentification>()?;
+    m.add_class::<SolverStats>()?;
 
     m.add_class::<PyFunctionalVariant>()?;
     m.add_class::<PyState>()?;
+    m.add_class::<PyStateBuilder>()?;
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
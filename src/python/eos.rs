@@ -39,6 +39,7 @@ use super::dippr::PyDippr;
 use super::joback::PyJoback;
 use feos_core::cubic::PengRobinson;
 use feos_core::python::cubic::PyPengRobinsonParameters;
+use feos_core::python::parameter::PyIdentifier;
 use feos_core::python::user_defined::{PyIdealGas, PyResidual};
 use feos_core::*;
 use ndarray::{Array1, Array2};
@@ -54,6 +55,79 @@ use std::convert::TryInto;
 use std::sync::Arc;
 use typenum::{Quot, P3};
 
+/// Ideal gas model that can be combined with any residual model.
+#[pyclass(name = "IdealGasModel")]
+#[derive(Clone)]
+pub struct PyIdealGasModel(pub Arc<IdealGasModel>);
+
+#[pymethods]
+impl PyIdealGasModel {
+    /// Ideal gas model of Joback and Reid.
+    ///
+    /// Parameters
+    /// ----------
+    /// joback : Joback
+    ///     The parametrized Joback model.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasModel
+    #[staticmethod]
+    fn joback(joback: PyJoback) -> Self {
+        Self(Arc::new(IdealGasModel::Joback(joback.0)))
+    }
+
+    /// Ideal gas model based on DIPPR equations for the ideal
+    /// gas heat capacity.
+    ///
+    /// Parameters
+    /// ----------
+    /// dippr : Dippr
+    ///     The parametrized Dippr model.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasModel
+    #[staticmethod]
+    fn dippr(dippr: PyDippr) -> Self {
+        Self(Arc::new(IdealGasModel::Dippr(dippr.0)))
+    }
+
+    /// Ideal gas model from a Python class.
+    ///
+    /// Parameters
+    /// ----------
+    /// ideal_gas : Class
+    ///     A python class implementing the necessary methods
+    ///     to be used as an ideal gas model.
+    ///
+    /// Returns
+    /// -------
+    /// IdealGasModel
+    #[staticmethod]
+    fn python(ideal_gas: Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self(Arc::new(IdealGasModel::Python(PyIdealGas::new(
+            ideal_gas,
+        )?))))
+    }
+
+    /// The name of the ideal gas model.
+    #[getter]
+    fn get_name(&self) -> String {
+        self.0.ideal_gas_model()
+    }
+
+    /// The number of components of the ideal gas model.
+    #[getter]
+    fn get_components(&self) -> usize {
+        self.0.components()
+    }
+
+    fn __repr__(&self) -> String {
+        self.0.ideal_gas_model()
+    }
+}
+
 /// Collection of equations of state.
 #[pyclass(name = "EquationOfState")]
 #[derive(Clone)]
@@ -75,6 +149,9 @@ impl PyEquationOfState {
     ///     Tolerance for convergence of cross association. Defaults to 1e-10.
     /// dq_variant : DQVariants, optional
     ///     Combination rule used in the dipole/quadrupole term. Defaults to 'DQVariants.DQ35'
+    /// simplified : bool, optional
+    ///     Use the averaged segment diameter of the simplified PC-SAFT
+    ///     in the hard-sphere and hard-chain contributions. Defaults to False.
     ///
     /// Returns
     /// -------
@@ -84,8 +161,8 @@ impl PyEquationOfState {
     #[cfg(feature = "pcsaft")]
     #[staticmethod]
     #[pyo3(
-        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35),
-        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant)"
+        signature = (parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant=DQVariants::DQ35, simplified=false),
+        text_signature = "(parameters, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, dq_variant, simplified=False)"
     )]
     pub fn pcsaft(
         parameters: PyPcSaftParameters,
@@ -93,12 +170,14 @@ impl PyEquationOfState {
         max_iter_cross_assoc: usize,
         tol_cross_assoc: f64,
         dq_variant: DQVariants,
+        simplified: bool,
     ) -> Self {
         let options = PcSaftOptions {
             max_eta,
             max_iter_cross_assoc,
             tol_cross_assoc,
             dq_variant,
+            simplified,
         };
         let residual = Arc::new(ResidualModel::PcSaft(PcSaft::with_options(
             parameters.0,
@@ -402,7 +481,7 @@ impl PyEquationOfState {
     /// -------
     /// EquationOfState
     fn python_ideal_gas(&self, ideal_gas: Bound<'_, PyAny>) -> PyResult<Self> {
-        Ok(self.add_ideal_gas(IdealGasModel::Python(PyIdealGas::new(ideal_gas)?)))
+        self.add_ideal_gas(Arc::new(IdealGasModel::Python(PyIdealGas::new(ideal_gas)?)))
     }
 
     /// Ideal gas model of Joback and Reid.
@@ -415,8 +494,8 @@ impl PyEquationOfState {
     /// Returns
     /// -------
     /// EquationOfState
-    fn joback(&self, joback: PyJoback) -> Self {
-        self.add_ideal_gas(IdealGasModel::Joback(joback.0))
+    fn joback(&self, joback: PyJoback) -> PyResult<Self> {
+        self.add_ideal_gas(Arc::new(IdealGasModel::Joback(joback.0)))
     }
 
     /// Ideal gas model based on DIPPR equations for the ideal
@@ -430,23 +509,239 @@ impl PyEquationOfState {
     /// Returns
     /// -------
     /// EquationOfState
-    fn dippr(&self, dippr: PyDippr) -> Self {
-        self.add_ideal_gas(IdealGasModel::Dippr(dippr.0))
+    fn dippr(&self, dippr: PyDippr) -> PyResult<Self> {
+        self.add_ideal_gas(Arc::new(IdealGasModel::Dippr(dippr.0)))
+    }
+
+    /// Combine the residual model with the given ideal gas model.
+    ///
+    /// An existing ideal gas model is replaced.
+    ///
+    /// Parameters
+    /// ----------
+    /// ideal_gas : IdealGasModel
+    ///     The ideal gas model.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the numbers of components of the ideal gas model
+    ///     and the residual model differ.
+    fn with_ideal_gas(&self, ideal_gas: PyIdealGasModel) -> PyResult<Self> {
+        let residual_components = match self.0.residual.as_ref() {
+            ResidualModel::NoResidual(_) => None,
+            residual => Some(residual.components()),
+        };
+        match residual_components {
+            Some(n) if n != ideal_gas.0.components() => Err(PyValueError::new_err(format!(
+                "The ideal gas model has {} components, but the residual model has {n}.",
+                ideal_gas.0.components()
+            ))),
+            _ => self.add_ideal_gas(ideal_gas.0),
+        }
+    }
+
+    /// The ideal gas model or None, if no ideal gas model is set.
+    #[getter]
+    fn get_ideal_gas(&self) -> Option<PyIdealGasModel> {
+        match self.0.ideal_gas.as_ref() {
+            IdealGasModel::NoModel(_) => None,
+            _ => Some(PyIdealGasModel(self.0.ideal_gas.clone())),
+        }
+    }
+
+    /// The name of the ideal gas model or None, if no ideal gas
+    /// model is set.
+    #[getter]
+    fn get_ideal_gas_model(&self) -> Option<String> {
+        self.get_ideal_gas().map(|ideal_gas| ideal_gas.get_name())
+    }
+
+    /// Calculate the molar weight of a mixture.
+    ///
+    /// Parameters
+    /// ----------
+    /// molefracs : numpy.ndarray[float]
+    ///     The mole fraction of each component.
+    ///
+    /// Returns
+    /// -------
+    /// SINumber
+    fn molar_weight_of_mixture(
+        &self,
+        molefracs: &Bound<'_, PyArray1<f64>>,
+    ) -> PyResult<MolarWeight> {
+        if !self.0.residual.has_molar_weight() {
+            return Err(PyValueError::new_err(
+                "The residual model does not provide molar weights.",
+            ));
+        }
+        let molefracs = molefracs.to_owned_array();
+        if molefracs.len() != self.0.components() {
+            return Err(
+                EosError::IncompatibleComposition(self.0.components(), molefracs.len()).into(),
+            );
+        }
+        Ok(self.0.molar_weight_of_mixture(&molefracs))
+    }
+
+    /// Return the identifiers of all components in the order used by the model.
+    ///
+    /// Returns
+    /// -------
+    /// List[Identifier]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the residual model is not constructed from pure-component records.
+    fn component_identifiers(&self) -> PyResult<Vec<PyIdentifier>> {
+        self.0
+            .residual
+            .component_identifiers()
+            .map(|identifiers| identifiers.into_iter().map(PyIdentifier).collect())
+            .ok_or_else(|| {
+                PyValueError::new_err("The residual model does not provide component identifiers.")
+            })
+    }
+
+    /// Return a copy of the PC-SAFT equation of state with a different
+    /// combination rule in the dipole/quadrupole term.
+    ///
+    /// Parameters
+    /// ----------
+    /// dq_variant : DQVariants
+    ///     Combination rule used in the dipole/quadrupole term.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    #[cfg(feature = "pcsaft")]
+    fn with_dq_variant(&self, dq_variant: DQVariants) -> PyResult<Self> {
+        let ResidualModel::PcSaft(pcsaft) = self.0.residual.as_ref() else {
+            return Err(PyValueError::new_err("The residual model is not PC-SAFT."));
+        };
+        let residual = Arc::new(ResidualModel::PcSaft(pcsaft.with_dq_variant(dq_variant)));
+        let eos = EquationOfState {
+            residual,
+            ..(*self.0).clone()
+        };
+        Ok(Self(Arc::new(eos.update_reference_values()?)))
+    }
+
+    /// Return a copy of the equation of state with a scaled maximum density.
+    ///
+    /// The maximum density is used to initialize liquid densities and
+    /// bounds the density iteration. Increase the factor, if liquid
+    /// densities exceed the maximum density of the model.
+    ///
+    /// Parameters
+    /// ----------
+    /// max_density_scaling : float
+    ///     Factor by which the maximum density is scaled.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    fn with_max_density_scaling(&self, max_density_scaling: f64) -> PyResult<Self> {
+        Ok(Self(Arc::new(
+            self.0.with_max_density_scaling(max_density_scaling)?,
+        )))
+    }
+
+    /// The factor by which the maximum density of the model is scaled.
+    #[getter]
+    fn get_max_density_scaling(&self) -> f64 {
+        self.0.max_density_scaling
+    }
+
+    /// Return a copy of the equation of state without the given
+    /// residual Helmholtz energy contribution.
+    ///
+    /// Parameters
+    /// ----------
+    /// name : str
+    ///     The name of the contribution, as returned by
+    ///     `State.residual_helmholtz_energy_contributions`.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    fn without_contribution(&self, name: &str) -> PyResult<Self> {
+        Ok(Self(Arc::new(self.0.without_contribution(name)?)))
+    }
+
+    /// Return a copy of the equation of state in which the given
+    /// residual Helmholtz energy contribution is enabled again.
+    ///
+    /// Parameters
+    /// ----------
+    /// name : str
+    ///     The name of the contribution.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    fn with_contribution(&self, name: &str) -> Self {
+        Self(Arc::new(self.0.with_contribution(name)))
+    }
+
+    /// The names of the disabled residual Helmholtz energy contributions.
+    #[getter]
+    fn get_disabled_contributions(&self) -> Vec<String> {
+        self.0.disabled_contributions.clone()
+    }
+
+    /// Return a copy of the equation of state in which the molar
+    /// enthalpy and entropy of every pure component vanish in the
+    /// given reference state.
+    ///
+    /// Parameters
+    /// ----------
+    /// pressure : SINumber
+    ///     The reference pressure.
+    /// temperature : SINumber, optional
+    ///     The reference temperature. If it is not given, the saturated
+    ///     liquid at the reference pressure is used as reference state,
+    ///     e.g., the normal boiling point for a pressure of 1 atm.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    #[pyo3(signature = (pressure, temperature=None), text_signature = "($self, pressure, temperature=None)")]
+    fn with_reference_state(
+        &self,
+        pressure: Pressure,
+        temperature: Option<Temperature>,
+    ) -> PyResult<Self> {
+        let reference_state = match temperature {
+            Some(t) => ReferenceState::TemperaturePressure(t, pressure),
+            None => ReferenceState::SaturatedLiquid(pressure),
+        };
+        Ok(Self(Arc::new(
+            self.0.with_reference_state(reference_state)?,
+        )))
     }
 }
 
 impl PyEquationOfState {
-    fn add_ideal_gas(&self, ideal_gas: IdealGasModel) -> Self {
+    fn add_ideal_gas(&self, ideal_gas: Arc<IdealGasModel>) -> PyResult<Self> {
         let residual = match self.0.residual.as_ref() {
             ResidualModel::NoResidual(_) => Arc::new(ResidualModel::NoResidual(NoResidual(
                 ideal_gas.components(),
             ))),
             _ => self.0.residual.clone(),
         };
-        Self(Arc::new(EquationOfState::new(
-            Arc::new(ideal_gas),
+        let eos = EquationOfState {
+            ideal_gas,
             residual,
-        )))
+            ..(*self.0).clone()
+        };
+        Ok(Self(Arc::new(eos.update_reference_values()?)))
     }
 }
 
@@ -465,9 +760,13 @@ impl_estimator_entropy_scaling!(EquationOfState<IdealGasModel, ResidualModel>, P
 pub fn eos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Contributions>()?;
     m.add_class::<Verbosity>()?;
+    m.add_class::<PhaseIdentification>()?;
+    m.add_class::<SolverStats>()?;
 
     m.add_class::<PyEquationOfState>()?;
+    m.add_class::<PyIdealGasModel>()?;
     m.add_class::<PyState>()?;
+    m.add_class::<PyStateBuilder>()?;
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
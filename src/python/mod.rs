@@ -13,8 +13,9 @@ use crate::saftvrqmie::python::saftvrqmie as saftvrqmie_module;
 #[cfg(feature = "uvtheory")]
 use crate::uvtheory::python::uvtheory as uvtheory_module;
 
+use feos_core::python::{reduce, to_si};
 use pyo3::prelude::*;
-use pyo3::wrap_pymodule;
+use pyo3::{wrap_pyfunction, wrap_pymodule};
 
 mod cubic;
 mod dippr;
@@ -33,6 +34,8 @@ use dft::dft as dft_module;
 #[pymodule]
 pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_function(wrap_pyfunction!(reduce, m)?)?;
+    m.add_function(wrap_pyfunction!(to_si, m)?)?;
     // m.add_wrapped(wrap_pymodule!(quantity_module))?;
 
     m.add_wrapped(wrap_pymodule!(eos_module))?;
@@ -1,4 +1,4 @@
-use crate::hard_sphere::HardSphere;
+use crate::hard_sphere::{HardSphere, HardSphereProperties};
 
 use super::SaftVRMieParameters;
 use association::Association;
@@ -83,6 +83,10 @@ impl Residual for SaftVRMie {
                 .sum()
     }
 
+    fn packing_fraction(&self, temperature: f64, partial_density: &Array1<f64>) -> Option<f64> {
+        Some(self.parameters.zeta(temperature, partial_density, [3])[0])
+    }
+
     fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
         &self,
         state: &StateHD<D>,
@@ -1,7 +1,8 @@
 use super::parameters::SaftVRQMieParameters;
 use feos_core::parameter::{Parameter, ParameterError};
 use feos_core::{
-    Components, EntropyScaling, EosError, EosResult, Molarweight, ReferenceSystem, Residual, State,
+    validate_residual_entropy, Components, EntropyScaling, EosError, EosResult, Molarweight,
+    ReferenceSystem, Residual, State,
 };
 use ndarray::{Array1, Array2};
 use num_dual::DualNum;
@@ -257,6 +258,7 @@ impl EntropyScaling for SaftVRQMie {
     }
 
     fn viscosity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        validate_residual_entropy("viscosity", s_res)?;
         let coefficients = self
             .parameters
             .viscosity
@@ -294,6 +296,7 @@ impl EntropyScaling for SaftVRQMie {
     }
 
     fn diffusion_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        validate_residual_entropy("diffusion", s_res)?;
         if self.components() != 1 {
             return Err(EosError::IncompatibleComponents(self.components(), 1));
         }
@@ -351,6 +354,7 @@ impl EntropyScaling for SaftVRQMie {
     }
 
     fn thermal_conductivity_correlation(&self, s_res: f64, x: &Array1<f64>) -> EosResult<f64> {
+        validate_residual_entropy("thermal conductivity", s_res)?;
         if self.components() != 1 {
             return Err(EosError::IncompatibleComponents(self.components(), 1));
         }
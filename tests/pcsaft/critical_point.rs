@@ -1,7 +1,10 @@
 use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::State;
+use feos_core::{
+    Components, Contributions, CriticalPointSpecification, CriticalityCondition, EosError,
+    SolverOptions, State,
+};
 use ndarray::arr1;
 use quantity::*;
 use std::error::Error;
@@ -28,6 +31,127 @@ fn test_critical_point_pure() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_critical_compressibility() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cp = State::critical_point(&saft, None, None, Default::default())?;
+    let z_c = cp.compressibility(Contributions::Total);
+    assert_relative_eq!(
+        z_c,
+        (cp.pressure(Contributions::Total) / (cp.density * RGAS * cp.temperature)).into_value(),
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(z_c, 0.31216027, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_pure_bisection() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    // starting at 20 K, the Newton iteration converges to a spurious
+    // solution at about 15 K with negative pressure
+    let t = 20.0 * KELVIN;
+    let (cp, stats) = State::critical_point_with_stats(&saft, None, Some(t), Default::default())?;
+    assert!(stats.converged);
+    assert!(stats.iterations > 20);
+    assert_relative_eq!(cp.temperature, 375.12441 * KELVIN, max_relative = 1e-8);
+    assert_relative_eq!(
+        cp.density,
+        4733.00377 * MOL / METER.powi::<P3>(),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_pure_subset() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane", "hexane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let all = State::critical_point_pure(&saft, None, Default::default())?;
+    assert_eq!(all.len(), 3);
+    let cp = State::critical_point_pure_subset(&saft, None, &[0, 2], Default::default())?;
+    assert_eq!(cp.len(), 2);
+    assert_relative_eq!(cp[0].temperature, all[0].temperature, max_relative = 1e-12);
+    assert_relative_eq!(cp[1].temperature, all[2].temperature, max_relative = 1e-12);
+    assert!(cp[0].temperature < cp[1].temperature);
+    assert!(matches!(
+        State::critical_point_pure_subset(&saft, None, &[3], Default::default()),
+        Err(EosError::IncompatibleComponents(3, 4))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_subset() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let propane = Arc::new(saft.subset(&[0]));
+    assert_eq!(propane.components(), 1);
+    let cp_pure = State::critical_point_pure(&saft, None, Default::default())?;
+    let cp = State::critical_point(&propane, None, None, Default::default())?;
+    assert_relative_eq!(cp.temperature, cp_pure[0].temperature, max_relative = 1e-12);
+    assert_relative_eq!(cp.density, cp_pure[0].density, max_relative = 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_residuals() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[1.5, 1.5]) * MOL;
+    let cp = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+    let (eigenvalue, cubic) = cp.critical_point_residuals();
+    assert!(eigenvalue.abs() < 1e-8);
+    assert!(cubic.abs() < 1e-6);
+
+    let state = State::new_nvt(&saft, 1.01 * cp.temperature, cp.volume, &moles)?;
+    let (eigenvalue, _) = state.critical_point_residuals();
+    assert!(eigenvalue.abs() > 1e-3);
+    let state = State::new_nvt(&saft, cp.temperature, 1.2 * cp.volume, &moles)?;
+    let (_, cubic) = state.critical_point_residuals();
+    assert!(cubic.abs() > 1e-3);
+
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cp = State::critical_point(&saft, None, None, Default::default())?;
+    let (eigenvalue, cubic) = cp.critical_point_residuals();
+    assert!(eigenvalue.abs() < 1e-8);
+    assert!(cubic.abs() < 1e-6);
+    Ok(())
+}
+
 #[test]
 fn test_critical_point_mix() -> Result<(), Box<dyn Error>> {
     let params = PcSaftParameters::from_json(
@@ -48,3 +172,132 @@ fn test_critical_point_mix() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_critical_point_with_stats() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let (cp, easy) =
+        State::critical_point_with_stats(&saft, None, Some(375.0 * KELVIN), Default::default())?;
+    assert!(easy.converged);
+    assert!(easy.residual < 1e-8);
+    assert_relative_eq!(cp.temperature, 375.12441 * KELVIN, max_relative = 1e-8);
+
+    let (_, hard) =
+        State::critical_point_with_stats(&saft, None, Some(200.0 * KELVIN), Default::default())?;
+    assert!(hard.converged);
+    assert!(hard.iterations > easy.iterations);
+
+    let options = SolverOptions::new().max_iter(2);
+    let (_, stats) = State::critical_point_with_stats(&saft, None, Some(200.0 * KELVIN), options)?;
+    assert!(!stats.converged);
+    assert_eq!(stats.iterations, 2);
+    assert!(State::critical_point(&saft, None, Some(200.0 * KELVIN), options).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_spinodal_with_stats() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let t = 300.0 * KELVIN;
+    let [(vapor, stats_vapor), (liquid, stats_liquid)] =
+        State::spinodal_with_stats(&saft, t, None, Default::default())?;
+    assert!(stats_vapor.converged && stats_liquid.converged);
+    let [spinodal_vapor, spinodal_liquid] = State::spinodal(&saft, t, None, Default::default())?;
+    assert_relative_eq!(vapor.density, spinodal_vapor.density, max_relative = 1e-12);
+    assert_relative_eq!(
+        liquid.density,
+        spinodal_liquid.density,
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_binary_continuation() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let cp = State::critical_point_binary(&saft, 385.0 * KELVIN, None, None, Default::default())?;
+
+    let t = 387.0 * KELVIN;
+    let options = SolverOptions::new().max_iter(4);
+    assert!(State::critical_point_binary(&saft, t, None, None, options).is_err());
+    let warm = State::critical_point_binary_from_state(&saft, t, &cp, options)?;
+    let cold = State::critical_point_binary(&saft, t, None, None, Default::default())?;
+    assert_relative_eq!(warm.temperature, t, max_relative = 1e-14);
+    assert_relative_eq!(warm.molefracs, cold.molefracs, max_relative = 1e-8);
+    assert_relative_eq!(warm.density, cold.density, max_relative = 1e-8);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point_binary_not_converged() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let options = SolverOptions::new().max_iter(3);
+    let t = 387.0 * KELVIN;
+    match State::critical_point_binary(&saft, t, None, Some([0.99, 0.01]), options) {
+        Err(EosError::CriticalPointNotConverged {
+            specification,
+            residual,
+            iterations,
+            ..
+        }) => {
+            assert_eq!(specification, CriticalPointSpecification::Temperature);
+            assert!(residual > 0.0);
+            assert_eq!(iterations, 3);
+        }
+        Err(e) => panic!("expected CriticalPointNotConverged, got {e:?}"),
+        Ok(_) => panic!("critical point calculation should not converge"),
+    }
+
+    let p = 40.0 * BAR;
+    match State::critical_point_binary(&saft, p, Some(250.0 * KELVIN), None, options) {
+        Err(EosError::CriticalPointNotConverged {
+            specification,
+            residual,
+            ..
+        }) => {
+            assert_eq!(specification, CriticalPointSpecification::Pressure);
+            assert!(residual > 0.0);
+        }
+        Err(e) => panic!("expected CriticalPointNotConverged, got {e:?}"),
+        Ok(_) => panic!("critical point calculation should not converge"),
+    }
+
+    let moles = arr1(&[0.5, 0.5]) * MOL;
+    match State::critical_point(&saft, Some(&moles), None, SolverOptions::new().max_iter(2)) {
+        Err(EosError::CriticalPointNotConverged {
+            specification,
+            condition,
+            ..
+        }) => {
+            assert_eq!(specification, CriticalPointSpecification::Composition);
+            assert_ne!(condition, CriticalityCondition::Pressure);
+        }
+        Err(e) => panic!("expected CriticalPointNotConverged, got {e:?}"),
+        Ok(_) => panic!("critical point calculation should not converge"),
+    }
+    Ok(())
+}
@@ -0,0 +1,319 @@
+use approx::assert_relative_eq;
+use feos::estimator::{
+    DataSet, DataSetCache, Estimator, EstimatorError, LiquidDensity, Loss, Phase, VaporPressure,
+    Viscosity,
+};
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
+use feos_core::{DensityInitialization, EosError, State};
+use ndarray::{arr1, s, Array1};
+use quantity::*;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+#[test]
+fn cost_per_dataset() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    let vapor_pressure: Arc<dyn DataSet<PcSaft>> = Arc::new(VaporPressure::new(
+        arr1(&[2.0, 10.5, 30.0]) * BAR,
+        arr1(&[250.0, 300.0, 350.0]) * KELVIN,
+        false,
+        None,
+        None,
+    ));
+    let liquid_density: Arc<dyn DataSet<PcSaft>> = Arc::new(LiquidDensity::new(
+        arr1(&[560.0, 490.0]) * KILOGRAM / METER.powi::<P3>(),
+        arr1(&[250.0, 300.0]) * KELVIN,
+        arr1(&[20.0, 20.0]) * BAR,
+    ));
+    let estimator = Estimator::new(
+        vec![vapor_pressure, liquid_density],
+        vec![3.0, 1.0],
+        vec![Loss::Linear, Loss::Huber(0.05)],
+    );
+
+    let cost = estimator.cost(&saft)?;
+    let cost_per_dataset = estimator.cost_per_dataset(&saft)?;
+    assert_eq!(cost_per_dataset.len(), 2);
+    assert_eq!(cost_per_dataset[0].0, "vapor pressure");
+    assert_eq!(cost_per_dataset[1].0, "liquid density");
+    let total: f64 = cost_per_dataset.iter().map(|(_, c)| c).sum();
+    assert_relative_eq!(total, cost.dot(&cost), max_relative = 1e-12);
+    assert_relative_eq!(
+        cost_per_dataset[0].1,
+        cost.slice(s![..3]).dot(&cost.slice(s![..3])),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn predict_vs_target() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    let target = arr1(&[2.0, 10.5, 30.0]) * BAR;
+    let vapor_pressure = VaporPressure::new(
+        target.clone(),
+        arr1(&[250.0, 300.0, 350.0]) * KELVIN,
+        false,
+        None,
+        None,
+    );
+    let (prediction, target_out) = vapor_pressure.predict_vs_target(&saft)?;
+    assert_eq!(prediction.len(), target_out.len());
+    assert_eq!(target_out.len(), 3);
+    assert_eq!(prediction, vapor_pressure.predict(&saft)?);
+    assert_relative_eq!(
+        target_out,
+        (target / PASCAL).into_value(),
+        max_relative = 1e-14
+    );
+    Ok(())
+}
+
+#[test]
+fn critical_point_cache() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    let datasets: Vec<Arc<dyn DataSet<PcSaft>>> = vec![
+        Arc::new(VaporPressure::new(
+            arr1(&[2.0, 10.5]) * BAR,
+            arr1(&[250.0, 300.0]) * KELVIN,
+            false,
+            None,
+            None,
+        )),
+        Arc::new(VaporPressure::new(
+            arr1(&[4.8, 10.5]) * BAR,
+            arr1(&[270.0, 300.0]) * KELVIN,
+            false,
+            None,
+            None,
+        )),
+    ];
+    let estimator = Estimator::new(
+        datasets.clone(),
+        vec![1.0, 1.0],
+        vec![Loss::Linear, Loss::Linear],
+    );
+
+    let cache = DataSetCache::new();
+    let cost = estimator.cost_with_cache(&saft, &cache)?;
+    assert_eq!(cache.critical_point_solves(), 1);
+
+    let solves: usize = datasets
+        .iter()
+        .map(|d| {
+            let cache = DataSetCache::new();
+            d.cost_with_cache(&saft, Loss::Linear, &cache).unwrap();
+            cache.critical_point_solves()
+        })
+        .sum();
+    assert_eq!(solves, 2);
+    assert_relative_eq!(cost, estimator.cost(&saft)?, max_relative = 1e-12);
+
+    // a different initial temperature is not served from the cache
+    let other = VaporPressure::new(
+        arr1(&[30.0]) * BAR,
+        arr1(&[350.0]) * KELVIN,
+        false,
+        None,
+        None,
+    );
+    other.cost_with_cache(&saft, Loss::Linear, &cache)?;
+    assert_eq!(cache.critical_point_solves(), 2);
+    datasets[0].cost_with_cache(&saft, Loss::Linear, &cache)?;
+    assert_eq!(cache.critical_point_solves(), 2);
+    Ok(())
+}
+
+#[test]
+fn viscosity() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let mut record = params.pure_records[0].clone();
+    record.model_record.viscosity = Some([-0.8013, -1.9972, -0.2907, -0.0467]);
+    let saft = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_pure(
+        record.clone(),
+    )?)));
+
+    // viscosities of propane calculated with the fitted coefficients
+    let temperature = arr1(&[250.0, 300.0, 350.0, 300.0]) * KELVIN;
+    let pressure = arr1(&[20.0, 20.0, 20.0, 1.0]) * BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let states = temperature
+        .into_iter()
+        .zip(&pressure)
+        .map(|(t, p)| State::new_npt(&saft, t, p, &moles, DensityInitialization::None))
+        .collect::<Result<Vec<_>, _>>()?;
+    let target = states
+        .iter()
+        .map(|s| s.viscosity().map(|v| v.convert_to(MILLI * PASCAL * SECOND)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let target = Array1::from_vec(target) * MILLI * PASCAL * SECOND;
+    let density = states
+        .iter()
+        .map(|s| s.density.convert_to(MOL / METER.powi::<P3>()));
+    let density = Array1::from_iter(density) * MOL / METER.powi::<P3>();
+
+    let pressure_data = Viscosity::new(target.clone(), temperature.clone(), pressure.clone(), None);
+    let density_data = Viscosity::new_density(target, temperature, density.clone());
+    assert_eq!(pressure_data.pressure(), Some(&pressure));
+    assert!(pressure_data.density().is_none());
+    assert_eq!(density_data.density(), Some(&density));
+    assert!(density_data.pressure().is_none());
+    assert_eq!(
+        DataSet::<PcSaft>::input_str(&density_data),
+        vec!["temperature", "density"]
+    );
+    for data in [&pressure_data, &density_data] {
+        assert!(data.mean_absolute_relative_difference(&saft)? < 1e-10);
+    }
+
+    // perturbed coefficients result in a larger deviation
+    record.model_record.viscosity = Some([-0.7, -1.9972, -0.2907, -0.0467]);
+    let perturbed = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_pure(record)?)));
+    assert!(pressure_data.mean_absolute_relative_difference(&perturbed)? > 1e-2);
+
+    // points that can not be evaluated are not finite and can be penalized
+    let failing_data: Arc<dyn DataSet<PcSaft>> = Arc::new(Viscosity::new(
+        arr1(&[0.1, 0.1]) * MILLI * PASCAL * SECOND,
+        arr1(&[300.0, 300.0]) * KELVIN,
+        arr1(&[20.0, -10.0]) * BAR,
+        Some(&vec![Phase::Liquid, Phase::Vapor]),
+    ));
+    let prediction = failing_data.predict(&saft)?;
+    assert!(prediction[0].is_finite() && prediction[1].is_nan());
+    let estimator = Estimator::new(vec![failing_data], vec![1.0], vec![Loss::Linear])
+        .with_non_finite_penalty(1e3)?;
+    assert_eq!(estimator.cost(&saft)?[1], 1e3);
+
+    // missing coefficients are reported as error
+    let without_coefficients = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?)));
+    assert!(matches!(
+        pressure_data.predict(&without_coefficients),
+        Err(EstimatorError::EosError(EosError::ParameterError(
+            ParameterError::MissingParameters(_)
+        )))
+    ));
+    Ok(())
+}
+
+#[test]
+fn mean_absolute_difference() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    // shift all predictions by the same absolute amount
+    let temperature = arr1(&[150.0, 250.0, 300.0]) * KELVIN;
+    let prediction = VaporPressure::new(
+        arr1(&[1.0, 1.0, 1.0]) * BAR,
+        temperature.clone(),
+        false,
+        None,
+        None,
+    )
+    .predict(&saft)?;
+    let shift = 1000.0;
+    let vapor_pressure = VaporPressure::new(
+        (&prediction + shift) * PASCAL,
+        temperature,
+        false,
+        None,
+        None,
+    );
+    assert_relative_eq!(
+        vapor_pressure.mean_absolute_difference(&saft)?,
+        shift,
+        max_relative = 1e-8
+    );
+
+    // the vapor pressure at 150 K is close to zero, which dominates the relative difference
+    let relative_difference = vapor_pressure.relative_difference(&saft)?;
+    assert!(relative_difference[0].abs() > 10.0 * relative_difference[1].abs());
+    assert!(vapor_pressure.mean_absolute_relative_difference(&saft)? > 0.1);
+
+    let estimator = Estimator::new(
+        vec![Arc::new(vapor_pressure)],
+        vec![1.0],
+        vec![Loss::Linear],
+    );
+    assert_relative_eq!(
+        estimator.mean_absolute_difference(&saft)?[0],
+        shift,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn non_finite_penalty() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+
+    // the last data point is supercritical and can not be predicted
+    let vapor_pressure: Arc<dyn DataSet<PcSaft>> = Arc::new(VaporPressure::new(
+        arr1(&[2.0, 10.5, 60.0]) * BAR,
+        arr1(&[250.0, 300.0, 400.0]) * KELVIN,
+        false,
+        None,
+        None,
+    ));
+    let estimator = Estimator::new(vec![vapor_pressure], vec![1.0], vec![Loss::Linear]);
+    let cost = estimator.cost(&saft)?;
+    assert!(cost[2].is_nan());
+
+    let estimator = estimator.with_non_finite_penalty(1e3)?;
+    let cost_penalty = estimator.cost(&saft)?;
+    assert!(cost_penalty.iter().all(|c| c.is_finite()));
+    assert_eq!(cost_penalty[2], 1e3);
+    assert_eq!(cost_penalty.slice(s![..2]), cost.slice(s![..2]));
+
+    for penalty in [f64::NAN, f64::INFINITY] {
+        let estimator = Estimator::<PcSaft>::new(vec![], vec![], vec![]);
+        assert!(matches!(
+            estimator.with_non_finite_penalty(penalty),
+            Err(EstimatorError::NonFinitePenalty(_))
+        ));
+    }
+    Ok(())
+}
@@ -1,5 +1,8 @@
 mod critical_point;
 mod dft;
+#[cfg(feature = "estimator")]
+mod estimator;
+mod parameters;
 mod properties;
 mod stability_analysis;
 mod state_creation_mixture;
@@ -0,0 +1,287 @@
+use feos::pcsaft::{PcSaft, PcSaftParameters, PcSaftRecord};
+use feos::ResidualModel;
+use feos_core::parameter::{
+    component_labels, ChemicalRecord, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord, SegmentRecord,
+};
+use feos_core::Components;
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+
+#[test]
+fn from_csv() -> Result<(), Box<dyn Error>> {
+    let csv =
+        PcSaftParameters::from_csv("tests/pcsaft/test_parameters.csv", IdentifierOption::Name)?;
+    let json = PcSaftParameters::from_json(
+        vec!["propane", "butane", "carbon-dioxide", "water_np"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    assert_eq!(csv.to_markdown(), json.to_markdown());
+    assert_eq!(
+        csv.pure_records[0].identifier.inchi.as_deref(),
+        Some("InChI=1/C3H8/c1-3-2/h3H2,1-2H3")
+    );
+    assert_eq!(csv.pure_records[0].model_record.mu, None);
+    assert_eq!(csv.pure_records[2].model_record.q, Some(4.4));
+    assert!(csv.pure_records[0]
+        .model_record
+        .association_records
+        .is_empty());
+    assert_eq!(
+        csv.pure_records[3].model_record.association_records.len(),
+        1
+    );
+    Ok(())
+}
+
+#[test]
+fn from_csv_missing_columns() -> Result<(), Box<dyn Error>> {
+    let path = std::env::temp_dir().join("feos_from_csv_missing_columns.csv");
+    std::fs::write(&path, "name,m,epsilon_k\npropane,2.0,208.1\n")?;
+    let err = PcSaftParameters::from_csv(&path, IdentifierOption::Cas)
+        .err()
+        .unwrap();
+    std::fs::remove_file(&path)?;
+    assert!(matches!(err, ParameterError::InvalidCsv(_)));
+    assert_eq!(
+        err.to_string(),
+        "Invalid CSV file: missing required column(s): molarweight, sigma, cas"
+    );
+    Ok(())
+}
+
+#[test]
+fn from_csv_invalid_fields() -> Result<(), Box<dyn Error>> {
+    let header = "name,molarweight,m,sigma,epsilon_k,mu\n";
+    for (i, rows) in [
+        "propane,44.1,2.0,3.6,208.1,NaN\n",
+        "propane,44.1,2.0,3.6,inf,\n",
+        "\"propane\n\",44.1,2.0,3.6,208.1,\n",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let path = std::env::temp_dir().join(format!("feos_from_csv_invalid_fields_{i}.csv"));
+        std::fs::write(&path, format!("{header}{rows}"))?;
+        let result = PcSaftParameters::from_csv(&path, IdentifierOption::Name);
+        std::fs::remove_file(&path)?;
+        assert!(matches!(result, Err(ParameterError::InvalidCsv(_))));
+    }
+    Ok(())
+}
+
+#[test]
+fn from_smiles_database() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_smiles_database(
+        &["CCC", "O=C=O"],
+        "tests/pcsaft/test_parameters.json",
+    )?;
+    let names: Vec<_> = params
+        .pure_records
+        .iter()
+        .map(|r| r.identifier.name.as_deref())
+        .collect();
+    assert_eq!(names, vec![Some("propane"), Some("carbon-dioxide")]);
+
+    let err = PcSaftParameters::from_smiles_database(
+        &["CCC", "C1CC1"],
+        "tests/pcsaft/test_parameters.json",
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, ParameterError::ComponentsNotFound(_)));
+    assert_eq!(
+        err.to_string(),
+        "The following component(s) were not found: [\"C1CC1\"]"
+    );
+    Ok(())
+}
+
+#[test]
+fn molar_weights() -> Result<(), Box<dyn Error>> {
+    let records = PureRecord::<PcSaftRecord>::from_json(
+        &["butane", "propane"],
+        "tests/pcsaft/test_parameters.json",
+        IdentifierOption::Name,
+    )?;
+    let params = PcSaftParameters::from_records(records.clone(), None)?;
+    let molar_weights: Vec<_> = records.iter().map(|r| r.molarweight).collect();
+    assert_eq!(params.molarweight.to_vec(), molar_weights);
+    assert_eq!(molar_weights, vec![58.123, 44.0962]);
+    Ok(())
+}
+
+#[test]
+fn component_identifiers() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let eos = ResidualModel::PcSaft(PcSaft::new(Arc::new(params)));
+    assert_eq!(eos.components(), 2);
+    let identifiers = eos.component_identifiers().unwrap();
+    let names: Vec<_> = identifiers.iter().map(|i| i.name.as_deref()).collect();
+    assert_eq!(names, vec![Some("propane"), Some("butane")]);
+    assert_eq!(identifiers[0].cas.as_deref(), Some("74-98-6"));
+    assert_eq!(identifiers[1].cas.as_deref(), Some("106-97-8"));
+    Ok(())
+}
+
+#[test]
+fn molefraction_labels() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let unnamed: Vec<_> = params
+        .pure_records
+        .iter()
+        .map(|r| PureRecord::new(Identifier::default(), r.molarweight, r.model_record.clone()))
+        .collect();
+    let named = ResidualModel::PcSaft(PcSaft::new(Arc::new(params)));
+    let identifiers = named.component_identifiers();
+    assert_eq!(
+        component_labels(identifiers.as_deref(), 2),
+        vec!["propane", "butane"]
+    );
+    assert_eq!(component_labels(None, 2), vec!["x0", "x1"]);
+
+    let unnamed = PcSaftParameters::from_records(unnamed, None)?;
+    let unnamed = ResidualModel::PcSaft(PcSaft::new(Arc::new(unnamed)));
+    let identifiers = unnamed.component_identifiers();
+    assert_eq!(
+        component_labels(identifiers.as_deref(), 2),
+        vec!["x0", "x1"]
+    );
+    Ok(())
+}
+
+#[test]
+fn association_sites_from_segments() -> Result<(), Box<dyn Error>> {
+    // segments of ethanol as identified by the SMARTS codes in sauer2014_smarts.json
+    let ethanol = ChemicalRecord::new(
+        Identifier::new(None, Some("ethanol"), None, Some("CCO"), None, None),
+        vec!["CH3".into(), "CH2".into(), "OH".into()],
+        None,
+    );
+    let segment_records = SegmentRecord::from_json("parameters/pcsaft/sauer2014_homo.json")?;
+    let params = PcSaftParameters::from_segments(vec![ethanol], segment_records, None)?;
+    let [association] = &params.pure_records[0].model_record.association_records[..] else {
+        panic!("expected a single association record");
+    };
+    assert_eq!(
+        (association.na, association.nb, association.nc),
+        (1.0, 1.0, 0.0)
+    );
+    Ok(())
+}
+
+#[test]
+fn pure_records_from_multiple_json() -> Result<(), Box<dyn Error>> {
+    let records = PureRecord::<PcSaftRecord>::from_multiple_json(
+        &["methanol", "propane"],
+        &[
+            "parameters/pcsaft/gross2001.json",
+            "parameters/pcsaft/gross2002.json",
+        ],
+        IdentifierOption::Name,
+    )?;
+    let names: Vec<_> = records
+        .iter()
+        .map(|r| r.identifier.name.as_deref())
+        .collect();
+    assert_eq!(names, vec![Some("methanol"), Some("propane")]);
+
+    let err = PureRecord::<PcSaftRecord>::from_multiple_json(
+        &["propane"],
+        &[
+            "parameters/pcsaft/gross2001.json",
+            "tests/pcsaft/test_parameters.json",
+        ],
+        IdentifierOption::Name,
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, ParameterError::IncompatibleParameters(_)));
+    assert!(err.to_string().ends_with(
+        "is defined in both 'parameters/pcsaft/gross2001.json' and 'tests/pcsaft/test_parameters.json'."
+    ));
+
+    // the same file passed twice
+    let err = PureRecord::<PcSaftRecord>::from_multiple_json(
+        &["propane"],
+        &[
+            "parameters/pcsaft/gross2001.json",
+            "parameters/pcsaft/gross2001.json",
+        ],
+        IdentifierOption::Name,
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Incompatible parameters: Substance 'propane' is defined in both 'parameters/pcsaft/gross2001.json' and 'parameters/pcsaft/gross2001.json'."
+    );
+
+    // duplicates within a single file
+    let records = PureRecord::<PcSaftRecord>::from_json(
+        &["propane"],
+        "tests/pcsaft/test_parameters.json",
+        IdentifierOption::Name,
+    )?;
+    let path = std::env::temp_dir().join(format!(
+        "feos_pure_records_duplicate_{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&path, serde_json::to_string(&[&records[0], &records[0]])?)?;
+    let duplicate = PureRecord::<PcSaftRecord>::from_multiple_json(
+        &["propane"],
+        &[&path],
+        IdentifierOption::Name,
+    );
+    std::fs::remove_file(&path)?;
+    let err = duplicate.err().unwrap();
+    assert!(matches!(err, ParameterError::IncompatibleParameters(_)));
+    assert!(err
+        .to_string()
+        .contains("Substance 'propane' is defined in both"));
+    Ok(())
+}
+
+#[test]
+fn segment_records_from_multiple_json() -> Result<(), Box<dyn Error>> {
+    let segments =
+        SegmentRecord::<PcSaftRecord>::from_json("parameters/pcsaft/sauer2014_homo.json")?;
+    let (first, second) = segments.split_at(10);
+    let id = std::process::id();
+    let path1 = std::env::temp_dir().join(format!("feos_segments_{id}_1.json"));
+    let path2 = std::env::temp_dir().join(format!("feos_segments_{id}_2.json"));
+    std::fs::write(&path1, serde_json::to_string(first)?)?;
+    std::fs::write(&path2, serde_json::to_string(second)?)?;
+    let merged = SegmentRecord::<PcSaftRecord>::from_multiple_json(&[&path1, &path2]);
+    let overlapping = SegmentRecord::<PcSaftRecord>::from_multiple_json(&[
+        &path1,
+        Path::new("parameters/pcsaft/sauer2014_homo.json"),
+    ]);
+    std::fs::remove_file(&path1)?;
+    std::fs::remove_file(&path2)?;
+
+    let merged = merged?;
+    assert_eq!(merged.len(), segments.len());
+    assert!(merged == segments);
+
+    let err = overlapping.err().unwrap();
+    assert!(matches!(err, ParameterError::IncompatibleParameters(_)));
+    assert!(err.to_string().contains(&format!(
+        "Segment '{}' is defined in both",
+        segments[0].identifier
+    )));
+    Ok(())
+}
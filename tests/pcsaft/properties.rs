@@ -1,21 +1,39 @@
 use approx::assert_relative_eq;
+use feos::ideal_gas::Joback;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
-use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{Residual, StateBuilder};
+use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
+use feos_core::{
+    Contributions, DensityInitialization, EosError, EquationOfState, IdealGas, Molarweight,
+    PhaseIdentification, Residual, State, StateBuilder, StateVec,
+};
 use ndarray::*;
 use quantity::*;
 use std::error::Error;
 use std::sync::Arc;
+use typenum::P3;
 
-#[test]
-fn test_dln_phi_dp() -> Result<(), Box<dyn Error>> {
+fn pcsaft(substances: &[&str]) -> Result<Arc<PcSaft>, ParameterError> {
     let params = PcSaftParameters::from_json(
-        vec!["propane", "butane"],
+        substances.to_vec(),
         "tests/pcsaft/test_parameters.json",
         None,
         IdentifierOption::Name,
     )?;
-    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    Ok(Arc::new(PcSaft::new(Arc::new(params))))
+}
+
+fn joback(substances: &[&str]) -> Result<Arc<Joback>, ParameterError> {
+    Ok(Arc::new(Joback::from_json(
+        substances.to_vec(),
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?))
+}
+
+#[test]
+fn test_dln_phi_dp() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
     let t = 300.0 * KELVIN;
     let p = BAR;
     let h = 1e-1 * PASCAL;
@@ -40,16 +58,657 @@ fn test_dln_phi_dp() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn test_residual_chemical_potential() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let joback = joback(&["propane", "butane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let s = StateBuilder::new(&eos)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .molefracs(&arr1(&[0.3, 0.7]))
+        .liquid()
+        .build()?;
+
+    let mu_res = s.residual_chemical_potential();
+    let mu_ig = s.chemical_potential(Contributions::IdealGas);
+    let mu = s.chemical_potential(Contributions::Total);
+    let mu_r = s.chemical_potential(Contributions::Residual);
+    for i in 0..2 {
+        assert_relative_eq!(mu_res.get(i), mu_r.get(i), max_relative = 1e-14);
+        assert_relative_eq!(
+            mu_res.get(i) + mu_ig.get(i),
+            mu.get(i),
+            max_relative = 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_contributions_map() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let joback = joback(&["propane", "butane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let s = StateBuilder::new(&eos)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .molefracs(&arr1(&[0.3, 0.7]))
+        .liquid()
+        .build()?;
+
+    let p = s.pressure_contributions_map();
+    assert_eq!(p.len(), s.pressure_contributions().len());
+    assert!(p.contains_key("Ideal gas"));
+    let p_sum = p.values().fold(0.0 * PASCAL, |acc, &p| acc + p);
+    assert_relative_eq!(
+        p_sum,
+        s.pressure(Contributions::Total),
+        max_relative = 1e-10
+    );
+
+    let mu = s.chemical_potential_contributions_map(1, Contributions::Total);
+    let mu_sum = mu.values().fold(0.0 * JOULE / MOL, |acc, &mu| acc + mu);
+    assert_relative_eq!(
+        mu_sum,
+        s.chemical_potential(Contributions::Total).get(1),
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ideal_gas_heat_capacity() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane"])?;
+    let joback = joback(&["propane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let s = StateBuilder::new(&eos)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .liquid()
+        .build()?;
+
+    // Joback: c_p = a + b*T + c*T^2 + d*T^3 + e*T^4 = 1 + 3 + 9 + 27 + 81 J/mol/K at 300 K
+    let c_p_ig = 121.0 * JOULE / MOL / KELVIN;
+    assert_relative_eq!(s.ideal_gas_heat_capacity(), c_p_ig, max_relative = 1e-6);
+    assert_relative_eq!(
+        s.molar_isobaric_heat_capacity(Contributions::IdealGas),
+        s.ideal_gas_heat_capacity(),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        s.molar_isobaric_heat_capacity(Contributions::IdealGas)
+            - s.molar_isochoric_heat_capacity(Contributions::IdealGas),
+        RGAS,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_association_helmholtz_energy() -> Result<(), Box<dyn Error>> {
+    let water = pcsaft(&["water_np"])?;
+    let s = StateBuilder::new(&water)
+        .temperature(300.0 * KELVIN)
+        .pressure(BAR)
+        .liquid()
+        .build()?;
+    let a_assoc = s.association_helmholtz_energy().unwrap();
+    assert!(a_assoc < 0.0 * JOULE);
+    let contributions = s.residual_helmholtz_energy_contributions();
+    let (_, a) = contributions
+        .iter()
+        .find(|(name, _)| name == "Association")
+        .unwrap();
+    assert_relative_eq!(a_assoc, *a, max_relative = 1e-14);
+
+    let propane = pcsaft(&["propane"])?;
+    let s = StateBuilder::new(&propane)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .liquid()
+        .build()?;
+    assert!(s.association_helmholtz_energy().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_speed_of_sound_contributions() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane"])?;
+    let joback = joback(&["propane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let t = 300.0 * KELVIN;
+    let s = StateBuilder::new(&eos)
+        .temperature(t)
+        .pressure(20.0 * BAR)
+        .liquid()
+        .build()?;
+
+    let contributions = s.speed_of_sound_contributions();
+    assert_eq!(contributions.len(), 2);
+    assert_eq!(contributions[0].0, eos.ideal_gas_model());
+    assert_eq!(contributions[1].0, "Total");
+
+    let c_p = s.ideal_gas_heat_capacity();
+    let gamma = (c_p / (c_p - RGAS)).into_value();
+    let c_ig = (gamma * RGAS * t / s.total_molar_weight()).sqrt();
+    assert_relative_eq!(contributions[0].1, c_ig, max_relative = 1e-10);
+    assert_relative_eq!(contributions[1].1, s.speed_of_sound(), max_relative = 1e-14);
+    Ok(())
+}
+
+#[test]
+fn test_state_vec_ln_phi() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let states = [0.2, 0.5, 0.8]
+        .into_iter()
+        .map(|x| {
+            StateBuilder::new(&saft)
+                .temperature(300.0 * KELVIN)
+                .pressure(5.0 * BAR)
+                .molefracs(&arr1(&[x, 1.0 - x]))
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let ln_phi = states.iter().collect::<StateVec<_>>().ln_phi();
+    assert_eq!(ln_phi.shape(), &[3, 2]);
+    for (row, state) in ln_phi.outer_iter().zip(&states) {
+        assert_eq!(row, state.ln_phi());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_state_vec_mass_basis() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let joback = joback(&["propane", "butane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let states = [0.2, 0.5, 0.8]
+        .into_iter()
+        .map(|x| {
+            StateBuilder::new(&eos)
+                .temperature(300.0 * KELVIN)
+                .pressure(5.0 * BAR)
+                .molefracs(&arr1(&[x, 1.0 - x]))
+                .build()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let state_vec: StateVec<_> = states.iter().collect();
+    let g = state_vec.specific_gibbs_energy(Contributions::Total);
+    for (i, state) in states.iter().enumerate() {
+        assert_eq!(g.get(i), state.specific_gibbs_energy(Contributions::Total));
+        assert_relative_eq!(
+            g.get(i),
+            state.molar_gibbs_energy(Contributions::Total) / state.total_molar_weight(),
+            max_relative = 1e-14
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_residual_helmholtz_energy_derivatives() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let s = StateBuilder::new(&saft)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .molefracs(&arr1(&[0.3, 0.7]))
+        .liquid()
+        .build()?;
+    let (a, da_drho, d2a_drho2, da_dt) = s.residual_helmholtz_energy_derivatives();
+    assert_relative_eq!(a, s.residual_helmholtz_energy(), max_relative = 1e-14);
+    assert_relative_eq!(da_dt, -s.residual_entropy(), max_relative = 1e-14);
+
+    let n = s.total_moles;
+    let rho = s.density;
+    let p_res = rho * rho / n * da_drho;
+    assert_relative_eq!(
+        p_res,
+        s.pressure(Contributions::Residual),
+        max_relative = 1e-12
+    );
+    let dp_drho = 2.0 * rho / n * da_drho + rho * rho / n * d2a_drho2;
+    assert_relative_eq!(
+        dp_drho,
+        s.dp_drho(Contributions::Residual),
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn test_thermal_expansion_volume() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let joback = joback(&["propane", "butane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let t = 300.0 * KELVIN;
+    let p = 20.0 * BAR;
+    let h = 1e-5 * KELVIN;
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let s = StateBuilder::new(&eos)
+        .temperature(t)
+        .pressure(p)
+        .moles(&moles)
+        .liquid()
+        .build()?;
+    let sh = StateBuilder::new(&eos)
+        .temperature(t + h)
+        .pressure(p)
+        .moles(&moles)
+        .liquid()
+        .build()?;
+
+    let dv_dt = s.thermal_expansion_volume();
+    assert_relative_eq!(
+        dv_dt,
+        s.thermal_expansivity() * s.volume,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(dv_dt, (sh.volume - s.volume) / h, max_relative = 1e-5);
+    Ok(())
+}
+
+#[test]
+fn test_molar_volume() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let joback = joback(&["propane", "butane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let moles = arr1(&[0.3, 0.7]) * MOL;
+    let s = StateBuilder::new(&eos)
+        .temperature(300.0 * KELVIN)
+        .pressure(20.0 * BAR)
+        .moles(&moles)
+        .liquid()
+        .build()?;
+    assert_relative_eq!(
+        (s.molar_volume() * s.density).into_value(),
+        1.0,
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        s.dv_dt(),
+        s.thermal_expansivity() * s.molar_volume(),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(
+        s.dv_dp(),
+        -s.isothermal_compressibility() * s.molar_volume(),
+        max_relative = 1e-14
+    );
+    Ok(())
+}
+
+#[test]
+fn test_phase_identification() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane"])?;
+    let t = 300.0 * KELVIN;
+    let h = 1e-5 * KELVIN;
+
+    let liquid = StateBuilder::new(&saft)
+        .temperature(t)
+        .pressure(20.0 * BAR)
+        .liquid()
+        .build()?;
+    assert!(liquid.phase_identification_parameter() > 1.0);
+    assert!(liquid.is_liquid()?);
+    assert!(!liquid.is_vapor()?);
+
+    let vapor = StateBuilder::new(&saft)
+        .temperature(t)
+        .pressure(BAR)
+        .vapor()
+        .build()?;
+    assert!(vapor.phase_identification_parameter() < 1.0);
+    assert!(vapor.is_vapor()?);
+    assert!(!vapor.is_liquid()?);
+
+    let supercritical = StateBuilder::new(&saft)
+        .temperature(400.0 * KELVIN)
+        .pressure(60.0 * BAR)
+        .build()?;
+    assert!(supercritical.is_supercritical()?);
+    assert!(!supercritical.is_liquid()?);
+    assert!(!supercritical.is_vapor()?);
+    assert!(!liquid.is_supercritical()?);
+
+    let cp = State::critical_point(&saft, None, None, Default::default())?;
+    for (state, phase) in [
+        (&liquid, PhaseIdentification::Liquid),
+        (&vapor, PhaseIdentification::Vapor),
+        (&supercritical, PhaseIdentification::Supercritical),
+    ] {
+        assert_eq!(state.phase_identification()?, phase);
+        assert_eq!(state.phase_identification_with_critical_point(&cp), phase);
+    }
+
+    let vh = StateBuilder::new(&saft)
+        .temperature(t + h)
+        .volume(liquid.volume)
+        .moles(&liquid.moles)
+        .build()?;
+    let c = Contributions::Total;
+    assert_relative_eq!(
+        liquid.d2p_dtdv(c),
+        (vh.dp_dv(c) - liquid.dp_dv(c)) / h,
+        max_relative = 1e-5
+    );
+    Ok(())
+}
+
+#[test]
+fn test_molar_weight_of_mixture() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let molefracs = arr1(&[0.3, 0.7]);
+    let s = StateBuilder::new(&saft)
+        .temperature(300.0 * KELVIN)
+        .pressure(BAR)
+        .molefracs(&molefracs)
+        .build()?;
+    assert_relative_eq!(
+        saft.molar_weight_of_mixture(&molefracs),
+        s.total_molar_weight(),
+        max_relative = 1e-14
+    );
+    Ok(())
+}
+
 #[test]
 fn test_virial_is_not_nan() -> Result<(), Box<dyn Error>> {
-    let params = PcSaftParameters::from_json(
-        vec!["water_np"],
+    let saft = pcsaft(&["water_np"])?;
+    let virial_b = saft.second_virial_coefficient(300.0 * KELVIN, None)?;
+    assert!(!virial_b.is_nan());
+    Ok(())
+}
+
+#[test]
+fn test_number_density() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let s = StateBuilder::new(&saft)
+        .temperature(300.0 * KELVIN)
+        .pressure(BAR)
+        .molefracs(&arr1(&[0.3, 0.7]))
+        .build()?;
+    let per_m3 = METER.powi::<typenum::N3>();
+    assert_relative_eq!(
+        s.number_density().convert_to(per_m3),
+        (s.density * NAV).convert_to(per_m3),
+        max_relative = 1e-14
+    );
+    let partial = s.partial_number_density();
+    for i in 0..2 {
+        assert_relative_eq!(
+            partial.get(i).convert_to(per_m3),
+            (s.partial_density.get(i) * NAV).convert_to(per_m3),
+            max_relative = 1e-14
+        );
+    }
+    assert_relative_eq!(
+        partial.sum().convert_to(per_m3),
+        s.number_density().convert_to(per_m3),
+        max_relative = 1e-14
+    );
+    Ok(())
+}
+
+#[test]
+fn test_isothermal_compressibility_from_density() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    for state in [
+        StateBuilder::new(&saft)
+            .temperature(300.0 * KELVIN)
+            .pressure(BAR)
+            .molefracs(&arr1(&[0.3, 0.7]))
+            .vapor()
+            .build()?,
+        StateBuilder::new(&saft)
+            .temperature(300.0 * KELVIN)
+            .pressure(50.0 * BAR)
+            .molefracs(&arr1(&[0.3, 0.7]))
+            .liquid()
+            .build()?,
+    ] {
+        assert_relative_eq!(
+            state
+                .isothermal_compressibility_from_density(Contributions::Total)
+                .convert_to(1.0 / BAR),
+            state.isothermal_compressibility().convert_to(1.0 / BAR),
+            max_relative = 1e-12
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_density_derivatives() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane"])?;
+    let t = 300.0 * KELVIN;
+    let p = 20.0 * BAR;
+    let s = StateBuilder::new(&saft)
+        .temperature(t)
+        .pressure(p)
+        .liquid()
+        .build()?;
+    assert_relative_eq!(
+        s.drho_dp(Contributions::Total),
+        s.density * s.isothermal_compressibility(),
+        max_relative = 1e-12
+    );
+
+    let dt = 1e-3 * KELVIN;
+    let rho = |t| -> Result<_, Box<dyn Error>> {
+        Ok(StateBuilder::new(&saft)
+            .temperature(t)
+            .pressure(p)
+            .liquid()
+            .build()?
+            .density)
+    };
+    let drho_dt = (rho(t + dt)? - rho(t - dt)?) / (2.0 * dt);
+    assert_relative_eq!(
+        s.drho_dt(Contributions::Total),
+        drho_dt,
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_henrys_law_constant_temperature_derivative() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["methane", "butane", "hexane"])?;
+    let t = 300.0 * KELVIN;
+    let dt = 1e-2 * KELVIN;
+    let unit = BAR / KELVIN;
+    for x in [arr1(&[0.0, 1.0, 0.0]), arr1(&[0.0, 0.4, 0.6])] {
+        let dh_dt = State::henrys_law_constant_temperature_derivative(&saft, t, &x)?;
+        let h_plus = State::henrys_law_constant(&saft, t + dt, &x)?;
+        let h_minus = State::henrys_law_constant(&saft, t - dt, &x)?;
+        let fd = (h_plus - h_minus) / (2.0 * dt);
+        for i in 0..dh_dt.len() {
+            assert_relative_eq!(
+                dh_dt.get(i).convert_to(unit),
+                fd.get(i).convert_to(unit),
+                max_relative = 1e-6
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_is_cooling_on_expansion() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane"])?;
+    let joback = joback(&["propane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let cold = StateBuilder::new(&eos)
+        .temperature(350.0 * KELVIN)
+        .pressure(30.0 * BAR)
+        .vapor()
+        .build()?;
+    assert!(cold.is_cooling_on_expansion());
+    let hot = StateBuilder::new(&eos)
+        .temperature(3000.0 * KELVIN)
+        .pressure(1.0 * BAR)
+        .build()?;
+    assert!(!hot.is_cooling_on_expansion());
+    Ok(())
+}
+
+#[test]
+fn test_pressure_grid() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let temperature = arr1(&[250.0, 300.0, 350.0]) * KELVIN;
+    let density = arr1(&[0.1, 1.0, 5.0, 10.0]) * KILO * MOL / METER.powi::<P3>();
+    let x = arr1(&[0.3, 0.7]);
+    let p = saft.pressure_grid(&temperature, &density, Some(&x))?;
+    assert_eq!(p.shape(), [3, 4]);
+    for (i, t) in temperature.into_iter().enumerate() {
+        for (j, rho) in density.into_iter().enumerate() {
+            let state = State::new_nvt(&saft, t, MOL / rho, &(&x * MOL))?;
+            assert_relative_eq!(
+                p.get((i, j)),
+                state.pressure(Contributions::Total),
+                max_relative = 1e-12
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fugacity_coefficient_table() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let temperature = arr1(&[280.0, 300.0]) * KELVIN;
+    let pressure = arr1(&[1.0, 4.0, 20.0]) * BAR;
+    let x = arr1(&[0.3, 0.7]);
+    let phi_liquid = State::fugacity_coefficient_table(
+        &saft,
+        &temperature,
+        &pressure,
+        Some(&x),
+        DensityInitialization::Liquid,
+    )?;
+    assert_eq!(phi_liquid.shape(), [2, 3, 2]);
+
+    let state = State::new_npt(
+        &saft,
+        temperature.get(1),
+        pressure.get(1),
+        &(&x * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    assert_relative_eq!(
+        phi_liquid.slice(s![1, 1, ..]),
+        state.ln_phi().mapv(f64::exp),
+        max_relative = 1e-12
+    );
+
+    // inside the two-phase region, the liquid and vapor roots differ
+    let phi_vapor = State::fugacity_coefficient_table(
+        &saft,
+        &temperature,
+        &pressure,
+        Some(&x),
+        DensityInitialization::Vapor,
+    )?;
+    assert!((phi_liquid[[1, 1, 0]] - phi_vapor[[1, 1, 0]]).abs() > 1e-2);
+
+    // errors of individual states keep their type
+    let pressure = arr1(&[1.0, -10.0]) * BAR;
+    let result = State::fugacity_coefficient_table(
+        &saft,
+        &temperature,
+        &pressure,
+        Some(&x),
+        DensityInitialization::Vapor,
+    );
+    assert!(matches!(
+        result,
+        Err(EosError::GridPointFailed { index: 1, ref source })
+            if matches!(**source, EosError::DensityIteration(..))
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_ideal_gas_pressure() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    let joback = joback(&["propane", "butane"])?;
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    for (t, p) in [(250.0 * KELVIN, 20.0 * BAR), (350.0 * KELVIN, 5.0 * BAR)] {
+        let s = StateBuilder::new(&eos)
+            .temperature(t)
+            .pressure(p)
+            .molefracs(&arr1(&[0.3, 0.7]))
+            .build()?;
+        assert_eq!(s.pressure(Contributions::IdealGas), s.density * RGAS * t);
+        assert_relative_eq!(
+            s.pressure(Contributions::Total),
+            s.pressure(Contributions::IdealGas) + s.pressure(Contributions::Residual),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(s.pressure(Contributions::Total), p, max_relative = 1e-8);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_thermodynamic_factor_binary() -> Result<(), Box<dyn Error>> {
+    // a mixture of two identical components is ideal
+    let propane = PcSaftParameters::from_json(
+        vec!["propane"],
         "tests/pcsaft/test_parameters.json",
         None,
         IdentifierOption::Name,
-    )?;
+    )?
+    .pure_records;
+    let params = PcSaftParameters::new_binary(vec![propane[0].clone(), propane[0].clone()], None)?;
     let saft = Arc::new(PcSaft::new(Arc::new(params)));
-    let virial_b = saft.second_virial_coefficient(300.0 * KELVIN, None)?;
-    assert!(!virial_b.is_nan());
+    let s = StateBuilder::new(&saft)
+        .temperature(250.0 * KELVIN)
+        .pressure(10.0 * BAR)
+        .molefracs(&arr1(&[0.3, 0.7]))
+        .liquid()
+        .build()?;
+    assert_relative_eq!(s.thermodynamic_factor_binary()?, 1.0, max_relative = 1e-10);
+
+    let saft = pcsaft(&["propane", "butane"])?;
+    let s = StateBuilder::new(&saft)
+        .temperature(250.0 * KELVIN)
+        .pressure(10.0 * BAR)
+        .molefracs(&arr1(&[0.3, 0.7]))
+        .liquid()
+        .build()?;
+    assert_eq!(
+        s.thermodynamic_factor_binary()?,
+        s.thermodynamic_factor()[[0, 0]]
+    );
+
+    let saft = pcsaft(&["propane"])?;
+    let s = State::new_nvt(
+        &saft,
+        250.0 * KELVIN,
+        1.0 * METER.powi::<P3>(),
+        &(arr1(&[1.0]) * MOL),
+    )?;
+    assert!(s.thermodynamic_factor_binary().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_partial_residual_molar_entropy() -> Result<(), Box<dyn Error>> {
+    let saft = pcsaft(&["propane", "butane"])?;
+    for (t, p) in [(250.0 * KELVIN, 20.0 * BAR), (350.0 * KELVIN, 5.0 * BAR)] {
+        let s = StateBuilder::new(&saft)
+            .temperature(t)
+            .pressure(p)
+            .molefracs(&arr1(&[0.3, 0.7]))
+            .build()?;
+        let s_i = s.partial_residual_molar_entropy();
+        assert_relative_eq!(
+            s_i.get(0) * s.molefracs[0] + s_i.get(1) * s.molefracs[1],
+            s.residual_molar_entropy(),
+            max_relative = 1e-12
+        );
+    }
     Ok(())
 }
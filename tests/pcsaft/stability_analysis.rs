@@ -1,6 +1,7 @@
-use feos::pcsaft::{PcSaft, PcSaftParameters};
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftOptions, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{DensityInitialization, PhaseEquilibrium, State};
+use feos_core::{Contributions, DensityInitialization, PhaseEquilibrium, State};
 use ndarray::arr1;
 use quantity::*;
 use std::error::Error;
@@ -46,3 +47,57 @@ fn test_stability_analysis() -> Result<(), Box<dyn Error>> {
     assert!(liquid_check.is_empty());
     Ok(())
 }
+
+#[test]
+fn test_stability_analysis_trial_states() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["water_np"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let temperature = 400.0 * KELVIN;
+    let eos = Arc::new(PcSaft::new(params.clone()));
+    let vle = PhaseEquilibrium::pure(&eos, temperature, None, Default::default())?;
+    let liquid_density = vle.liquid().density;
+
+    // with a low maximum packing fraction, the liquid trial phase
+    // of the default stability analysis misses the dense liquid
+    let options = PcSaftOptions {
+        max_eta: 0.3,
+        ..Default::default()
+    };
+    let eos = Arc::new(PcSaft::with_options(params, options));
+    let supersaturated_vapor = State::new_npt(
+        &eos,
+        temperature,
+        1.1 * vle.vapor().pressure(Contributions::Total),
+        &(arr1(&[1.0]) * MOL),
+        DensityInitialization::Vapor,
+    )?;
+    assert!(supersaturated_vapor
+        .stability_analysis(Default::default())?
+        .is_empty());
+
+    let trial_states = [(
+        arr1(&[1.0]),
+        DensityInitialization::InitialDensity(liquid_density),
+    )];
+    let check = supersaturated_vapor
+        .stability_analysis_with_trial_states(&trial_states, Default::default())?;
+    assert_eq!(check.len(), 1);
+    assert_relative_eq!(check[0].density, liquid_density, max_relative = 1e-3);
+
+    let trial_states = [(arr1(&[0.5, 0.5]), DensityInitialization::Liquid)];
+    assert!(supersaturated_vapor
+        .stability_analysis_with_trial_states(&trial_states, Default::default())
+        .is_err());
+
+    for x in [0.0, -1.0, f64::NAN] {
+        let trial_states = [(arr1(&[x]), DensityInitialization::Liquid)];
+        assert!(supersaturated_vapor
+            .stability_analysis_with_trial_states(&trial_states, Default::default())
+            .is_err());
+    }
+    Ok(())
+}
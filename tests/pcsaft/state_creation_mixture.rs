@@ -2,7 +2,10 @@ use approx::assert_relative_eq;
 use feos::ideal_gas::Joback;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
-use feos_core::{Contributions, EquationOfState, StateBuilder};
+use feos_core::{
+    Components, Contributions, DensityInitialization, EosError, EquationOfState, Residual, State,
+    StateBuilder, StateVec,
+};
 use ndarray::prelude::*;
 use ndarray::Zip;
 use quantity::*;
@@ -89,9 +92,48 @@ fn temperature_partial_density() -> Result<(), Box<dyn Error>> {
         .build()?;
     assert_relative_eq!(x, state.molefracs, max_relative = 1e-10);
     assert_relative_eq!(density, state.density, max_relative = 1e-10);
-    // Zip::from(&state.partial_density.to_reduced(reference))
-    //     .and(&partial_density.into_value()?)
-    //     .for_each(|&r1, &r2| assert_relative_eq!(r1, r2, max_relative = 1e-10));
+    let unit = MOL / METER.powi::<P3>();
+    assert_relative_eq!(
+        (state.partial_density / unit).into_value(),
+        (partial_density / unit).into_value(),
+        max_relative = 1e-10
+    );
+    Ok(())
+}
+
+#[test]
+fn partial_density_inconsistent_inputs() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_butane_parameters()?.0));
+    let temperature = 300.0 * KELVIN;
+    let partial_density = arr1(&[0.3, 0.7]) * MOL / METER.powi::<P3>();
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .partial_density(&partial_density)
+        .density(MOL / METER.powi::<P3>())
+        .build();
+    assert!(matches!(state, Err(EosError::UndeterminedState(_))));
+
+    let x = arr1(&[0.3, 0.7]);
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .partial_density(&partial_density)
+        .molefracs(&x)
+        .build();
+    assert!(matches!(state, Err(EosError::UndeterminedState(_))));
+
+    let partial_density = arr1(&[0.3, -0.7]) * MOL / METER.powi::<P3>();
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .partial_density(&partial_density)
+        .build();
+    assert!(matches!(state, Err(EosError::InvalidState(_, p, _)) if p == "partial_density"));
+
+    let partial_density = arr1(&[0.0, 0.0]) * MOL / METER.powi::<P3>();
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .partial_density(&partial_density)
+        .build();
+    assert!(matches!(state, Err(EosError::UndeterminedState(_))));
     Ok(())
 }
 
@@ -134,3 +176,161 @@ fn temperature_pressure_molefracs() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn molar_gibbs_energy_of_mixing() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_butane_parameters()?.0));
+    let x = Array1::linspace(0.0, 1.0, 11);
+    let g_mix = State::molar_gibbs_energy_of_mixing(&saft, 200.0 * KELVIN, BAR, &x)?
+        .convert_to(JOULE / MOL);
+    assert_relative_eq!(g_mix[0], 0.0, epsilon = 1e-10);
+    assert_relative_eq!(g_mix[10], 0.0, epsilon = 1e-10);
+    assert!(g_mix.slice(s![1..10]).iter().all(|&g| g < 0.0));
+
+    for x1 in [-0.1, 1.1, f64::NAN] {
+        let g_mix = State::molar_gibbs_energy_of_mixing(&saft, 200.0 * KELVIN, BAR, &arr1(&[x1]));
+        assert!(matches!(g_mix, Err(EosError::InvalidState(..))));
+    }
+    let propane = Arc::new(saft.subset(&[0]));
+    let g_mix = State::molar_gibbs_energy_of_mixing(&propane, 200.0 * KELVIN, BAR, &x);
+    assert!(matches!(g_mix, Err(EosError::IncompatibleComponents(1, 2))));
+    Ok(())
+}
+
+#[test]
+fn molefracs_normalization() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_butane_parameters()?.0));
+    let temperature = 300.0 * KELVIN;
+    let density = 100.0 * MOL / METER.powi::<P3>();
+    let x = arr1(&[0.5, 0.4]);
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .density(density)
+        .molefracs(&x)
+        .build()?;
+    assert_relative_eq!(
+        state.molefracs,
+        arr1(&[5.0 / 9.0, 4.0 / 9.0]),
+        max_relative = 1e-14
+    );
+    assert_relative_eq!(state.density, density, max_relative = 1e-14);
+
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .pressure(BAR)
+        .molefracs(&x)
+        .vapor()
+        .build()?;
+    assert_relative_eq!(
+        state.molefracs,
+        arr1(&[5.0 / 9.0, 4.0 / 9.0]),
+        max_relative = 1e-14
+    );
+
+    let x = arr1(&[0.5, -0.1]);
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .density(density)
+        .molefracs(&x)
+        .build();
+    assert!(matches!(state, Err(EosError::InvalidState(_, _, x)) if x == -0.1));
+
+    let x = arr1(&[0.5, f64::INFINITY]);
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .density(density)
+        .molefracs(&x)
+        .build();
+    assert!(matches!(state, Err(EosError::InvalidState(_, _, x)) if x == f64::INFINITY));
+
+    let x = arr1(&[0.0, 0.0]);
+    let state = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .pressure(BAR)
+        .molefracs(&x)
+        .vapor()
+        .build();
+    assert!(
+        matches!(state, Err(EosError::InvalidState(_, ref name, x)) if name == "sum of molefracs" && x == 0.0)
+    );
+    Ok(())
+}
+
+#[test]
+fn validate_moles() -> Result<(), Box<dyn Error>> {
+    let saft = PcSaft::new(propane_butane_parameters()?.0);
+    let moles = arr1(&[1.0, 2.0]) * MOL;
+    assert_eq!(saft.validate_moles(Some(&moles))?, moles);
+
+    let moles = arr1(&[1.0, 2.0, 3.0]) * MOL;
+    let err = saft.validate_moles(Some(&moles)).unwrap_err();
+    assert!(matches!(err, EosError::IncompatibleComposition(2, 3)));
+    assert_eq!(
+        err.to_string(),
+        "Equation of state has 2 components, but the composition has 3 entries."
+    );
+    assert!(matches!(
+        saft.validate_moles(None),
+        Err(EosError::UndeterminedState(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn state_vec_to_dict() -> Result<(), Box<dyn Error>> {
+    let (saft_params, joback) = propane_butane_parameters()?;
+    let identifiers: Vec<_> = saft_params
+        .pure_records
+        .iter()
+        .map(|r| r.identifier.clone())
+        .collect();
+    let saft = Arc::new(PcSaft::new(saft_params));
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let x = arr1(&[0.3, 0.7]);
+    let states = [300.0, 310.0]
+        .iter()
+        .map(|&t| {
+            State::new_npt(
+                &eos,
+                t * KELVIN,
+                BAR,
+                &(&x * MOL),
+                DensityInitialization::None,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let states: StateVec<_> = states.iter().collect();
+
+    let dict = states.to_dict(Contributions::Total, Some(&identifiers), false)?;
+    for key in [
+        "propane",
+        "butane",
+        "temperature",
+        "pressure",
+        "density",
+        "molar enthalpy",
+        "molar entropy",
+        "mass density",
+        "specific enthalpy",
+        "specific entropy",
+    ] {
+        assert_eq!(dict[key].len(), 2, "{key}");
+    }
+    assert!(!dict.contains_key("specific volume"));
+    assert_relative_eq!(dict["temperature"][1], 310.0);
+    assert_relative_eq!(dict["butane"][0], 0.7);
+
+    let dict = states.to_dict(Contributions::Total, None, true)?;
+    assert!(dict.contains_key("x0"));
+    assert!(dict.contains_key("specific volume"));
+    assert!(dict.contains_key("specific gibbs energy"));
+    for key in ["density", "molar enthalpy", "molar entropy"] {
+        assert!(!dict.contains_key(key), "{key}");
+    }
+    assert_relative_eq!(
+        dict["specific volume"][0] * dict["mass density"][0],
+        1.0,
+        max_relative = 1e-14
+    );
+    Ok(())
+}
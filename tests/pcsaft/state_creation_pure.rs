@@ -3,9 +3,10 @@ use feos::ideal_gas::Joback;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
 use feos_core::{
-    Contributions, DensityInitialization, EquationOfState, IdealGas, PhaseEquilibrium, Residual,
-    State, StateBuilder,
+    Contributions, DensityInitialization, EosError, EquationOfState, IdealGas, PhaseEquilibrium,
+    ReferenceState, Residual, State, StateBuilder, StateVec,
 };
+use ndarray::arr1;
 use quantity::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -143,6 +144,74 @@ fn pressure_temperature_initial_density() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn pressure_temperature_initial_mass_density() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?.0));
+    let pressure = 20.0 * BAR;
+    let temperature = 300.0 * KELVIN;
+    let mass_density = 530.0 * KILOGRAM / METER.powi::<P3>();
+    let density = mass_density / (44.0962 * GRAM / MOL);
+    let state_molar = StateBuilder::new(&saft)
+        .temperature(temperature)
+        .pressure(pressure)
+        .initial_density(density)
+        .build()?;
+    let state_mass = State::new_npt(
+        &saft,
+        temperature,
+        pressure,
+        &(arr1(&[1.0]) * MOL),
+        DensityInitialization::from_mass_density(&*saft, mass_density, None)?,
+    )?;
+    assert_relative_eq!(
+        state_mass.density,
+        state_molar.density,
+        max_relative = 1e-12
+    );
+    assert_relative_eq!(
+        state_mass.mass_density(),
+        state_molar.mass_density(),
+        max_relative = 1e-12
+    );
+    assert!(
+        DensityInitialization::from_mass_density(&*saft, mass_density, Some(&arr1(&[2.0]))).is_ok()
+    );
+    assert!(DensityInitialization::from_mass_density(
+        &*saft,
+        mass_density,
+        Some(&arr1(&[0.5, 0.5]))
+    )
+    .is_err());
+    for invalid in [0.0, -530.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            DensityInitialization::from_mass_density(
+                &*saft,
+                invalid * KILOGRAM / METER.powi::<P3>(),
+                None
+            ),
+            Err(EosError::InvalidState(_, _, _))
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn pressure_temperature_error() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?.0));
+    let err = StateBuilder::new(&saft)
+        .temperature(300.0 * KELVIN)
+        .pressure(-10.0 * BAR)
+        .vapor()
+        .build()
+        .err()
+        .unwrap();
+    let message = err.to_string();
+    assert!(message.contains("T = 300 K"));
+    assert!(message.contains("p = -1 MPa"));
+    assert!(message.contains("initialization: vapor"));
+    Ok(())
+}
+
 #[test]
 fn pressure_enthalpy_vapor() -> Result<(), Box<dyn Error>> {
     let (saft_params, joback) = propane_parameters()?;
@@ -184,6 +253,45 @@ fn pressure_enthalpy_vapor() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn builder_equals_new_full() -> Result<(), Box<dyn Error>> {
+    let (saft_params, joback) = propane_parameters()?;
+    let saft = Arc::new(PcSaft::new(saft_params));
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let pressure = 0.3 * BAR;
+    let molar_enthalpy = 2000.0 * JOULE / MOL;
+    let total_moles = 2.0 * MOL;
+    let initial_temperature = 250.0 * KELVIN;
+    let builder = StateBuilder::new(&eos)
+        .pressure(pressure)
+        .total_moles(total_moles)
+        .molar_enthalpy(molar_enthalpy)
+        .initial_temperature(initial_temperature)
+        .vapor()
+        .build()?;
+    let full = State::new_full(
+        &eos,
+        None,
+        None,
+        None,
+        None,
+        Some(total_moles),
+        None,
+        None,
+        Some(pressure),
+        Some(molar_enthalpy),
+        None,
+        None,
+        DensityInitialization::Vapor,
+        Some(initial_temperature),
+    )?;
+    assert_eq!(builder.temperature, full.temperature);
+    assert_eq!(builder.volume, full.volume);
+    assert_eq!(builder.moles, full.moles);
+    assert_eq!(builder.density, full.density);
+    Ok(())
+}
+
 #[test]
 fn density_internal_energy() -> Result<(), Box<dyn Error>> {
     let (saft_params, joback) = propane_parameters()?;
@@ -297,6 +405,42 @@ fn pressure_entropy_vapor() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn isentropic_pressure_change() -> Result<(), Box<dyn Error>> {
+    let (saft_params, joback) = propane_parameters()?;
+    let saft = Arc::new(PcSaft::new(saft_params));
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let state = StateBuilder::new(&eos)
+        .temperature(300.0 * KELVIN)
+        .pressure(BAR)
+        .total_moles(MOL)
+        .vapor()
+        .build()?;
+    let molar_entropy = state.molar_entropy(Contributions::Total);
+
+    // compression heats the gas
+    let pressure = 5.0 * BAR;
+    let compressed = state.isentropic_pressure_change(pressure)?;
+    assert_relative_eq!(
+        compressed.molar_entropy(Contributions::Total),
+        molar_entropy,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        compressed.pressure(Contributions::Total),
+        pressure,
+        max_relative = 1e-8
+    );
+    assert_eq!(compressed.moles, state.moles);
+    assert!(compressed.temperature > state.temperature);
+
+    // expansion back to the initial pressure recovers the initial state
+    let expanded = compressed.isentropic_pressure_change(BAR)?;
+    assert_relative_eq!(expanded.temperature, state.temperature, max_relative = 1e-8);
+    assert_relative_eq!(expanded.density, state.density, max_relative = 1e-8);
+    Ok(())
+}
+
 #[test]
 fn temperature_entropy_vapor() -> Result<(), Box<dyn Error>> {
     let (saft_params, joback) = propane_parameters()?;
@@ -443,3 +587,376 @@ fn test_consistency() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn max_density_scaling() -> Result<(), Box<dyn Error>> {
+    let (saft_params, joback) = propane_parameters()?;
+    let saft = Arc::new(PcSaft::new(saft_params));
+    let default = Arc::new(EquationOfState::new(joback, saft));
+    let t = 100.0 * KELVIN;
+    let p = 10000.0 * BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    assert!(State::new_npt(&default, t, p, &moles, DensityInitialization::Liquid).is_err());
+
+    for factor in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            default.with_max_density_scaling(factor),
+            Err(EosError::InvalidState(..))
+        ));
+    }
+    let scaled = Arc::new(default.with_max_density_scaling(2.0)?);
+    let max_density = default.max_density(None)?;
+    assert_relative_eq!(
+        scaled.max_density(None)?,
+        max_density * 2.0,
+        max_relative = 1e-14
+    );
+    let state = State::new_npt(&scaled, t, p, &moles, DensityInitialization::Liquid)?;
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        p,
+        max_relative = 1e-10
+    );
+    assert!(state.density > max_density);
+    Ok(())
+}
+
+#[test]
+fn density_iteration_not_converged() -> Result<(), Box<dyn Error>> {
+    let (saft_params, joback) = propane_parameters()?;
+    let saft = Arc::new(PcSaft::new(saft_params));
+    let eos = Arc::new(EquationOfState::new(joback, saft));
+    let t = 300.0 * KELVIN;
+    let p = BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let initial_density = 400.0 * MOL / METER.powi::<P3>();
+    let state = State::new_npt(
+        &eos,
+        t,
+        p,
+        &moles,
+        DensityInitialization::InitialDensity(initial_density),
+    )?;
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        p,
+        max_relative = 1e-10
+    );
+
+    // the steps of the density iteration are bounded by the maximum density,
+    // so that the vapor density can not be reached within the maximum number
+    // of iterations
+    let scaled = Arc::new(eos.with_max_density_scaling(1e-3)?);
+    let result = State::new_npt(
+        &scaled,
+        t,
+        p,
+        &moles,
+        DensityInitialization::InitialDensity(initial_density),
+    );
+    assert!(matches!(
+        result,
+        Err(EosError::DensityIteration(_, _, _, ref e)) if matches!(**e, EosError::NotConverged(_))
+    ));
+    Ok(())
+}
+
+#[test]
+fn without_contribution() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaftParameters::from_json(
+        vec!["water_np"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let joback = Arc::new(Joback::from_json(
+        vec!["water_np"],
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let eos = Arc::new(EquationOfState::new(joback, Arc::new(PcSaft::new(saft))));
+    let t = 300.0 * KELVIN;
+    let p = 100.0 * BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let density = |eos: &Arc<_>| -> Result<_, Box<dyn Error>> {
+        Ok(State::new_npt(eos, t, p, &moles, DensityInitialization::Liquid)?.density)
+    };
+    let rho = density(&eos)?;
+
+    let disabled = Arc::new(eos.without_contribution("Association")?);
+    assert_eq!(disabled.disabled_contributions, vec!["Association"]);
+    let rho_disabled = density(&disabled)?;
+    assert!(rho_disabled < 0.9 * rho);
+
+    let enabled = Arc::new(disabled.with_contribution("Association"));
+    assert!(enabled.disabled_contributions.is_empty());
+    assert_relative_eq!(density(&enabled)?, rho, max_relative = 1e-12);
+
+    assert!(matches!(
+        eos.without_contribution("Polar"),
+        Err(EosError::UnknownContribution(name)) if name == "Polar"
+    ));
+    Ok(())
+}
+
+#[test]
+fn new_batch() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?.0));
+    let t = 300.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&saft, t, None, Default::default())?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let moles = arr1(&[1.0]) * MOL;
+
+    // below the vapor pressure, the stable (vapor) phase is found by default
+    let p = 0.9 * p_sat;
+    let default = State::new_npt(&saft, t, p, &moles, DensityInitialization::None)?;
+    assert!(default.density < vle.vapor().density);
+
+    // the metastable liquid is found from a liquid initial density
+    let temperature = arr1(&[300.0, 300.0]) * KELVIN;
+    let pressure = Pressure::from_vec(vec![p, 1.1 * p_sat]);
+    let initial_density = Density::from_vec(vec![vle.liquid().density; 2]);
+    let states = State::new_batch(&saft, &temperature, &pressure, None, &initial_density)?;
+    assert_eq!(states.len(), 2);
+    for (i, state) in states.iter().enumerate() {
+        assert_relative_eq!(
+            state.pressure(Contributions::Total),
+            pressure.get(i),
+            max_relative = 1e-10
+        );
+        assert!(state.density > vle.vapor().density);
+    }
+    assert!(states[0].density < vle.liquid().density);
+
+    let initial_density = Density::from_vec(vec![vle.liquid().density]);
+    assert!(matches!(
+        State::new_batch(&saft, &temperature, &pressure, None, &initial_density),
+        Err(EosError::IncompatibleLength {
+            expected: 2,
+            found: 1,
+            ..
+        })
+    ));
+    Ok(())
+}
+
+#[test]
+fn new_grid() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?.0));
+    let temperature = arr1(&[250.0, 300.0, 350.0]) * KELVIN;
+    let pressure = arr1(&[1.0, 5.0, 10.0, 20.0]) * BAR;
+    let states = State::new_grid(
+        &saft,
+        &temperature,
+        &pressure,
+        None,
+        DensityInitialization::None,
+    )?;
+    assert_eq!(states.len(), temperature.len() * pressure.len());
+    for (k, state) in states.iter().enumerate() {
+        assert_eq!(state.temperature, temperature.get(k / 4));
+        assert_relative_eq!(
+            state.pressure(Contributions::Total),
+            pressure.get(k % 4),
+            max_relative = 1e-10
+        );
+    }
+    let state_vec: StateVec<_> = states.iter().collect();
+    assert_eq!(state_vec.pressure().len(), 12);
+    assert_eq!(state_vec.mass_density().len(), 12);
+
+    // there is no vapor state at negative pressures
+    let temperature = arr1(&[250.0]) * KELVIN;
+    let pressure = arr1(&[1.0, -10.0]) * BAR;
+    let err = State::new_grid(
+        &saft,
+        &temperature,
+        &pressure,
+        None,
+        DensityInitialization::Vapor,
+    )
+    .err()
+    .unwrap();
+    assert!(err.to_string().starts_with("State 1 of the grid failed"));
+    let EosError::GridPointFailed { index, source } = err else {
+        panic!("expected a GridPointFailed error");
+    };
+    assert_eq!(index, 1);
+    assert!(matches!(*source, EosError::DensityIteration(..)));
+    Ok(())
+}
+
+#[test]
+fn compressibility_isobar() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?.0));
+    let pressure = 5.0 * BAR;
+    let temperature = arr1(&[250.0, 280.0, 300.0, 350.0]) * KELVIN;
+    let z = State::compressibility_isobar(
+        &saft,
+        pressure,
+        None,
+        &temperature,
+        DensityInitialization::None,
+    )?;
+    assert_eq!(z.len(), temperature.len());
+    for (t, &z) in temperature.into_iter().zip(z.iter()) {
+        let state = State::new_npt(
+            &saft,
+            t,
+            pressure,
+            &(arr1(&[1.0]) * MOL),
+            DensityInitialization::None,
+        )?;
+        assert_relative_eq!(
+            z,
+            state.compressibility(Contributions::Total),
+            max_relative = 1e-10
+        );
+    }
+    // the stable phase at 280 K is the vapor
+    assert!(z[1] > 0.8);
+
+    // liquid initialization yields the (metastable) liquid branch
+    let z_liquid = State::compressibility_isobar(
+        &saft,
+        pressure,
+        None,
+        &(arr1(&[250.0, 280.0]) * KELVIN),
+        DensityInitialization::Liquid,
+    )?;
+    assert!(z_liquid.iter().all(|&z| z < 0.1));
+    assert_relative_eq!(z_liquid[0], z[0], max_relative = 1e-10);
+
+    // there is no vapor state at negative pressures
+    let result = State::compressibility_isobar(
+        &saft,
+        -10.0 * BAR,
+        None,
+        &temperature,
+        DensityInitialization::Vapor,
+    );
+    assert!(matches!(
+        result,
+        Err(EosError::GridPointFailed { index: 0, ref source })
+            if matches!(**source, EosError::DensityIteration(..))
+    ));
+    Ok(())
+}
+
+#[test]
+fn new_pure() -> Result<(), Box<dyn Error>> {
+    let saft = Arc::new(PcSaft::new(propane_parameters()?.0));
+    let temperature = 300.0 * KELVIN;
+    let pressure = 40.0 * BAR;
+    let moles = arr1(&[1.0]) * MOL;
+    let full = State::new_npt(
+        &saft,
+        temperature,
+        pressure,
+        &moles,
+        DensityInitialization::None,
+    )?;
+
+    let from_pressure = State::new_pure(&saft, temperature, pressure)?;
+    assert_relative_eq!(from_pressure.density, full.density, max_relative = 1e-10);
+    let from_density = State::new_pure(&saft, temperature, full.density)?;
+    assert_relative_eq!(
+        from_density.pressure(Contributions::Total),
+        pressure,
+        max_relative = 1e-8
+    );
+
+    let mixture = Arc::new(PcSaft::new(Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?)));
+    assert!(matches!(
+        State::new_pure(&mixture, temperature, pressure),
+        Err(EosError::IncompatibleComponents(2, 1))
+    ));
+    if let Err(err) = State::new_pure(&mixture, temperature, pressure) {
+        assert_eq!(
+            err.to_string(),
+            "Equation of state has 2 components, but the calculation requires 1."
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn reference_state() -> Result<(), Box<dyn Error>> {
+    let (saft, joback) = propane_parameters()?;
+    let eos = Arc::new(EquationOfState::new(joback, Arc::new(PcSaft::new(saft))));
+    let t = 300.0 * KELVIN;
+    let p = BAR;
+    let state = State::new_pure(&eos, t, p)?;
+    let state_liquid = State::new_pure(&eos, 250.0 * KELVIN, 10.0 * BAR)?;
+
+    let reference = Arc::new(eos.with_reference_state(ReferenceState::TemperaturePressure(t, p))?);
+    let state_ref = State::new_pure(&reference, t, p)?;
+    let c = Contributions::Total;
+    assert_relative_eq!(
+        state_ref.molar_enthalpy(c).convert_to(JOULE / MOL),
+        0.0,
+        epsilon = 1e-8
+    );
+    assert_relative_eq!(
+        state_ref.molar_entropy(c).convert_to(JOULE / MOL / KELVIN),
+        0.0,
+        epsilon = 1e-10
+    );
+
+    // differences and non-caloric properties are not affected
+    let state_liquid_ref = State::new_pure(&reference, 250.0 * KELVIN, 10.0 * BAR)?;
+    assert_relative_eq!(
+        state_liquid_ref.molar_enthalpy(c) - state_ref.molar_enthalpy(c),
+        state_liquid.molar_enthalpy(c) - state.molar_enthalpy(c),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        state_liquid_ref.molar_isobaric_heat_capacity(c),
+        state_liquid.molar_isobaric_heat_capacity(c),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        state_liquid_ref.density,
+        state_liquid.density,
+        max_relative = 1e-10
+    );
+
+    let p_nbp = 101325.0 * PASCAL;
+    let nbp = Arc::new(eos.with_reference_state(ReferenceState::SaturatedLiquid(p_nbp))?);
+    let vle = PhaseEquilibrium::pure(&nbp, p_nbp, None, Default::default())?;
+    assert_relative_eq!(
+        vle.liquid().molar_enthalpy(c).convert_to(JOULE / MOL),
+        0.0,
+        epsilon = 1e-8
+    );
+    assert_relative_eq!(
+        vle.liquid()
+            .molar_entropy(c)
+            .convert_to(JOULE / MOL / KELVIN),
+        0.0,
+        epsilon = 1e-10
+    );
+
+    // the reference state is kept if the residual model is replaced
+    let (saft, _) = propane_parameters()?;
+    let mut record = saft.pure_records[0].clone();
+    record.model_record = record.model_record.with_epsilon_k(220.0);
+    let replaced = EquationOfState {
+        residual: Arc::new(PcSaft::new(Arc::new(PcSaftParameters::new_pure(record)?))),
+        ..(*nbp).clone()
+    };
+    let replaced = Arc::new(replaced.update_reference_values()?);
+    let vle = PhaseEquilibrium::pure(&replaced, p_nbp, None, Default::default())?;
+    assert_relative_eq!(
+        vle.liquid().molar_enthalpy(c).convert_to(JOULE / MOL),
+        0.0,
+        epsilon = 1e-8
+    );
+    Ok(())
+}
@@ -1,7 +1,11 @@
 use approx::assert_relative_eq;
+use feos::ideal_gas::Joback;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
-use feos_core::{Contributions, PhaseEquilibrium, SolverOptions};
+use feos_core::{
+    Contributions, DensityInitialization, EosError, EquationOfState, PhaseEquilibrium,
+    SolverOptions, State,
+};
 use ndarray::*;
 use quantity::*;
 use std::error::Error;
@@ -58,5 +62,131 @@ fn test_tp_flash() -> Result<(), Box<dyn Error>> {
         &vle.liquid().molefracs * &vle.liquid().ln_phi().mapv(f64::exp),
         max_relative = 1e-10
     );
+    let k = vle.k_values();
+    assert_eq!(k.len(), 2);
+    assert!(k[0] > 1.0 && k[1] < 1.0);
+    assert_relative_eq!(
+        k,
+        (vle.liquid().ln_phi() - vle.vapor().ln_phi()).mapv(f64::exp),
+        max_relative = 1e-9
+    );
+    Ok(())
+}
+
+#[test]
+fn test_bubble_dew_point_temperature() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let p = 5.0 * BAR;
+    let z = arr1(&[0.4, 0.6]);
+    let bubble = PhaseEquilibrium::bubble_point(
+        &mix,
+        p,
+        &z,
+        Some(280.0 * KELVIN),
+        None,
+        Default::default(),
+    )?;
+    let dew =
+        PhaseEquilibrium::dew_point(&mix, p, &z, Some(280.0 * KELVIN), None, Default::default())?;
+    let t_bubble = bubble.liquid().temperature;
+    let t_dew = dew.vapor().temperature;
+    assert!(t_bubble < t_dew);
+    assert_relative_eq!(
+        bubble.vapor().pressure(Contributions::Total),
+        p,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        dew.liquid().pressure(Contributions::Total),
+        p,
+        max_relative = 1e-6
+    );
+
+    // the bubble point of the dew liquid coincides with the dew point
+    let x = dew.liquid().molefracs.clone();
+    let bubble_dew =
+        PhaseEquilibrium::bubble_point(&mix, p, &x, Some(t_dew), None, Default::default())?;
+    assert_relative_eq!(bubble_dew.liquid().temperature, t_dew, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_ph_flash() -> Result<(), Box<dyn Error>> {
+    for components in [vec!["propane"], vec!["propane", "butane"]] {
+        let joback = Joback::from_json(
+            components.clone(),
+            "tests/pcsaft/test_parameters_joback.json",
+            None,
+            IdentifierOption::Name,
+        )?;
+        let saft = Arc::new(PcSaft::new(read_params(components.clone())?));
+        let eos = Arc::new(EquationOfState::new(Arc::new(joback), saft));
+        let n = components.len();
+        let feed = Array1::from_elem(n, 1.0 / n as f64) * MOL;
+
+        // subcooled liquid throttled into the two-phase region
+        let liquid = State::new_npt(
+            &eos,
+            300.0 * KELVIN,
+            20.0 * BAR,
+            &feed,
+            DensityInitialization::Liquid,
+        )?;
+        let p = 3.0 * BAR;
+        let vle = liquid.ph_flash(p, Default::default())?;
+        let (vapor, liquid_out) = (vle.vapor(), vle.liquid());
+        let beta = (vapor.total_moles / (vapor.total_moles + liquid_out.total_moles)).into_value();
+        assert!(0.0 < beta && beta < 1.0);
+        assert!(vapor.temperature < liquid.temperature);
+        assert_relative_eq!(
+            vapor.temperature,
+            liquid_out.temperature,
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(vapor.pressure(Contributions::Total), p, max_relative = 1e-6);
+        assert_relative_eq!(
+            liquid_out.pressure(Contributions::Total),
+            p,
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(
+            vapor.moles.clone() + liquid_out.moles.clone(),
+            feed,
+            max_relative = 1e-10
+        );
+
+        // enthalpy is conserved
+        let c = Contributions::Total;
+        assert_relative_eq!(
+            vapor.enthalpy(c) + liquid_out.enthalpy(c),
+            liquid.enthalpy(c),
+            max_relative = 1e-6
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_vlle() -> Result<(), Box<dyn Error>> {
+    let eos = Arc::new(PcSaft::new(read_params(vec!["water_np", "hexane"])?));
+    let t = 320.0 * KELVIN;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let vlle = PhaseEquilibrium::vlle(&eos, t, &feed, Default::default())?;
+    let (v, l1, l2) = (vlle.vapor(), vlle.liquid1(), vlle.liquid2());
+
+    // three distinct phases at the same pressure
+    assert!(v.density < l1.density && v.density < l2.density);
+    assert!((l1.molefracs[0] - l2.molefracs[0]).abs() > 0.5);
+    let p = v.pressure(Contributions::Total);
+    assert_relative_eq!(l1.pressure(Contributions::Total), p, max_relative = 1e-6);
+    assert_relative_eq!(l2.pressure(Contributions::Total), p, max_relative = 1e-6);
+    let ln_f = |s: &State<PcSaft>| s.ln_phi() + s.molefracs.mapv(f64::ln);
+    assert_relative_eq!(ln_f(l1), ln_f(v), epsilon = 1e-6);
+    assert_relative_eq!(ln_f(l2), ln_f(v), epsilon = 1e-6);
+
+    // no liquid-liquid demixing in a mixture of alkanes
+    let eos = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let vlle = PhaseEquilibrium::vlle(&eos, 250.0 * KELVIN, &feed, Default::default());
+    assert!(matches!(vlle, Err(EosError::NoPhaseSplit)));
     Ok(())
 }
@@ -1,7 +1,9 @@
 use approx::assert_relative_eq;
+use feos::ideal_gas::Joback;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
-use feos_core::{Contributions, PhaseEquilibrium};
+use feos_core::{Contributions, EquationOfState, PhaseEquilibrium, State};
+use ndarray::arr1;
 use quantity::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -75,3 +77,147 @@ fn vle_pure_pressure() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[test]
+fn vle_pure_vapor_pressure() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let temperatures = arr1(&[200.0, 250.0, 300.0, 350.0]) * KELVIN;
+    let pressures = PhaseEquilibrium::pure_vapor_pressure(&saft, &temperatures)?;
+    // saturation pressures of propane from NIST
+    let reference = arr1(&[0.20188, 2.1813, 9.9760, 29.480]) * BAR;
+    for i in 0..temperatures.len() {
+        assert_relative_eq!(pressures.get(i), reference.get(i), max_relative = 3e-2);
+    }
+    assert!(
+        PhaseEquilibrium::pure_vapor_pressure(&saft, &(arr1(&[300.0, 400.0]) * KELVIN)).is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn vle_pure_enthalpy_of_vaporization() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let joback = Joback::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let eos = Arc::new(EquationOfState::new(Arc::new(joback), saft));
+
+    let t = 250.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default())?;
+    let dh_vap = vle.enthalpy_of_vaporization();
+    assert!(dh_vap > 15.0 * KILO * JOULE / MOL);
+    assert!(dh_vap < 25.0 * KILO * JOULE / MOL);
+    assert_relative_eq!(
+        vle.entropy_of_vaporization(),
+        dh_vap / t,
+        max_relative = 1e-8
+    );
+
+    let cp = State::critical_point(&eos, None, None, Default::default())?;
+    let vle = PhaseEquilibrium::pure(
+        &eos,
+        cp.temperature - 0.01 * KELVIN,
+        None,
+        Default::default(),
+    )?;
+    assert!(vle.enthalpy_of_vaporization() > 0.0 * JOULE / MOL);
+    assert!(vle.enthalpy_of_vaporization() < 0.05 * dh_vap);
+    Ok(())
+}
+
+#[test]
+fn vle_pure_to_dict() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let joback = Joback::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let eos = Arc::new(EquationOfState::new(Arc::new(joback), saft));
+
+    let vle = PhaseEquilibrium::pure(&eos, 250.0 * KELVIN, None, Default::default())?;
+    let dict = vle.to_dict(Contributions::Total, None);
+    assert_relative_eq!(
+        dict["vapor pressure"],
+        dict["liquid pressure"],
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        dict["vapor pressure"],
+        vle.vapor()
+            .pressure(Contributions::Total)
+            .convert_to(PASCAL)
+    );
+    for phase in ["vapor", "liquid"] {
+        for key in [
+            "temperature",
+            "density",
+            "molar enthalpy",
+            "molar entropy",
+            "mass density",
+            "specific enthalpy",
+            "specific entropy",
+        ] {
+            assert!(
+                dict.contains_key(&format!("{phase} {key}")),
+                "{phase} {key}"
+            );
+        }
+    }
+    assert_eq!(dict.len(), 16);
+    Ok(())
+}
+
+#[test]
+fn maxwell_construction() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    for t in [
+        200.0 * KELVIN,
+        250.0 * KELVIN,
+        300.0 * KELVIN,
+        350.0 * KELVIN,
+    ] {
+        let (p, [rho_v, rho_l]) =
+            PhaseEquilibrium::maxwell_construction(&saft, t, Default::default())?;
+        let vle = PhaseEquilibrium::pure(&saft, t, None, Default::default())?;
+        assert_relative_eq!(
+            p,
+            vle.vapor().pressure(Contributions::Total),
+            max_relative = 1e-6
+        );
+        assert_relative_eq!(rho_v, vle.vapor().density, max_relative = 1e-6);
+        assert_relative_eq!(rho_l, vle.liquid().density, max_relative = 1e-6);
+    }
+    assert!(
+        PhaseEquilibrium::maxwell_construction(&saft, 400.0 * KELVIN, Default::default()).is_err()
+    );
+    Ok(())
+}